@@ -39,6 +39,16 @@ impl<T: Fact> Dep<T> {
         }
     }
 
+    /// Check whether this dep holds: a fact must pass its own check, ANY needs
+    /// at least one passing dep, and EVERY needs all of its deps to pass.
+    pub fn check(&self, ctx: &T::Context) -> bool {
+        match self {
+            Dep::Fact(fact) => fact.check(ctx),
+            Dep::Any(_, cs) => cs.iter().any(|c| c.check(ctx)),
+            Dep::Every(_, cs) => cs.iter().all(|c| c.check(ctx)),
+        }
+    }
+
     pub fn explain(&self, ctx: &T::Context) -> String {
         match &self {
             Dep::Fact(fact) => fact.explain(ctx),
@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::Hash;
 
+use petgraph::graph::NodeIndex;
+
 use crate::{Dep, Fact};
 
 #[derive(Debug, derive_more::From, derive_more::Deref, derive_more::DerefMut)]
@@ -41,10 +43,9 @@ impl<'c, T: Fact> DepGraph<'c, T> {
             .collect()
     }
 
-    pub fn report(&self) -> std::io::Result<String> {
-        use std::fmt::Write;
-        let mut out = "".to_string();
-        let dot = format!(
+    /// Render the graph in Graphviz dot syntax.
+    pub fn dot(&self) -> String {
+        format!(
             "{:?}",
             petgraph::dot::Dot::with_attr_getters(
                 &**self,
@@ -52,20 +53,268 @@ impl<'c, T: Fact> DepGraph<'c, T> {
                 &|_g, _e| "".to_string(),
                 &|_g, _n| { "nojustify=true".to_string() },
             )
+        )
+    }
+
+    /// A human-readable report of the graph, drawn as an ASCII tree, followed
+    /// by the dot syntax for use with other tools.
+    pub fn report(&self) -> std::io::Result<String> {
+        use std::fmt::Write;
+        let mut out = "".to_string();
+        writeln!(&mut out, "Dependency tree:\n\n{}", self.ascii_tree()).unwrap();
+        writeln!(&mut out, "Original dot output:\n\n{}", self.dot()).unwrap();
+        Ok(out)
+    }
+
+    /// The result of checking each node, indexed by node index.
+    pub fn checks(&self) -> Vec<bool> {
+        self.node_weights().map(|n| n.dep.check(n.ctx)).collect()
+    }
+
+    /// Draw the graph as an ASCII tree, starting from each root node.
+    ///
+    /// Nodes which are reachable along more than one path are only expanded
+    /// the first time they are drawn.
+    pub fn ascii_tree(&self) -> String {
+        let checks = self.checks();
+        let mut out = String::new();
+        let mut seen = HashSet::new();
+        for root in self.roots() {
+            self.ascii_subtree(root, &checks, "", None, &mut seen, &mut out);
+        }
+        out
+    }
+
+    fn ascii_subtree(
+        &self,
+        ix: NodeIndex,
+        checks: &[bool],
+        prefix: &str,
+        last: Option<bool>,
+        seen: &mut HashSet<NodeIndex>,
+        out: &mut String,
+    ) {
+        let (branch, indent) = match last {
+            None => ("", ""),
+            Some(false) => ("├── ", "│   "),
+            Some(true) => ("└── ", "    "),
+        };
+        let status = if checks[ix.index()] { "PASS" } else { "FAIL" };
+        let repeat = !seen.insert(ix);
+        out.push_str(&format!(
+            "{prefix}{branch}[{status}] {}{}\n",
+            self[ix].label(),
+            if repeat { " (see above)" } else { "" }
+        ));
+        if repeat {
+            return;
+        }
+        let children = self.children(ix);
+        let prefix = format!("{prefix}{indent}");
+        for (i, child) in children.iter().enumerate() {
+            let last = i == children.len() - 1;
+            self.ascii_subtree(*child, checks, &prefix, Some(last), seen, out);
+        }
+    }
+
+    /// Draw the graph as a standalone SVG image, with passing nodes in green
+    /// and failing nodes in red.
+    pub fn svg(&self) -> String {
+        const CHAR_WIDTH: usize = 7;
+        const MAX_CHARS: usize = 60;
+        const PAD: usize = 10;
+        const NODE_HEIGHT: usize = 30;
+        const ROW_HEIGHT: usize = 80;
+        const GAP: usize = 20;
+
+        let checks = self.checks();
+        let labels: Vec<String> = self
+            .node_weights()
+            .map(|n| {
+                let label = n.label();
+                if label.chars().count() > MAX_CHARS {
+                    let mut short: String = label.chars().take(MAX_CHARS - 1).collect();
+                    short.push('…');
+                    short
+                } else {
+                    label
+                }
+            })
+            .collect();
+        let widths: Vec<usize> = labels
+            .iter()
+            .map(|l| l.chars().count() * CHAR_WIDTH + 2 * PAD)
+            .collect();
+
+        let layers = self.layers();
+        let row_widths: Vec<usize> = layers
+            .iter()
+            .map(|row| row.iter().map(|ix| widths[ix.index()] + GAP).sum::<usize>() + GAP)
+            .collect();
+        let width = row_widths.iter().copied().max().unwrap_or(GAP);
+        let height = layers.len() * ROW_HEIGHT + GAP;
+
+        // The top-left corner of each node's box
+        let mut positions = vec![(0, 0); self.node_count()];
+        for (depth, row) in layers.iter().enumerate() {
+            let mut x = (width - row_widths[depth]) / 2 + GAP;
+            for ix in row {
+                positions[ix.index()] = (x, depth * ROW_HEIGHT + GAP);
+                x += widths[ix.index()] + GAP;
+            }
+        }
+
+        let mut out = format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="monospace" font-size="12">"#,
+                r#"<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="8" markerHeight="8" orient="auto-start-reverse">"#,
+                r##"<path d="M 0 0 L 10 5 L 0 10 z" fill="#555"/></marker></defs>"##,
+                "\n"
+            ),
+            w = width,
+            h = height
         );
+        for edge in self.edge_indices() {
+            if let Some((a, b)) = self.edge_endpoints(edge) {
+                let (ax, ay) = positions[a.index()];
+                let (bx, by) = positions[b.index()];
+                out.push_str(&format!(
+                    r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#555" marker-end="url(#arrow)"/>"##,
+                    ax + widths[a.index()] / 2,
+                    ay + NODE_HEIGHT,
+                    bx + widths[b.index()] / 2,
+                    by
+                ));
+                out.push('\n');
+            }
+        }
+        for ix in self.node_indices() {
+            let (x, y) = positions[ix.index()];
+            let (fill, stroke) = if checks[ix.index()] {
+                ("#d4edda", "#28a745")
+            } else {
+                ("#f8d7da", "#dc3545")
+            };
+            out.push_str(&format!(
+                concat!(
+                    r#"<g><title>{}</title>"#,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" rx="4" fill="{}" stroke="{}"/>"#,
+                    r#"<text x="{}" y="{}">{}</text></g>"#,
+                    "\n"
+                ),
+                xml_escape(&self[ix].dep.explain(self[ix].ctx)),
+                x,
+                y,
+                widths[ix.index()],
+                NODE_HEIGHT,
+                fill,
+                stroke,
+                x + PAD,
+                y + NODE_HEIGHT / 2 + 4,
+                xml_escape(&labels[ix.index()]),
+            ));
+        }
+        out.push_str("</svg>\n");
+        out
+    }
 
-        if let Ok(graph) = graph_easy(&dot) {
-            writeln!(&mut out, "Original dot output:\n\n{}", dot).unwrap();
-            writeln!(&mut out, "`graph-easy` output:\n{}", graph).unwrap();
-        } else {
-            writeln!(
-                &mut out,
-                "`graph-easy` not installed. Original dot output:\n\n{}",
-                dot
-            )
-            .unwrap();
+    /// Wrap the SVG drawing of the graph in a self-contained HTML page.
+    pub fn html(&self, title: &str) -> String {
+        let title = xml_escape(title);
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n</head>\n<body>\n<h1>{title}</h1>\n{}</body>\n</html>\n",
+            self.svg()
+        )
+    }
+
+    /// Export the nodes and edges of the graph, along with the result of
+    /// checking each node.
+    #[cfg(feature = "json")]
+    pub fn export(&self) -> GraphExport {
+        let checks = self.checks();
+        let nodes = self
+            .node_indices()
+            .map(|ix| {
+                NodeExport::new(
+                    ix.index(),
+                    &self[ix].dep,
+                    self[ix].ctx,
+                    checks[ix.index()],
+                    false,
+                )
+            })
+            .collect();
+        let edges = self
+            .edge_indices()
+            .filter_map(|e| self.edge_endpoints(e))
+            .map(|(a, b)| (a.index(), b.index()))
+            .collect();
+        GraphExport { nodes, edges }
+    }
+
+    /// The nodes which no other node depends on, sorted by label.
+    fn roots(&self) -> Vec<NodeIndex> {
+        let mut roots: Vec<_> = self
+            .node_indices()
+            .filter(|i| {
+                self.edges_directed(*i, petgraph::Direction::Incoming)
+                    .next()
+                    .is_none()
+            })
+            .collect();
+        if roots.is_empty() {
+            // Every node is part of a loop, so just start anywhere
+            roots.extend(self.node_indices().next());
         }
-        Ok(out)
+        roots.sort_by_key(|ix| self[*ix].label());
+        roots
+    }
+
+    /// The direct deps of a node, sorted by label.
+    fn children(&self, ix: NodeIndex) -> Vec<NodeIndex> {
+        let mut children: Vec<_> = self.neighbors(ix).collect();
+        children.sort_by_key(|ix| self[*ix].label());
+        children.dedup();
+        children
+    }
+
+    /// Group the nodes into rows for drawing, where each node is placed one
+    /// row below the deepest node which depends on it.
+    fn layers(&self) -> Vec<Vec<NodeIndex>> {
+        let mut depths = vec![0; self.node_count()];
+        let order = petgraph::algo::toposort(&**self, None).unwrap_or_else(|_| {
+            // Loops should already have been pruned, but if not, fall back to
+            // a breadth-first ordering which places each node at most once.
+            let mut order = vec![];
+            let mut seen = HashSet::new();
+            for root in self.roots() {
+                let mut bfs = petgraph::visit::Bfs::new(&**self, root);
+                while let Some(ix) = bfs.next(&**self) {
+                    if seen.insert(ix) {
+                        order.push(ix);
+                    }
+                }
+            }
+            order.extend(self.node_indices().filter(|ix| !seen.contains(ix)));
+            order
+        });
+        let mut placed = HashSet::new();
+        for ix in order {
+            placed.insert(ix);
+            for child in self.neighbors(ix) {
+                if !placed.contains(&child) {
+                    depths[child.index()] = depths[child.index()].max(depths[ix.index()] + 1);
+                }
+            }
+        }
+        let mut layers = vec![vec![]; depths.iter().max().map(|d| d + 1).unwrap_or(0)];
+        for ix in self.node_indices() {
+            layers[depths[ix.index()]].push(ix);
+        }
+        for row in layers.iter_mut() {
+            row.sort_by_key(|ix| self[*ix].label());
+        }
+        layers
     }
 
     pub fn print(&self) {
@@ -74,6 +323,94 @@ impl<'c, T: Fact> DepGraph<'c, T> {
     }
 }
 
+impl<'c, T: Fact> GraphNode<'c, T> {
+    /// A one-line description of this node. Facts are explained in full,
+    /// while ANY and EVERY nodes only show their name, since their deps
+    /// appear as separate nodes.
+    pub fn label(&self) -> String {
+        match &self.dep {
+            Dep::Fact(fact) => fact
+                .explain(self.ctx)
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            Dep::Any(name, _) => group_label("ANY", name),
+            Dep::Every(name, _) => group_label("EVERY", name),
+        }
+    }
+}
+
+fn group_label(kind: &str, name: &Option<String>) -> String {
+    match name {
+        Some(name) => format!("{kind}: {name}"),
+        None => kind.to_string(),
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A serializable form of a [`DepGraph`].
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct GraphExport {
+    /// The nodes of the graph. Each node's id is its index in this list.
+    pub nodes: Vec<NodeExport>,
+    /// The edges of the graph, as pairs of node ids, pointing from a node
+    /// to one of its deps.
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// A serializable form of a single node in a [`DepGraph`].
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct NodeExport {
+    /// The node's id
+    pub id: usize,
+    /// Whether this node is a "fact", "any" or "every" dep
+    pub kind: &'static str,
+    /// The one-line description of the node
+    pub label: String,
+    /// The full explanation of the node's dep
+    pub explanation: String,
+    /// Whether the node's check passed
+    pub passed: bool,
+    /// Whether the traversal stopped at this node
+    pub terminal: bool,
+}
+
+#[cfg(feature = "json")]
+impl NodeExport {
+    pub(crate) fn new<T: Fact>(
+        id: usize,
+        dep: &Dep<T>,
+        ctx: &T::Context,
+        passed: bool,
+        terminal: bool,
+    ) -> Self {
+        let node = GraphNode {
+            dep: dep.clone(),
+            ctx,
+        };
+        Self {
+            id,
+            kind: match dep {
+                Dep::Fact(_) => "fact",
+                Dep::Any(..) => "any",
+                Dep::Every(..) => "every",
+            },
+            label: node.label(),
+            explanation: dep.explain(ctx),
+            passed,
+            terminal,
+        }
+    }
+}
+
 impl<'c, T: Fact> Debug for GraphNode<'c, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.dep.explain(self.ctx))
@@ -88,6 +425,9 @@ impl<'c, T: Fact> Default for DepGraph<'c, T> {
 
 /// If a `graph-easy` binary is installed, render an ASCII graph from the
 /// provided dot syntax.
+///
+/// [`DepGraph::ascii_tree`] gives a similar rendering without needing any
+/// external tools.
 pub fn graph_easy(dot: &str) -> anyhow::Result<String> {
     use std::io::{Read, Write};
 
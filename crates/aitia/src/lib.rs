@@ -56,7 +56,12 @@ pub use fact::{Fact, FactTraits};
 #[cfg(test)]
 mod tests;
 
-use traversal::{Traversal, TraversalError, TraversalResult};
+pub use graph::{graph_easy, DepGraph, GraphNode};
+#[cfg(feature = "json")]
+pub use graph::{GraphExport, NodeExport};
+#[cfg(feature = "json")]
+pub use traversal::TraversalExport;
+pub use traversal::{Traversal, TraversalError, TraversalResult};
 
 #[macro_export]
 macro_rules! assert_fact {
@@ -513,3 +513,105 @@ fn holochain_like() {
 
     report(&fatma_store.traverse(&checks));
 }
+
+/// The native renderings of a graph and the JSON export of a traversal
+mod rendering {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Step(u8);
+
+    impl Fact for Step {
+        type Context = HashSet<u8>;
+
+        fn dep(&self, _ctx: &Self::Context) -> DepResult<Self> {
+            // Each step below 10 can follow either the step before it or a
+            // step above 10, which has no deps of its own
+            Ok((self.0 > 0 && self.0 < 10).then(|| {
+                Dep::any_named(
+                    format!("before {}", self.0),
+                    vec![Self(self.0 - 1).into(), Self(self.0 + 10).into()],
+                )
+            }))
+        }
+
+        fn check(&self, ctx: &Self::Context) -> bool {
+            ctx.contains(&self.0)
+        }
+    }
+
+    #[test]
+    fn ascii_tree() {
+        let ctx = hashset![0];
+        let tr = Step(2).traverse(&ctx).unwrap();
+        pretty_assertions::assert_eq!(
+            tr.graph().ascii_tree(),
+            [
+                "[FAIL] Step(2)",
+                "└── [FAIL] ANY: before 2",
+                "    ├── [FAIL] Step(1)",
+                "    └── [FAIL] Step(12)",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn svg_and_html() {
+        let ctx = hashset![0];
+        let tr = Step(2).traverse(&ctx).unwrap();
+        let svg = tr.graph().svg();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<rect ").count(), tr.graph().node_count());
+        assert_eq!(svg.matches("<line ").count(), tr.graph().edge_count());
+
+        let html = tr.graph().html("Step <2>");
+        assert!(html.contains("<title>Step &lt;2&gt;</title>"));
+        assert!(html.contains(&svg));
+    }
+
+    #[test]
+    fn json_export() {
+        let ctx = hashset![0];
+        let tr = Step(2).traverse(&ctx).unwrap();
+        let export = tr.export();
+        assert!(!export.root_check_passed);
+        assert_eq!(export.edges.len(), tr.graph().edge_count() + 1);
+
+        let terminals: Vec<_> = export.nodes.iter().filter(|n| n.terminal).collect();
+        assert_eq!(terminals.len(), 1);
+        assert_eq!(terminals[0].label, "ANY: before 1");
+        assert!(terminals[0].passed);
+
+        // The terminal is linked to the node which depends on it
+        let parents: Vec<_> = export
+            .edges
+            .iter()
+            .filter(|(_, dep)| *dep == terminals[0].id)
+            .map(|(node, _)| export.nodes[*node].label.as_str())
+            .collect();
+        assert_eq!(parents, vec!["Step(1)"]);
+
+        // Node ids don't depend on the order of any hash sets
+        for _ in 0..10 {
+            assert_eq!(Step(2).traverse(&ctx).unwrap().export(), export);
+        }
+        assert_eq!(export.nodes[0].label, "Step(2)");
+
+        let fails: HashSet<_> = export
+            .nodes
+            .iter()
+            .filter(|n| !n.passed)
+            .map(|n| n.label.as_str())
+            .collect();
+        assert_eq!(
+            fails,
+            hashset!["Step(2)", "ANY: before 2", "Step(1)", "Step(12)"]
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&tr.to_json().unwrap()).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), export.nodes.len());
+    }
+}
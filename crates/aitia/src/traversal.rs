@@ -1,8 +1,10 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
 use std::hash::Hash;
 
 use crate::graph::{DepGraph, GraphNode};
+#[cfg(feature = "json")]
+use crate::graph::{GraphExport, NodeExport};
 use crate::{dep::*, Fact};
 
 #[derive(Debug, derive_more::From)]
@@ -23,10 +25,87 @@ pub struct Traversal<'c, T: Fact> {
     pub(crate) root_check_passed: bool,
     pub(crate) graph: DepGraph<'c, T>,
     pub(crate) terminals: HashSet<Dep<T>>,
+    /// The edges from nodes of the graph to the terminals they depend on,
+    /// as pairs of node index and terminal, in a stable order
+    pub(crate) terminal_edges: Vec<(usize, Dep<T>)>,
     pub(crate) ctx: &'c T::Context,
 }
 
-impl<'c, T: Fact> Traversal<'c, T> {}
+impl<'c, T: Fact> Traversal<'c, T> {
+    /// Whether the target fact was true
+    pub fn root_check_passed(&self) -> bool {
+        self.root_check_passed
+    }
+
+    /// The graph of deps visited by the traversal, excluding the terminals
+    pub fn graph(&self) -> &DepGraph<'c, T> {
+        &self.graph
+    }
+
+    /// The deps at which the traversal stopped
+    pub fn terminals(&self) -> &HashSet<Dep<T>> {
+        &self.terminals
+    }
+
+    /// Export the traversal, with the result of checking each node.
+    ///
+    /// The terminal deps are included as extra nodes, after the nodes of
+    /// the graph, along with the edges from the nodes which depend on them.
+    /// Node ids are stable, so exporting the same traversal twice gives the
+    /// same result.
+    #[cfg(feature = "json")]
+    pub fn export(&self) -> TraversalExport {
+        let GraphExport {
+            mut nodes,
+            mut edges,
+        } = self.graph.export();
+        let mut terminal_ids = HashMap::new();
+        let mut terminal_id = |dep: &Dep<T>, nodes: &mut Vec<NodeExport>| {
+            *terminal_ids.entry(dep.clone()).or_insert_with(|| {
+                let id = nodes.len();
+                nodes.push(NodeExport::new(
+                    id,
+                    dep,
+                    self.ctx,
+                    dep.check(self.ctx),
+                    true,
+                ));
+                id
+            })
+        };
+        for (parent, dep) in &self.terminal_edges {
+            let id = terminal_id(dep, &mut nodes);
+            edges.push((*parent, id));
+        }
+        // A terminal with no parent can only be the target fact itself
+        for dep in &self.terminals {
+            terminal_id(dep, &mut nodes);
+        }
+        TraversalExport {
+            root_check_passed: self.root_check_passed,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Export the traversal as pretty-printed JSON.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.export())
+    }
+}
+
+/// A serializable form of a [`Traversal`].
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct TraversalExport {
+    /// Whether the target fact was true
+    pub root_check_passed: bool,
+    /// The nodes of the graph, followed by the terminals
+    pub nodes: Vec<NodeExport>,
+    /// The edges of the graph, as pairs of node ids
+    pub edges: Vec<(usize, usize)>,
+}
 
 pub type TraversalResult<'c, F> = Result<Traversal<'c, F>, TraversalError<'c, F>>;
 
@@ -99,12 +178,13 @@ pub fn traverse<'c, F: Fact>(fact: F, ctx: &'c F::Context) -> TraversalResult<'c
     match res {
         Ok(check) => {
             table.insert(dep.clone(), Some(check.clone()));
-            let (graph, terminals) = produce_graph(&table, &dep, ctx);
+            let (graph, terminals, terminal_edges) = produce_graph(&table, &dep, ctx);
 
             Ok(Traversal {
                 root_check_passed,
                 graph,
                 terminals,
+                terminal_edges,
                 ctx,
             })
         }
@@ -113,7 +193,7 @@ pub fn traverse<'c, F: Fact>(fact: F, ctx: &'c F::Context) -> TraversalResult<'c
                 dep.clone(),
                 Some(TraversalStep::Continue(vec![dep.clone()])),
             );
-            let (graph, _) = produce_graph(&table, &dep, ctx);
+            let (graph, _, _) = produce_graph(&table, &dep, ctx);
 
             Err(TraversalError { graph, inner })
         }
@@ -293,33 +373,53 @@ fn prune_traversal<'a, 'b: 'a, T: Fact + Eq + Hash>(
     (sub, terminals)
 }
 
+/// Build the graph of a pruned traversal, along with its terminals and the
+/// edges from nodes of the graph to those terminals.
+///
+/// Nodes are added in breadth-first order from the start, visiting the deps of
+/// each node in the order they are given, so node indices are the same every
+/// time the same traversal is graphed.
+#[allow(clippy::type_complexity)]
 pub fn produce_graph<'a, 'b: 'a, 'c, T: Fact + Eq + Hash>(
     table: &'a TraversalMap<T>,
     start: &'b Dep<T>,
     ctx: &'c T::Context,
-) -> (DepGraph<'c, T>, HashSet<Dep<T>>) {
+) -> (DepGraph<'c, T>, HashSet<Dep<T>>, Vec<(usize, Dep<T>)>) {
     let mut g = DepGraph::default();
 
     let (sub, passes) = prune_traversal(table, start);
+    let passes: HashSet<_> = passes.into_iter().collect();
 
-    let rows: HashSet<_> = sub.into_iter().collect();
     let mut nodemap = HashMap::new();
-    for (i, (k, _)) in rows.iter().enumerate() {
+    let mut order = vec![];
+    let mut queue: VecDeque<_> = sub
+        .get_key_value(start)
+        .map(|(k, _)| *k)
+        .into_iter()
+        .collect();
+    while let Some(next) = queue.pop_front() {
+        if nodemap.contains_key(next) {
+            continue;
+        }
         let id = g.add_node(GraphNode {
-            dep: (*k).to_owned(),
+            dep: next.to_owned(),
             ctx,
         });
-        nodemap.insert(k, id);
-        assert_eq!(id.index(), i);
+        nodemap.insert(next, id);
+        order.push(next);
+        queue.extend(sub[next].iter().filter(|c| sub.contains_key(c)));
     }
 
-    for (k, v) in rows.iter() {
-        for c in v.iter() {
-            if let (Some(k), Some(c)) = (nodemap.get(k), nodemap.get(&c)) {
-                g.add_edge(*k, *c, ());
+    let mut terminal_edges = vec![];
+    for k in order {
+        for c in sub[k].iter() {
+            if let Some(c_id) = nodemap.get(c) {
+                g.add_edge(nodemap[k], *c_id, ());
+            } else if passes.contains(c) {
+                terminal_edges.push((nodemap[k].index(), c.clone()));
             }
         }
     }
 
-    (g, passes.into_iter().cloned().collect())
+    (g, passes.into_iter().cloned().collect(), terminal_edges)
 }
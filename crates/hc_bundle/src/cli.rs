@@ -1,8 +1,10 @@
 #![forbid(missing_docs)]
 //! Binary `hc-dna` command executable.
 
-use clap::{Parser, Subcommand};
-use holochain_types::prelude::{AppManifest, DnaManifest, ValidatedDnaManifest};
+use clap::{Args, Parser, Subcommand};
+use holochain_types::prelude::{
+    AgentPubKey, AppManifest, DnaManifest, DnaModifiersOpt, ValidatedDnaManifest, YamlProperties,
+};
 use holochain_types::web_app::WebAppManifest;
 use holochain_util::ffs;
 use mr_bundle::{Location, Manifest};
@@ -20,6 +22,55 @@ pub const APP_BUNDLE_EXT: &str = "happ";
 /// The file extension to use for Web-hApp bundles.
 pub const WEB_APP_BUNDLE_EXT: &str = "webhapp";
 
+/// Options for signing a bundle after packing it, with a key held by a
+/// running Lair keystore.
+#[derive(Debug, Clone, Default, Args)]
+pub struct SignArgs {
+    /// Sign the bundle with the key of this agent, e.g. `uhCAk...`,
+    /// which must be held by the Lair keystore given with `--lair-url`.
+    #[arg(long, requires = "lair_url", value_parser = parse_agent_key)]
+    pub sign: Option<AgentPubKey>,
+
+    /// The connection URL of the Lair keystore to sign with.
+    #[arg(long, value_parser = parse_url)]
+    pub lair_url: Option<url2::Url2>,
+
+    /// Read the Lair keystore passphrase from stdin instead of the tty.
+    #[arg(long)]
+    pub piped: bool,
+
+    /// Write the signature to a detached `[bundle].sig` file next to the
+    /// bundle, instead of embedding it into the bundle.
+    #[arg(long, requires = "sign")]
+    pub detached_signature: bool,
+}
+
+impl SignArgs {
+    /// Sign the bundle which was packed to `bundle_path`, if a signing key was given.
+    pub async fn sign<M: Manifest>(self, bundle_path: &Path) -> anyhow::Result<()> {
+        if let (Some(signer), Some(lair_url)) = (self.sign, self.lair_url) {
+            let keystore = crate::signing::connect_keystore(lair_url, self.piped).await?;
+            let path = crate::signing::sign_bundle::<M>(
+                bundle_path,
+                &keystore,
+                signer,
+                self.detached_signature,
+            )
+            .await?;
+            println!("Wrote signature to {}", path.to_string_lossy());
+        }
+        Ok(())
+    }
+}
+
+fn parse_agent_key(arg: &str) -> anyhow::Result<AgentPubKey> {
+    AgentPubKey::try_from(arg).map_err(|e| anyhow::anyhow!("{:?}", e))
+}
+
+fn parse_url(arg: &str) -> anyhow::Result<url2::Url2> {
+    url2::Url2::try_parse(arg).map_err(|e| anyhow::anyhow!("{:?}", e))
+}
+
 /// Work with Holochain DNA bundles.
 #[derive(Debug, Parser)]
 #[command(version, about)]
@@ -63,6 +114,10 @@ pub enum HcDnaBundleSubcommand {
         /// that can be used to run this happ on iOS
         #[arg(long)]
         dylib_ios: bool,
+
+        /// Sign the packed bundle.
+        #[command(flatten)]
+        sign: SignArgs,
     },
 
     /// Unpack parts of the `.dna` bundle file into a specific directory.
@@ -96,6 +151,30 @@ pub enum HcDnaBundleSubcommand {
         force: bool,
     },
 
+    /// Print what a `.dna` bundle will produce when installed, as JSON:
+    /// its DNA hash, the hash of each zome's wasm, the zomes' dependencies,
+    /// and the entry and link types defined by each integrity zome.
+    ///
+    /// e.g.:
+    ///
+    /// $ hc dna inspect ./some/dir/my-dna.dna --network-seed abc
+    Inspect {
+        /// The path to the bundle to inspect.
+        path: std::path::PathBuf,
+
+        /// Override the network seed of the DNA.
+        #[arg(long)]
+        network_seed: Option<String>,
+
+        /// Override the properties of the DNA with the contents of a YAML file.
+        #[arg(long)]
+        properties: Option<PathBuf>,
+
+        /// Don't run the integrity zomes to find their entry and link types.
+        #[arg(long)]
+        skip_types: bool,
+    },
+
     /// Print the schema for a DNA manifest
     Schema,
 }
@@ -145,6 +224,10 @@ pub enum HcAppBundleSubcommand {
         /// as each of the DNA files specified in the manifest.
         #[arg(short, long)]
         recursive: bool,
+
+        /// Sign the packed bundle.
+        #[command(flatten)]
+        sign: SignArgs,
     },
 
     /// Unpack parts of the `.happ` bundle file into a specific directory.
@@ -178,6 +261,18 @@ pub enum HcAppBundleSubcommand {
         force: bool,
     },
 
+    /// Print what a `.happ` bundle will produce when installed, as JSON:
+    /// the DNA hash of each role, with the role's modifiers applied, along
+    /// with the details given by `hc dna inspect`.
+    Inspect {
+        /// The path to the bundle to inspect.
+        path: std::path::PathBuf,
+
+        /// Don't run the integrity zomes to find their entry and link types.
+        #[arg(long)]
+        skip_types: bool,
+    },
+
     /// Print the schema for a hApp manifest
     Schema,
 }
@@ -229,6 +324,10 @@ pub enum HcWebAppBundleSubcommand {
         /// as each of the DNA files specified in the hApps' manifests.
         #[arg(short, long)]
         recursive: bool,
+
+        /// Sign the packed bundle.
+        #[command(flatten)]
+        sign: SignArgs,
     },
 
     /// Unpack parts of the `.webhapp` bundle file into a specific directory.
@@ -262,6 +361,17 @@ pub enum HcWebAppBundleSubcommand {
         force: bool,
     },
 
+    /// Print what the hApp inside a `.webhapp` bundle will produce when
+    /// installed, as JSON. See `hc app inspect`.
+    Inspect {
+        /// The path to the bundle to inspect.
+        path: std::path::PathBuf,
+
+        /// Don't run the integrity zomes to find their entry and link types.
+        #[arg(long)]
+        skip_types: bool,
+    },
+
     /// Print the schema for a web hApp manifest
     Schema,
 }
@@ -305,12 +415,14 @@ impl HcDnaBundleSubcommand {
                 path,
                 output,
                 dylib_ios,
+                sign,
             } => {
                 let name = get_dna_name(&path).await?;
                 let (bundle_path, _) =
                     crate::packing::pack::<ValidatedDnaManifest>(&path, output, name, dylib_ios)
                         .await?;
                 println!("Wrote bundle {}", bundle_path.to_string_lossy());
+                sign.sign::<ValidatedDnaManifest>(&bundle_path).await?;
             }
             Self::Unpack {
                 path,
@@ -338,6 +450,26 @@ impl HcDnaBundleSubcommand {
                 };
                 println!("Unpacked to directory {}", dir_path.to_string_lossy());
             }
+            Self::Inspect {
+                path,
+                network_seed,
+                properties,
+                skip_types,
+            } => {
+                let mut modifiers = DnaModifiersOpt::<YamlProperties>::none();
+                if let Some(network_seed) = network_seed {
+                    modifiers = modifiers.with_network_seed(network_seed);
+                }
+                if let Some(properties) = properties {
+                    let properties_yaml = ffs::read_to_string(&properties).await?;
+                    let properties: serde_yaml::Value = serde_yaml::from_str(&properties_yaml)?;
+                    modifiers = modifiers.with_properties(YamlProperties::new(properties));
+                }
+                let inspection =
+                    crate::inspect::inspect_dna(&path, modifiers.serialized()?, !skip_types)
+                        .await?;
+                println!("{}", serde_json::to_string_pretty(&inspection)?);
+            }
             Self::Schema => {
                 println!("{}", include_str!("../schema/dna-manifest.schema.json"));
             }
@@ -357,6 +489,7 @@ impl HcAppBundleSubcommand {
                 path,
                 output,
                 recursive,
+                sign,
             } => {
                 let name = get_app_name(&path).await?;
 
@@ -367,6 +500,7 @@ impl HcAppBundleSubcommand {
                 let (bundle_path, _) =
                     crate::packing::pack::<AppManifest>(&path, output, name, false).await?;
                 println!("Wrote bundle {}", bundle_path.to_string_lossy());
+                sign.sign::<AppManifest>(&bundle_path).await?;
             }
             Self::Unpack {
                 path,
@@ -389,6 +523,10 @@ impl HcAppBundleSubcommand {
                 };
                 println!("Unpacked to directory {}", dir_path.to_string_lossy());
             }
+            Self::Inspect { path, skip_types } => {
+                let inspection = crate::inspect::inspect_app(&path, !skip_types).await?;
+                println!("{}", serde_json::to_string_pretty(&inspection)?);
+            }
             Self::Schema => {
                 println!("{}", include_str!("../schema/happ-manifest.schema.json"));
             }
@@ -408,6 +546,7 @@ impl HcWebAppBundleSubcommand {
                 path,
                 output,
                 recursive,
+                sign,
             } => {
                 let name = get_web_app_name(&path).await?;
 
@@ -418,6 +557,7 @@ impl HcWebAppBundleSubcommand {
                 let (bundle_path, _) =
                     crate::packing::pack::<WebAppManifest>(&path, output, name, false).await?;
                 println!("Wrote bundle {}", bundle_path.to_string_lossy());
+                sign.sign::<WebAppManifest>(&bundle_path).await?;
             }
             Self::Unpack {
                 path,
@@ -445,6 +585,10 @@ impl HcWebAppBundleSubcommand {
                 };
                 println!("Unpacked to directory {}", dir_path.to_string_lossy());
             }
+            Self::Inspect { path, skip_types } => {
+                let inspection = crate::inspect::inspect_web_app(&path, !skip_types).await?;
+                println!("{}", serde_json::to_string_pretty(&inspection)?);
+            }
             Self::Schema => {
                 println!(
                     "{}",
@@ -508,6 +652,7 @@ pub async fn web_app_pack_recursive(web_app_workdir_path: &PathBuf) -> anyhow::R
             path: ffs::canonicalize(app_workdir_location).await?,
             output: None,
             recursive: true,
+            sign: SignArgs::default(),
         }
         .run()
         .await?;
@@ -533,6 +678,7 @@ pub async fn app_pack_recursive(app_workdir_path: &PathBuf) -> anyhow::Result<()
             path: dna_workdir_location,
             output: None,
             dylib_ios: false,
+            sign: SignArgs::default(),
         }
         .run()
         .await?;
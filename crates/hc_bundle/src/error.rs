@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use holochain_serialized_bytes::SerializedBytesError;
 use holochain_util::ffs;
-use wasmer::{CompileError, SerializeError};
+use wasmer::{CompileError, InstantiationError, RuntimeError, SerializeError};
 
 /// HcBundleError type.
 #[derive(Debug, thiserror::Error)]
@@ -42,6 +42,26 @@ pub enum HcBundleError {
 
     #[error(transparent)]
     ModuleCompileError(#[from] CompileError),
+
+    /// The wasm of a zome could not be instantiated for inspection
+    #[error(transparent)]
+    ModuleInstantiationError(#[from] Box<InstantiationError>),
+
+    /// The wasm of a zome failed while being inspected
+    #[error(transparent)]
+    WasmRuntimeError(#[from] RuntimeError),
+
+    /// ZomeError
+    #[error("Zome error: {0}")]
+    ZomeError(#[from] holochain_types::prelude::ZomeError),
+
+    /// The Lair keystore could not be used to sign a bundle
+    #[error("Keystore error: {0}")]
+    KeystoreError(String),
+
+    /// A zome reported a number of types which doesn't fit in a u8
+    #[error("The zome function {0} returned an invalid number of types: {1}")]
+    TypeCountOutOfRange(String, i32),
 }
 
 /// HcBundle Result type.
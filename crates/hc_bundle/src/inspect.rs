@@ -0,0 +1,249 @@
+#![forbid(missing_docs)]
+
+//! Defines the CLI commands for inspecting DNA, hApp, and web-hApp bundles.
+
+use crate::error::{HcBundleError, HcBundleResult};
+use holochain_serialized_bytes::prelude::*;
+use holochain_types::prelude::*;
+use holochain_types::web_app::WebAppBundle;
+use std::path::Path;
+use std::sync::Arc;
+use wasmer::AsStoreMut;
+use wasmer::Function;
+use wasmer::Imports;
+use wasmer::Instance;
+use wasmer::Module;
+use wasmer::RuntimeError;
+use wasmer::Store;
+
+/// What a DNA bundle will produce when it is installed.
+#[derive(Debug, serde::Serialize)]
+pub struct DnaInspection {
+    /// The name of the DNA.
+    pub name: String,
+    /// The hash of the DNA, including any modifier overrides.
+    pub dna_hash: DnaHashB64,
+    /// The hash the DNA would have without the modifier overrides.
+    pub original_dna_hash: DnaHashB64,
+    /// The network seed of the DNA.
+    pub network_seed: String,
+    /// The properties of the DNA.
+    pub properties: serde_yaml::Value,
+    /// The origin time of the DNA.
+    pub origin_time: String,
+    /// The integrity zomes, in order.
+    pub integrity_zomes: Vec<ZomeInspection>,
+    /// The coordinator zomes, in order.
+    pub coordinator_zomes: Vec<ZomeInspection>,
+}
+
+/// A single zome of a DNA bundle.
+#[derive(Debug, serde::Serialize)]
+pub struct ZomeInspection {
+    /// The name of the zome.
+    pub name: ZomeName,
+    /// The hash of the zome's wasm.
+    pub wasm_hash: WasmHashB64,
+    /// The integrity zomes this zome depends on.
+    pub dependencies: Vec<ZomeName>,
+    /// The entry and link types defined by an integrity zome.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<ZomeTypesInspection>,
+}
+
+/// The entry and link types defined by an integrity zome, found by running
+/// its wasm.
+#[derive(Debug, serde::Serialize)]
+pub struct ZomeTypesInspection {
+    /// The number of entry types.
+    pub num_entry_types: u8,
+    /// The number of link types.
+    pub num_link_types: u8,
+    /// The definitions returned by the `entry_defs` callback.
+    pub entry_defs: Vec<EntryDef>,
+}
+
+/// What a hApp bundle will produce when it is installed.
+#[derive(Debug, serde::Serialize)]
+pub struct AppInspection {
+    /// The name of the app.
+    pub name: String,
+    /// The roles of the app, in order.
+    pub roles: Vec<RoleInspection>,
+}
+
+/// A single role of a hApp bundle.
+#[derive(Debug, serde::Serialize)]
+pub struct RoleInspection {
+    /// The name of the role.
+    pub name: RoleName,
+    /// How the role's cell is provisioned.
+    pub provisioning: Option<CellProvisioning>,
+    /// The role's DNA with the role's modifiers applied, if the role has
+    /// a DNA location.
+    pub dna: Option<DnaInspection>,
+}
+
+/// Inspect a `.dna` bundle file, applying the given modifier overrides.
+///
+/// If `run_wasm` is set, each integrity zome is run to find its entry and
+/// link types.
+pub async fn inspect_dna(
+    bundle_path: &Path,
+    modifiers: DnaModifiersOpt,
+    run_wasm: bool,
+) -> HcBundleResult<DnaInspection> {
+    let bundle = DnaBundle::read_from_file(bundle_path).await?;
+    inspect_dna_bundle(bundle, modifiers, run_wasm).await
+}
+
+/// Inspect a `.happ` bundle file.
+///
+/// If `run_wasm` is set, each integrity zome is run to find its entry and
+/// link types.
+pub async fn inspect_app(bundle_path: &Path, run_wasm: bool) -> HcBundleResult<AppInspection> {
+    let bundle: AppBundle = mr_bundle::Bundle::read_from_file(bundle_path).await?.into();
+    inspect_app_bundle(bundle, run_wasm).await
+}
+
+/// Inspect the hApp inside a `.webhapp` bundle file.
+///
+/// If `run_wasm` is set, each integrity zome is run to find its entry and
+/// link types.
+pub async fn inspect_web_app(bundle_path: &Path, run_wasm: bool) -> HcBundleResult<AppInspection> {
+    let bundle: WebAppBundle = mr_bundle::Bundle::read_from_file(bundle_path).await?.into();
+    inspect_app_bundle(bundle.happ_bundle().await?, run_wasm).await
+}
+
+async fn inspect_app_bundle(bundle: AppBundle, run_wasm: bool) -> HcBundleResult<AppInspection> {
+    let mut roles = Vec::new();
+    for role in bundle.manifest().app_roles() {
+        let dna = match &role.dna.location {
+            Some(location) => {
+                let bytes = bundle.resolve(location).await?;
+                let dna_bundle: DnaBundle = mr_bundle::Bundle::decode(&bytes)?.into();
+                let modifiers = role.dna.modifiers.clone().serialized()?;
+                Some(inspect_dna_bundle(dna_bundle, modifiers, run_wasm).await?)
+            }
+            None => None,
+        };
+        roles.push(RoleInspection {
+            name: role.name,
+            provisioning: role.provisioning,
+            dna,
+        });
+    }
+    Ok(AppInspection {
+        name: bundle.manifest().app_name().to_string(),
+        roles,
+    })
+}
+
+async fn inspect_dna_bundle(
+    bundle: DnaBundle,
+    modifiers: DnaModifiersOpt,
+    run_wasm: bool,
+) -> HcBundleResult<DnaInspection> {
+    let (dna_file, original_dna_hash) = bundle.into_dna_file(modifiers).await?;
+    let dna_def = dna_file.dna_def();
+
+    let mut integrity_zomes = Vec::new();
+    for (name, def) in &dna_def.integrity_zomes {
+        let types = if run_wasm {
+            let wasm = dna_file.get_wasm_for_zome(name)?;
+            Some(inspect_zome_types(&wasm.code())?)
+        } else {
+            None
+        };
+        integrity_zomes.push(ZomeInspection {
+            name: name.clone(),
+            wasm_hash: def.wasm_hash(name)?.into(),
+            dependencies: def.as_any_zome_def().dependencies().to_vec(),
+            types,
+        });
+    }
+    let coordinator_zomes = dna_def
+        .coordinator_zomes
+        .iter()
+        .map(|(name, def)| {
+            Ok(ZomeInspection {
+                name: name.clone(),
+                wasm_hash: def.wasm_hash(name)?.into(),
+                dependencies: def.as_any_zome_def().dependencies().to_vec(),
+                types: None,
+            })
+        })
+        .collect::<HcBundleResult<_>>()?;
+
+    let properties = YamlProperties::try_from(dna_def.modifiers.properties.clone())?;
+
+    Ok(DnaInspection {
+        name: dna_def.name.clone(),
+        dna_hash: dna_file.dna_hash().clone().into(),
+        original_dna_hash: original_dna_hash.into(),
+        network_seed: dna_def.modifiers.network_seed.clone(),
+        properties: properties.into_inner(),
+        origin_time: dna_def.modifiers.origin_time.to_string(),
+        integrity_zomes,
+        coordinator_zomes,
+    })
+}
+
+/// Run an integrity zome's wasm to find the entry and link types it defines.
+///
+/// The zome is run without a conductor, so any host function it calls
+/// while defining its types will fail.
+fn inspect_zome_types(wasm: &[u8]) -> HcBundleResult<ZomeTypesInspection> {
+    let mut store = Store::new(cranelift());
+    let module = Module::from_binary(&store, wasm)?;
+
+    let mut imports = Imports::new();
+    for import in module.imports().functions() {
+        let name = import.name().to_string();
+        let function = Function::new(&mut store, import.ty(), move |_| {
+            Err(RuntimeError::new(format!(
+                "Host function {} is not available while inspecting a bundle",
+                name
+            )))
+        });
+        imports.define(import.module(), import.name(), function);
+    }
+    let instance = Arc::new(Instance::new(&mut store, &module, &imports).map_err(Box::new)?);
+
+    let num_entry_types = call_const_fn(&mut store, &instance, "__num_entry_types")?;
+    let num_link_types = call_const_fn(&mut store, &instance, "__num_link_types")?;
+
+    let entry_defs = if instance.exports.get_function("entry_defs").is_ok() {
+        let result: ExternIO = holochain_wasmer_host::guest::call(
+            &mut store.as_store_mut(),
+            instance.clone(),
+            "entry_defs",
+            ExternIO::encode(())?,
+        )?;
+        match result.decode()? {
+            EntryDefsCallbackResult::Defs(EntryDefs(defs)) => defs,
+        }
+    } else {
+        Vec::new()
+    };
+
+    Ok(ZomeTypesInspection {
+        num_entry_types,
+        num_link_types,
+        entry_defs,
+    })
+}
+
+/// Call one of the constant functions which integrity zomes export to count
+/// their types, treating a missing function as no types.
+fn call_const_fn(store: &mut Store, instance: &Instance, name: &str) -> HcBundleResult<u8> {
+    match instance.exports.get_typed_function::<(), i32>(store, name) {
+        Ok(func) => {
+            let count = func.call(store)?;
+            Ok(count
+                .try_into()
+                .map_err(|_| HcBundleError::TypeCountOutOfRange(name.to_string(), count))?)
+        }
+        Err(_) => Ok(0),
+    }
+}
@@ -1,10 +1,17 @@
 mod cli;
 mod error;
 mod init;
+mod inspect;
 mod packing;
+mod signing;
 
 pub use cli::{
     app_pack_recursive, bundled_dnas_workdir_locations, get_app_name, get_dna_name,
-    get_web_app_name, web_app_pack_recursive, HcAppBundle, HcDnaBundle, HcWebAppBundle,
+    get_web_app_name, web_app_pack_recursive, HcAppBundle, HcDnaBundle, HcWebAppBundle, SignArgs,
+};
+pub use inspect::{
+    inspect_app, inspect_dna, inspect_web_app, AppInspection, DnaInspection, RoleInspection,
+    ZomeInspection, ZomeTypesInspection,
 };
 pub use packing::{pack, unpack, unpack_raw};
+pub use signing::{connect_keystore, sign_bundle};
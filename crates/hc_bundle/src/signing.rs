@@ -0,0 +1,52 @@
+#![forbid(missing_docs)]
+
+//! Signing of packed DNA, hApp, and web-hApp bundles with a key held by Lair.
+
+use crate::error::{HcBundleError, HcBundleResult};
+use holochain_keystore::MetaLairClient;
+use holochain_types::prelude::AgentPubKey;
+use mr_bundle::signature::{detached_signature_path, write_detached_signatures};
+use mr_bundle::{Bundle, BundleSignature, Manifest};
+use std::path::{Path, PathBuf};
+
+/// Connect to a running Lair keystore, reading its passphrase from the tty,
+/// or from stdin if `piped` is set.
+pub async fn connect_keystore(
+    connection_url: url2::Url2,
+    piped: bool,
+) -> HcBundleResult<MetaLairClient> {
+    holochain_util::pw::pw_set_piped(piped);
+    let passphrase = holochain_util::pw::pw_get()?;
+    holochain_keystore::lair_keystore::spawn_lair_keystore(connection_url, passphrase)
+        .await
+        .map_err(|e| HcBundleError::KeystoreError(e.to_string()))
+}
+
+/// Sign the contents of the bundle file at `bundle_path`, as they are stored
+/// in it, with the key of the given agent, returning the path of the file the
+/// signature was written to.
+///
+/// The signature is embedded into the bundle file, unless `detached` is set,
+/// in which case it is written to a `.sig` file next to the bundle file.
+pub async fn sign_bundle<M: Manifest>(
+    bundle_path: &Path,
+    keystore: &MetaLairClient,
+    signer: AgentPubKey,
+    detached: bool,
+) -> HcBundleResult<PathBuf> {
+    let mut bundle = Bundle::<M>::read_from_file(bundle_path).await?;
+    let signature = keystore
+        .sign(signer.clone(), bundle.canonical_bytes()?.into())
+        .await
+        .map_err(|e| HcBundleError::KeystoreError(e.to_string()))?;
+    let signature = BundleSignature::new(signer.get_raw_32(), signature.0.to_vec());
+    if detached {
+        let signature_path = detached_signature_path(bundle_path);
+        write_detached_signatures(&signature_path, &[signature]).await?;
+        Ok(signature_path)
+    } else {
+        bundle.add_signature(signature)?;
+        bundle.write_to_file(bundle_path).await?;
+        Ok(bundle_path.to_owned())
+    }
+}
@@ -233,6 +233,7 @@ async fn test_multi_integrity() {
                 .into(),
             ),
         ],
+        lineage: Default::default(),
     };
     assert_eq!(
         dna.dna_def().integrity_zomes[0]
@@ -265,6 +266,220 @@ async fn test_multi_integrity() {
     assert_eq!(*dna.dna_def(), expected);
 }
 
+#[tokio::test]
+/// Test that `inspect` reports the hashes which installing the bundles will produce.
+async fn test_inspect() {
+    let mut cmd = Command::cargo_bin("hc-dna").unwrap();
+    let cmd = cmd.args(["pack", "tests/fixtures/my-app/dnas/dna5"]);
+    cmd.assert().success();
+    let dna_path = "tests/fixtures/my-app/dnas/dna5/multi integrity dna.dna";
+
+    let inspect_dna = |network_seed: Option<&str>| {
+        let mut cmd = Command::cargo_bin("hc-dna").unwrap();
+        cmd.args(["inspect", dna_path, "--skip-types"]);
+        if let Some(network_seed) = network_seed {
+            cmd.args(["--network-seed", network_seed]);
+        }
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<Value>(&output.stdout).unwrap()
+    };
+
+    let (dna, _) = read_dna(Path::new(dna_path))
+        .unwrap()
+        .into_dna_file(DnaModifiersOpt::none())
+        .await
+        .unwrap();
+    let inspection = inspect_dna(None);
+    assert_eq!(
+        inspection["dna_hash"],
+        DnaHashB64::from(dna.dna_hash().clone()).to_string()
+    );
+    assert_eq!(inspection["dna_hash"], inspection["original_dna_hash"]);
+    assert_eq!(inspection["coordinator_zomes"][1]["name"], "zome4");
+    assert_eq!(
+        inspection["coordinator_zomes"][1]["dependencies"],
+        serde_json::json!(["zome1", "zome2"])
+    );
+    assert!(inspection["integrity_zomes"][0].get("types").is_none());
+
+    let (dna, _) = read_dna(Path::new(dna_path))
+        .unwrap()
+        .into_dna_file(DnaModifiersOpt::none().with_network_seed("abc".into()))
+        .await
+        .unwrap();
+    let modified = inspect_dna(Some("abc"));
+    assert_eq!(
+        modified["dna_hash"],
+        DnaHashB64::from(dna.dna_hash().clone()).to_string()
+    );
+    assert_eq!(modified["original_dna_hash"], inspection["dna_hash"]);
+    assert_eq!(modified["network_seed"], "abc");
+}
+
+#[tokio::test]
+/// Test that `inspect` runs the integrity zomes to report their entry and link types.
+async fn test_inspect_types() {
+    let dir = tempfile::tempdir().unwrap();
+    let wasm = wat::parse_str(
+        r#"(module
+            (func (export "__num_entry_types") (result i32) i32.const 2)
+            (func (export "__num_link_types") (result i32) i32.const 3))"#,
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("types.wasm"), wasm).unwrap();
+    std::fs::write(
+        dir.path().join("dna.yaml"),
+        r#"---
+manifest_version: "1"
+name: types dna
+integrity:
+  network_seed: ~
+  origin_time: 2022-02-11T23:29:00.789576Z
+  properties: ~
+  zomes:
+    - name: types
+      bundled: ./types.wasm
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("hc-dna").unwrap();
+    cmd.arg("pack").arg(dir.path());
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("hc-dna").unwrap();
+    cmd.arg("inspect").arg(dir.path().join("types dna.dna"));
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let inspection = serde_json::from_slice::<Value>(&output.stdout).unwrap();
+
+    let types = &inspection["integrity_zomes"][0]["types"];
+    assert_eq!(types["num_entry_types"], 2);
+    assert_eq!(types["num_link_types"], 3);
+    assert_eq!(types["entry_defs"], serde_json::json!([]));
+}
+
+#[tokio::test]
+/// Test that `hc app inspect` and `hc web-app inspect` report the hash of each
+/// role's DNA with the role's modifiers applied.
+async fn test_inspect_app_and_web_app() {
+    for dna in [
+        "tests/fixtures/my-app/dnas/dna1",
+        "tests/fixtures/my-app/dnas/dna2",
+    ] {
+        let mut cmd = Command::cargo_bin("hc-dna").unwrap();
+        cmd.args(["pack", dna]).assert().success();
+    }
+    let mut cmd = Command::cargo_bin("hc-app").unwrap();
+    cmd.args(["pack", "tests/fixtures/my-app/"])
+        .assert()
+        .success();
+    let mut cmd = Command::cargo_bin("hc-web-app").unwrap();
+    cmd.args(["pack", "tests/fixtures/web-app/"])
+        .assert()
+        .success();
+
+    let app_path = "tests/fixtures/my-app/fixture-app.happ";
+    let bundle = read_app(Path::new(app_path)).unwrap();
+    let mut expected = Vec::new();
+    for role in bundle.manifest().app_roles() {
+        let location = role.dna.location.as_ref().unwrap();
+        let dna_bundle: DnaBundle =
+            mr_bundle::Bundle::decode(&bundle.resolve(location).await.unwrap())
+                .unwrap()
+                .into();
+        let (dna, _) = dna_bundle
+            .into_dna_file(role.dna.modifiers.serialized().unwrap())
+            .await
+            .unwrap();
+        expected.push((
+            role.name.clone(),
+            DnaHashB64::from(dna.dna_hash().clone()).to_string(),
+        ));
+    }
+    assert_eq!(expected.len(), 2);
+
+    let inspect = |bin: &str, path: &str| {
+        let mut cmd = Command::cargo_bin(bin).unwrap();
+        cmd.args(["inspect", path, "--skip-types"]);
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<Value>(&output.stdout).unwrap()
+    };
+    let app = inspect("hc-app", app_path);
+    let web_app = inspect(
+        "hc-web-app",
+        "tests/fixtures/web-app/fixture-web-app.webhapp",
+    );
+    assert_eq!(app, web_app);
+    assert_eq!(app["name"], "fixture-app");
+    for (role, (name, dna_hash)) in app["roles"].as_array().unwrap().iter().zip(&expected) {
+        assert_eq!(role["name"], name.as_str());
+        assert_eq!(role["dna"]["dna_hash"], dna_hash.as_str());
+    }
+    assert_ne!(
+        app["roles"][1]["dna"]["dna_hash"],
+        app["roles"][1]["dna"]["original_dna_hash"]
+    );
+}
+
+#[tokio::test]
+async fn test_sign_bundle() {
+    let keystore = holochain_keystore::spawn_mem_keystore().await.unwrap();
+    let signer = keystore.new_sign_keypair_random().await.unwrap();
+    let trusted = signer.get_raw_32().try_into().unwrap();
+    let tmp = tempfile::tempdir().unwrap();
+
+    // Embedded
+    let (bundle_path, _) = holochain_cli_bundle::pack::<ValidatedDnaManifest>(
+        Path::new("tests/fixtures/my-app/dnas/dna1"),
+        Some(tmp.path().join("embedded.dna")),
+        "embedded".into(),
+        false,
+    )
+    .await
+    .unwrap();
+    let signature_path = holochain_cli_bundle::sign_bundle::<ValidatedDnaManifest>(
+        &bundle_path,
+        &keystore,
+        signer.clone(),
+        false,
+    )
+    .await
+    .unwrap();
+    assert_eq!(signature_path, bundle_path);
+    let signed = read_dna(&bundle_path).unwrap();
+    assert_eq!(signed.signatures().len(), 1);
+    signed.verify_signed_by(&[trusted], &[]).unwrap();
+
+    // Detached
+    let (bundle_path, _) = holochain_cli_bundle::pack::<ValidatedDnaManifest>(
+        Path::new("tests/fixtures/my-app/dnas/dna1"),
+        Some(tmp.path().join("detached.dna")),
+        "detached".into(),
+        false,
+    )
+    .await
+    .unwrap();
+    let signature_path = holochain_cli_bundle::sign_bundle::<ValidatedDnaManifest>(
+        &bundle_path,
+        &keystore,
+        signer,
+        true,
+    )
+    .await
+    .unwrap();
+    assert_eq!(signature_path, tmp.path().join("detached.dna.sig"));
+    let unsigned = read_dna(&bundle_path).unwrap();
+    assert!(unsigned.signatures().is_empty());
+    assert!(unsigned.verify_signed_by(&[trusted], &[]).is_err());
+    let detached = mr_bundle::signature::read_detached_signatures(&signature_path)
+        .await
+        .unwrap();
+    unsigned.verify_signed_by(&[trusted], &detached).unwrap();
+}
+
 #[test]
 fn test_all_dna_manifests_match_schema() {
     let schema = load_schema("dna-manifest");
@@ -292,6 +507,7 @@ fn test_default_dna_manifest_matches_schema() {
         Timestamp::now().into(),
         vec![],
         vec![],
+        vec![],
     );
 
     let default_manifest: Value =
@@ -0,0 +1,425 @@
+//! A reference HTTP server implementing the Chain Head Coordinator protocol.
+//!
+//! This speaks the same protocol as the `ChcRemote` client in the `holochain`
+//! crate. Each chain is addressed by `/{dna_hash}/{agent_pubkey}/` and exposes
+//! two endpoints, both of which accept a msgpack-encoded POST body:
+//!
+//! - `add_records`: append records to the chain. Returns 200 on success,
+//!   409 with the encoded `(seq, hash)` of the current head if the records
+//!   don't follow on from that head, or 498 with the encoded `seq` of the first
+//!   record if they don't constitute a valid chain at all.
+//! - `get_record_data`: get all records after (not including) the `since_hash`,
+//!   or the entire chain if no hash is given. Returns 498 if the `since_hash`
+//!   is not part of the chain.
+//!
+//! All signatures are checked against the agent in the path, and records are
+//! persisted in SQLite, keyed by DNA hash and agent.
+
+// Fixes some warnings introduced by `warp`
+#![allow(opaque_hidden_inferred_bound)]
+
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+
+use holochain_keystore::AgentPubKeyExt;
+use holochain_types::chain::ChainItem;
+use holochain_types::prelude::*;
+use rusqlite::{named_params, OptionalExtension};
+use warp::http::StatusCode;
+use warp::hyper::body::Bytes;
+use warp::Filter;
+
+/// Records can carry entries, so we allow much larger bodies than the bootstrap server does.
+const SIZE_LIMIT: u64 = 64 * 1024 * 1024;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS ChcRecord (
+    dna_hash        BLOB    NOT NULL,
+    agent           BLOB    NOT NULL,
+    seq             INTEGER NOT NULL,
+    action_hash     BLOB    NOT NULL,
+    signed_action   BLOB    NOT NULL,
+    encrypted_entry BLOB    NULL,
+    PRIMARY KEY (dna_hash, agent, seq)
+);
+CREATE INDEX IF NOT EXISTS ChcRecord_action_hash_idx ON ChcRecord(dna_hash, agent, action_hash);
+";
+
+/// The future which drives the CHC server.
+pub type ChcDriver = futures::future::BoxFuture<'static, ()>;
+
+/// Errors which can occur while handling a CHC request.
+#[derive(Debug, thiserror::Error)]
+pub enum ChcServerError {
+    /// A hash in the request path could not be parsed.
+    #[error("Invalid hash in path: {0}")]
+    InvalidPath(String),
+
+    /// The request body could not be decoded.
+    #[error(transparent)]
+    SerializationError(#[from] SerializedBytesError),
+
+    /// Signature verification could not be performed.
+    #[error(transparent)]
+    KeystoreError(#[from] holochain_keystore::KeystoreError),
+
+    /// A signature did not match the agent of the chain.
+    #[error("Invalid signature for action at seq {0}")]
+    InvalidSignature(u32),
+
+    /// The signature of a `get_record_data` request did not match the agent of the chain.
+    #[error("Invalid signature for get_record_data request")]
+    InvalidRequestSignature,
+
+    /// An action was authored by an agent other than the one owning the chain.
+    #[error("Action at seq {0} was not authored by the owner of this chain")]
+    WrongAuthor(u32),
+
+    /// The records would fork the chain. Contains the current head.
+    #[error("Chain head has moved, head is at seq {0} with hash {1}")]
+    InvalidChain(u32, ActionHash),
+
+    /// The records do not form a valid chain. Contains the seq of the first record.
+    #[error("Invalid add_records payload, starting at seq {0}")]
+    NoRecordsAdded(u32),
+
+    /// The `since_hash` of a `get_record_data` request is not in the chain.
+    #[error("Hash not found in chain: {0}")]
+    HashNotFound(ActionHash),
+
+    /// The database could not be accessed.
+    #[error(transparent)]
+    DatabaseError(#[from] rusqlite::Error),
+}
+
+/// Result type for the CHC server.
+pub type ChcServerResult<T> = Result<T, ChcServerError>;
+
+impl ChcServerError {
+    fn into_response(self) -> warp::reply::Response {
+        let (status, body) = match &self {
+            Self::InvalidChain(seq, hash) => (
+                StatusCode::CONFLICT,
+                holochain_serialized_bytes::encode(&(seq, hash)).ok(),
+            ),
+            Self::NoRecordsAdded(seq) => (
+                chc_status_498(),
+                holochain_serialized_bytes::encode(seq).ok(),
+            ),
+            Self::HashNotFound(_) => (chc_status_498(), None),
+            Self::InvalidSignature(_) | Self::InvalidRequestSignature | Self::WrongAuthor(_) => {
+                (StatusCode::UNAUTHORIZED, None)
+            }
+            Self::InvalidPath(_) | Self::SerializationError(_) => (StatusCode::BAD_REQUEST, None),
+            Self::KeystoreError(_) | Self::DatabaseError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, None)
+            }
+        };
+        let body = body.unwrap_or_else(|| self.to_string().into_bytes());
+        let mut response = warp::reply::Response::new(body.into());
+        *response.status_mut() = status;
+        response
+    }
+}
+
+/// The nonstandard status code used by the CHC protocol to signal that the
+/// request could not be applied to the chain.
+fn chc_status_498() -> StatusCode {
+    StatusCode::from_u16(498).expect("498 is a valid status code")
+}
+
+/// Persistent storage of CHC records for any number of chains.
+#[derive(Clone)]
+pub struct ChcStore(Arc<parking_lot::Mutex<rusqlite::Connection>>);
+
+impl ChcStore {
+    /// Open a store backed by a SQLite database at the given path,
+    /// creating it if it doesn't exist.
+    pub fn open(path: &Path) -> ChcServerResult<Self> {
+        Self::init(rusqlite::Connection::open(path)?)
+    }
+
+    /// Open a store which only lives in memory, for testing.
+    pub fn in_memory() -> ChcServerResult<Self> {
+        Self::init(rusqlite::Connection::open_in_memory()?)
+    }
+
+    fn init(conn: rusqlite::Connection) -> ChcServerResult<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self(Arc::new(parking_lot::Mutex::new(conn))))
+    }
+
+    /// Validate and append records to the chain for this cell.
+    ///
+    /// This has the same semantics as the `ChcLocal` used in tests:
+    /// if the records don't follow on from the current head, nothing is
+    /// added and the current head is returned in the error.
+    pub async fn add_records(
+        &self,
+        cell_id: &CellId,
+        request: AddRecordsRequest,
+    ) -> ChcServerResult<()> {
+        let mut records = Vec::with_capacity(request.len());
+        for payload in request {
+            records.push(verify_payload(cell_id.agent_pubkey(), payload).await?);
+        }
+
+        let mut conn = self.0.lock();
+        let txn = conn.transaction()?;
+        let head = chain_head(&txn, cell_id)?;
+        check_chain(records.iter().map(|(action, _)| action), &head)?;
+        for (action, encrypted_entry) in records {
+            txn.execute(
+                "INSERT INTO ChcRecord (dna_hash, agent, seq, action_hash, signed_action, encrypted_entry)
+                VALUES (:dna_hash, :agent, :seq, :action_hash, :signed_action, :encrypted_entry)",
+                named_params! {
+                    ":dna_hash": cell_id.dna_hash().get_raw_39(),
+                    ":agent": cell_id.agent_pubkey().get_raw_39(),
+                    ":seq": action.seq(),
+                    ":action_hash": action.get_hash().get_raw_39(),
+                    ":signed_action": holochain_serialized_bytes::encode(&action)?,
+                    ":encrypted_entry": encrypted_entry
+                        .map(|e| holochain_serialized_bytes::encode(&e))
+                        .transpose()?,
+                },
+            )?;
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// Get the records for this cell after (not including) the `since_hash`,
+    /// or all records if no hash is given.
+    pub async fn get_record_data(
+        &self,
+        cell_id: &CellId,
+        request: GetRecordsRequest,
+    ) -> ChcServerResult<Vec<(SignedActionHashed, Option<(Arc<EncryptedEntry>, Signature)>)>> {
+        let payload = holochain_serialized_bytes::encode(&request.payload)?;
+        if !cell_id
+            .agent_pubkey()
+            .verify_signature_raw(&request.signature, payload.into())
+            .await?
+        {
+            return Err(ChcServerError::InvalidRequestSignature);
+        }
+
+        let conn = self.0.lock();
+        let since_seq: Option<u32> = match &request.payload.since_hash {
+            Some(hash) => Some(
+                conn.query_row(
+                    "SELECT seq FROM ChcRecord
+                    WHERE dna_hash = :dna_hash AND agent = :agent AND action_hash = :action_hash",
+                    named_params! {
+                        ":dna_hash": cell_id.dna_hash().get_raw_39(),
+                        ":agent": cell_id.agent_pubkey().get_raw_39(),
+                        ":action_hash": hash.get_raw_39(),
+                    },
+                    |row| row.get(0),
+                )
+                .optional()?
+                .ok_or_else(|| ChcServerError::HashNotFound(hash.clone()))?,
+            ),
+            None => None,
+        };
+
+        let mut stmt = conn.prepare(
+            "SELECT signed_action, encrypted_entry FROM ChcRecord
+            WHERE dna_hash = :dna_hash AND agent = :agent AND seq > :since_seq
+            ORDER BY seq ASC",
+        )?;
+        let rows = stmt.query_map(
+            named_params! {
+                ":dna_hash": cell_id.dna_hash().get_raw_39(),
+                ":agent": cell_id.agent_pubkey().get_raw_39(),
+                ":since_seq": since_seq.map(i64::from).unwrap_or(-1),
+            },
+            |row| Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, Option<Vec<u8>>>(1)?)),
+        )?;
+        let mut records = Vec::new();
+        for row in rows {
+            let (action, entry) = row?;
+            records.push((
+                holochain_serialized_bytes::decode(&action)?,
+                entry
+                    .map(|e| holochain_serialized_bytes::decode(&e))
+                    .transpose()?,
+            ));
+        }
+        Ok(records)
+    }
+}
+
+/// Check all signatures of a payload, returning the decoded record.
+async fn verify_payload(
+    agent: &AgentPubKey,
+    payload: AddRecordPayload,
+) -> ChcServerResult<(SignedActionHashed, Option<(Arc<EncryptedEntry>, Signature)>)> {
+    let AddRecordPayload {
+        signed_action_msgpack,
+        signed_action_signature,
+        encrypted_entry,
+    } = payload;
+    let signed_action: SignedActionHashed =
+        holochain_serialized_bytes::decode(&signed_action_msgpack)?;
+    let seq = signed_action.seq();
+    let action = signed_action.action();
+
+    if action.author() != agent {
+        return Err(ChcServerError::WrongAuthor(seq));
+    }
+    // The claimed hash must match the action itself.
+    if ActionHash::with_data_sync(action) != *signed_action.action_address() {
+        return Err(ChcServerError::InvalidSignature(seq));
+    }
+    if !agent
+        .verify_signature(signed_action.signature(), action)
+        .await?
+    {
+        return Err(ChcServerError::InvalidSignature(seq));
+    }
+    if !agent
+        .verify_signature_raw(&signed_action_signature, signed_action_msgpack.into())
+        .await?
+    {
+        return Err(ChcServerError::InvalidSignature(seq));
+    }
+    if let Some((entry, signature)) = &encrypted_entry {
+        if !agent
+            .verify_signature_raw(signature, entry.0.clone().into())
+            .await?
+        {
+            return Err(ChcServerError::InvalidSignature(seq));
+        }
+    }
+    Ok((signed_action, encrypted_entry))
+}
+
+/// Get the hash and seq of the last record of a chain, if any.
+fn chain_head(
+    conn: &rusqlite::Connection,
+    cell_id: &CellId,
+) -> ChcServerResult<Option<(ActionHash, u32)>> {
+    Ok(conn
+        .query_row(
+            "SELECT action_hash, seq FROM ChcRecord
+            WHERE dna_hash = :dna_hash AND agent = :agent
+            ORDER BY seq DESC LIMIT 1",
+            named_params! {
+                ":dna_hash": cell_id.dna_hash().get_raw_39(),
+                ":agent": cell_id.agent_pubkey().get_raw_39(),
+            },
+            |row| Ok((ActionHash::from_raw_39_panicky(row.get(0)?), row.get(1)?)),
+        )
+        .optional()?)
+}
+
+/// Check that the actions form a chain which follows on from the current head.
+fn check_chain<'a>(
+    mut actions: impl Iterator<Item = &'a SignedActionHashed>,
+    head: &Option<(ActionHash, u32)>,
+) -> ChcServerResult<()> {
+    let mut prev = head.clone();
+    let first = match actions.next() {
+        Some(first) => first,
+        None => return Ok(()),
+    };
+    let err = || match head {
+        Some((hash, seq)) => ChcServerError::InvalidChain(*seq, hash.clone()),
+        None => ChcServerError::NoRecordsAdded(first.seq()),
+    };
+    for action in std::iter::once(first).chain(actions) {
+        let follows = match &prev {
+            Some((prev_hash, prev_seq)) => {
+                action.prev_hash() == Some(prev_hash) && prev_seq.checked_add(1) == Some(action.seq())
+            }
+            None => action.prev_hash().is_none() && action.seq() == 0,
+        };
+        if !follows {
+            return Err(err());
+        }
+        prev = Some((action.get_hash().clone(), action.seq()));
+    }
+    Ok(())
+}
+
+/// Run a CHC server on the given address, persisting records in the given store.
+pub fn run(
+    addr: impl Into<SocketAddr> + 'static,
+    store: ChcStore,
+) -> Result<(ChcDriver, SocketAddr), String> {
+    let add_records = warp::post()
+        .and(warp::path!(String / String / "add_records"))
+        .and(warp::body::content_length_limit(SIZE_LIMIT))
+        .and(warp::body::bytes())
+        .and(with_store(store.clone()))
+        .then(handle_add_records);
+
+    let get_record_data = warp::post()
+        .and(warp::path!(String / String / "get_record_data"))
+        .and(warp::body::content_length_limit(SIZE_LIMIT))
+        .and(warp::body::bytes())
+        .and(with_store(store))
+        .then(handle_get_record_data);
+
+    match warp::serve(add_records.or(get_record_data)).try_bind_ephemeral(addr) {
+        Ok((addr, server)) => Ok((futures::future::FutureExt::boxed(server), addr)),
+        Err(e) => Err(format!("Failed to bind socket: {:?}", e)),
+    }
+}
+
+fn with_store(
+    store: ChcStore,
+) -> impl Filter<Extract = (ChcStore,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || store.clone())
+}
+
+async fn handle_add_records(
+    dna_hash: String,
+    agent: String,
+    body: Bytes,
+    store: ChcStore,
+) -> warp::reply::Response {
+    let result = async {
+        let cell_id = parse_cell_id(&dna_hash, &agent)?;
+        let request: AddRecordsRequest = holochain_serialized_bytes::decode(&body)?;
+        store.add_records(&cell_id, request).await
+    };
+    match result.await {
+        Ok(()) => warp::reply::Response::default(),
+        Err(e) => {
+            tracing::debug!(?e, "add_records failed");
+            e.into_response()
+        }
+    }
+}
+
+async fn handle_get_record_data(
+    dna_hash: String,
+    agent: String,
+    body: Bytes,
+    store: ChcStore,
+) -> warp::reply::Response {
+    let result = async {
+        let cell_id = parse_cell_id(&dna_hash, &agent)?;
+        let request: GetRecordsRequest = holochain_serialized_bytes::decode(&body)?;
+        let records = store.get_record_data(&cell_id, request).await?;
+        Ok::<_, ChcServerError>(holochain_serialized_bytes::encode(&records)?)
+    };
+    match result.await {
+        Ok(body) => warp::reply::Response::new(body.into()),
+        Err(e) => {
+            tracing::debug!(?e, "get_record_data failed");
+            e.into_response()
+        }
+    }
+}
+
+fn parse_cell_id(dna_hash: &str, agent: &str) -> ChcServerResult<CellId> {
+    let dna_hash = DnaHash::try_from(dna_hash)
+        .map_err(|_| ChcServerError::InvalidPath(dna_hash.to_string()))?;
+    let agent =
+        AgentPubKey::try_from(agent).map_err(|_| ChcServerError::InvalidPath(agent.to_string()))?;
+    Ok(CellId::new(dna_hash, agent))
+}
@@ -2,6 +2,8 @@ use clap::Parser;
 use tokio::io::AsyncWriteExt;
 use tx5_signal_srv::{Error, Result};
 
+pub mod chc;
+
 /// Helper for running local Holochain bootstrap and WebRTC signal servers.
 #[derive(Debug, Parser)]
 #[command(version, about)]
@@ -41,6 +43,31 @@ pub struct HcRunLocalServices {
     /// Disable running a signal server.
     #[arg(long)]
     disable_signal: bool,
+
+    /// If set, write the URLs of the chain head coordinator server to a new
+    /// file, one per bound address separated by newlines. Any of them can be
+    /// used as the `chc_url` in a conductor config. If the file exists, an
+    /// error will be returned.
+    #[arg(long)]
+    chc_address_path: Option<std::path::PathBuf>,
+
+    /// A single interface on which to run the chain head coordinator server.
+    #[arg(long, default_value = "127.0.0.1")]
+    chc_interface: String,
+
+    /// The port to use for the chain head coordinator server. You probably want
+    /// to leave this as 0 (zero) to be assigned an available port.
+    #[arg(long, default_value = "0")]
+    chc_port: u16,
+
+    /// The SQLite database file in which the chain head coordinator persists
+    /// records. If not set, records are only kept in memory.
+    #[arg(long)]
+    chc_database_path: Option<std::path::PathBuf>,
+
+    /// Run a chain head coordinator server.
+    #[arg(long)]
+    enable_chc: bool,
 }
 
 struct AOut(Option<tokio::fs::File>);
@@ -87,6 +114,11 @@ impl HcRunLocalServices {
         signal_interfaces: String,
         signal_port: u16,
         disable_signal: bool,
+        chc_address_path: Option<std::path::PathBuf>,
+        chc_interface: String,
+        chc_port: u16,
+        chc_database_path: Option<std::path::PathBuf>,
+        enable_chc: bool,
     ) -> Self {
         Self {
             bootstrap_address_path,
@@ -97,6 +129,11 @@ impl HcRunLocalServices {
             signal_interfaces,
             signal_port,
             disable_signal,
+            chc_address_path,
+            chc_interface,
+            chc_port,
+            chc_database_path,
+            enable_chc,
         }
     }
 
@@ -157,6 +194,29 @@ impl HcRunLocalServices {
             println!("# HC SIGNAL - RUNNING");
         }
 
+        if self.enable_chc {
+            let chc_ip: std::net::IpAddr = self.chc_interface.parse().map_err(Error::err)?;
+            let chc_addr = std::net::SocketAddr::from((chc_ip, self.chc_port));
+            let store = match &self.chc_database_path {
+                Some(path) => chc::ChcStore::open(path),
+                None => chc::ChcStore::in_memory(),
+            }
+            .map_err(Error::err)?;
+            let (chc_driver, chc_addr) = chc::run(chc_addr, store).map_err(Error::str)?;
+            task_list.push(chc_driver);
+
+            let mut a_out = AOut::new(&self.chc_address_path).await?;
+
+            for addr in tx_addr(chc_addr)? {
+                a_out.write(format!("http://{addr}\n")).await?;
+                println!("# HC CHC - ADDR: http://{addr}");
+            }
+
+            a_out.close().await?;
+
+            println!("# HC CHC - RUNNING");
+        }
+
         if task_list.is_empty() {
             println!("All Services Disabled - Aborting");
             return Ok(());
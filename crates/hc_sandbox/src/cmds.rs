@@ -65,6 +65,14 @@ pub struct Network {
     /// prior knowledge of each other.
     #[arg(short, long, value_parser = try_parse_url2)]
     pub bootstrap: Option<Url2>,
+
+    /// Also discover peers on the local network via mDNS.
+    /// Peers on the same LAN can then find each other even if the
+    /// bootstrap service can't be reached.
+    /// Note that WebRTC connections still need a signal server
+    /// which both peers can reach.
+    #[arg(long)]
+    pub mdns: bool,
 }
 
 #[derive(Debug, Parser, Clone)]
@@ -194,9 +202,16 @@ impl From<Network> for KitsuneP2pConfig {
         let Network {
             transport,
             bootstrap,
+            mdns,
         } = n;
         let mut kit = KitsuneP2pConfig::default();
         kit.bootstrap_service = bootstrap;
+        if mdns {
+            kit = kit.tune(|mut tp| {
+                tp.mdns_discovery = true;
+                tp
+            });
+        }
 
         match transport {
             NetworkType::Mem => (),
@@ -1,9 +1,67 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use aitia::{simple_report, Fact};
 
 use super::*;
 
+/// The environment variable naming a directory to write report files into,
+/// e.g. to be collected as CI artifacts.
+pub const REPORT_DIR_ENV_VAR: &str = "HC_SLEUTH_REPORT_DIR";
+
 pub fn report(event: Event, ctx: &Context) {
-    if let Some(report) = simple_report(&event.traverse(ctx)) {
+    let tr = event.traverse(ctx);
+    if let Some(report) = simple_report(&tr) {
         println!("hc_sleuth simple report:\n{report}");
+        if let Some(dir) = std::env::var_os(REPORT_DIR_ENV_VAR) {
+            match write_report_files(&tr, Path::new(&dir)) {
+                Ok(stem) => println!("hc_sleuth report files written to {}.*", stem.display()),
+                Err(err) => tracing::error!(?err, "Could not write hc_sleuth report files"),
+            }
+        }
     }
 }
+
+/// Write a report of a traversal into a directory, which doesn't need any
+/// extra tools to view:
+/// - `report-<id>.txt`: the simple report, including an ASCII tree of the graph
+/// - `report-<id>.svg` and `report-<id>.html`: a drawing of the graph
+/// - `report-<id>.json`: each node of the traversal and whether it passed, if
+///   the traversal completed
+///
+/// The id is unique to each report, so that several reports, even from
+/// different processes, can be written into the same directory. Returns the
+/// path of the report files without their extension.
+pub fn write_report_files(
+    tr: &aitia::TraversalResult<'_, Event>,
+    dir: &Path,
+) -> std::io::Result<PathBuf> {
+    static REPORT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    std::fs::create_dir_all(dir)?;
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let stem = dir.join(format!(
+        "report-{}-{}-{}",
+        millis,
+        std::process::id(),
+        REPORT_COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+    let path = |ext| stem.with_extension(ext);
+
+    let graph = match tr {
+        Ok(tr) => {
+            std::fs::write(path("json"), tr.to_json()?)?;
+            tr.graph()
+        }
+        Err(err) => &err.graph,
+    };
+    let text = simple_report(tr).unwrap_or_else(|| "The target fact PASSED".to_string());
+    std::fs::write(path("txt"), text)?;
+    std::fs::write(path("svg"), graph.svg())?;
+    std::fs::write(path("html"), graph.html("hc_sleuth report"))?;
+    Ok(stem)
+}
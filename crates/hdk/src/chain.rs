@@ -7,6 +7,11 @@ pub use hdi::chain::*;
 /// The agent activity is held by the neighbourhood of the agent's public key, rather than a content hash like the rest of the DHT.
 ///
 /// The agent activity can be filtered with [ `ChainQueryFilter` ] like a local chain query.
+///
+/// Activity for long chains can be loaded a page at a time by setting `offset` and `limit` on the filter.
+/// The page is applied to the valid and rejected activity separately, and is counted from the end of
+/// the chain and returned in descending order if the filter is descending. The status is always that
+/// of the whole chain, even when the page doesn't include the chain head.
 pub fn get_agent_activity(
     agent: AgentPubKey,
     query: ChainQueryFilter,
@@ -294,6 +294,52 @@ pub fn get_details<H: Into<AnyDhtHash>>(
         .unwrap())
 }
 
+/// Share a private entry from this agent's source chain with some agents.
+///
+/// The record for `action_hash`, including its private entry, is sealed for each
+/// recipient with [ `x_25519_x_salsa20_poly1305_encrypt` ] using the `sender` key,
+/// which must have been created in this agent's keystore with [ `create_x25519_keypair` ].
+/// Each sealed record is delivered as a remote call to the `recv_private_entry`
+/// function of this zome on the recipient's cell, and the response from each
+/// recipient is returned in the order the recipients were given.
+///
+/// ### Usage
+/// The recipient needs an unrestricted cap grant for a function like:
+/// ```ignore
+/// #[hdk_extern]
+/// fn recv_private_entry(sealed: SealedPrivateEntry) -> ExternResult<()> {
+///     let action_hash = sealed.action_hash.clone();
+///     let record = open_private_entry(sealed)?;
+///     // Check the author really published this action.
+///     let action = must_get_action(action_hash)?;
+///     // ... keep the record somewhere
+///     Ok(())
+/// }
+/// ```
+pub fn share_private_entry(
+    action_hash: ActionHash,
+    sender: X25519PubKey,
+    recipients: Vec<(AgentPubKey, X25519PubKey)>,
+) -> ExternResult<Vec<(AgentPubKey, ZomeCallResponse)>> {
+    HDK.with(|h| {
+        h.borrow().share_private_entry(SharePrivateEntryInput {
+            action_hash,
+            sender,
+            recipients,
+        })
+    })
+}
+
+/// Open a record shared with this agent by [ `share_private_entry` ].
+///
+/// Fails if the record can't be decrypted with the recipient key, or if it is not
+/// the signed action by the claimed author and the entry that action commits to.
+/// This does not check that the action was published, use [ `must_get_action` ]
+/// with the `action_hash` of the sealed record for that.
+pub fn open_private_entry(sealed: SealedPrivateEntry) -> ExternResult<Record> {
+    HDK.with(|h| h.borrow().open_private_entry(sealed))
+}
+
 /// Implements a whole lot of sane defaults for a struct or enum that should behave as an entry.
 /// All the entry def fields are available as dedicated methods on the type and matching From impls
 /// are provided for each. This allows for both Foo::entry_def() and EntryDef::from(Foo::new())
@@ -36,6 +36,11 @@ pub trait HdkT: HdiT {
     fn delete(&self, delete_input: DeleteInput) -> ExternResult<ActionHash>;
     fn get(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Record>>>;
     fn get_details(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>>;
+    fn share_private_entry(
+        &self,
+        share_private_entry_input: SharePrivateEntryInput,
+    ) -> ExternResult<Vec<(AgentPubKey, ZomeCallResponse)>>;
+    fn open_private_entry(&self, sealed: SealedPrivateEntry) -> ExternResult<Record>;
     // CounterSigning
     fn accept_countersigning_preflight_request(
         &self,
@@ -53,17 +58,30 @@ pub trait HdkT: HdiT {
         get_links_input: Vec<GetLinksInput>,
     ) -> ExternResult<Vec<LinkDetails>>;
     fn count_links(&self, query: LinkQuery) -> ExternResult<usize>;
+    // Local store
+    fn local_get(&self, key: Bytes) -> ExternResult<Option<Bytes>>;
+    fn local_put(&self, local_put_input: LocalPutInput) -> ExternResult<()>;
+    fn local_delete(&self, key: Bytes) -> ExternResult<bool>;
+    fn local_scan_prefix(&self, prefix: Bytes) -> ExternResult<Vec<(Bytes, Bytes)>>;
     // P2P
     fn block_agent(&self, block_agent_input: BlockAgentInput) -> ExternResult<()>;
     fn unblock_agent(&self, unblock_agent_input: BlockAgentInput) -> ExternResult<()>;
     fn call(&self, call: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>>;
+    fn call_remote_streamed(&self, call: Call) -> ExternResult<ZomeCallResponse>;
     fn emit_signal(&self, app_signal: AppSignal) -> ExternResult<()>;
     fn send_remote_signal(&self, remote_signal: RemoteSignal) -> ExternResult<()>;
+    fn send_remote_signal_acknowledged(
+        &self,
+        remote_signal: AcknowledgedRemoteSignal,
+    ) -> ExternResult<Vec<(AgentPubKey, RemoteSignalDelivery)>>;
     // Random
     fn random_bytes(&self, number_of_bytes: u32) -> ExternResult<Bytes>;
     // Time
     fn sys_time(&self, sys_time_input: ()) -> ExternResult<Timestamp>;
     fn schedule(&self, scheduled_fn: String) -> ExternResult<()>;
+    fn schedule_with_payload(&self, input: ScheduleWithPayloadInput) -> ExternResult<()>;
+    fn list_schedules(&self, list_schedules_input: ()) -> ExternResult<Vec<ScheduledFnInfo>>;
+    fn cancel_schedule(&self, input: CancelScheduleInput) -> ExternResult<()>;
     fn sleep(&self, wake_after: std::time::Duration) -> ExternResult<()>;
     // XSalsa20Poly1305
     fn x_salsa20_poly1305_shared_secret_create_random(
@@ -109,6 +127,11 @@ mockall::mock! {
         fn delete(&self, delete_input: DeleteInput) -> ExternResult<ActionHash>;
         fn get(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Record>>>;
         fn get_details(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>>;
+        fn share_private_entry(
+            &self,
+            share_private_entry_input: SharePrivateEntryInput,
+        ) -> ExternResult<Vec<(AgentPubKey, ZomeCallResponse)>>;
+        fn open_private_entry(&self, sealed: SealedPrivateEntry) -> ExternResult<Record>;
         // CounterSigning
         fn accept_countersigning_preflight_request(
             &self,
@@ -126,17 +149,30 @@ mockall::mock! {
             get_links_input: Vec<GetLinksInput>,
         ) -> ExternResult<Vec<LinkDetails>>;
         fn count_links(&self, query: LinkQuery) -> ExternResult<usize>;
+        // Local store
+        fn local_get(&self, key: Bytes) -> ExternResult<Option<Bytes>>;
+        fn local_put(&self, local_put_input: LocalPutInput) -> ExternResult<()>;
+        fn local_delete(&self, key: Bytes) -> ExternResult<bool>;
+        fn local_scan_prefix(&self, prefix: Bytes) -> ExternResult<Vec<(Bytes, Bytes)>>;
         // P2P
         fn block_agent(&self, block_agent_input: BlockAgentInput) -> ExternResult<()>;
         fn unblock_agent(&self, unblock_agent_input: BlockAgentInput) -> ExternResult<()>;
         fn call(&self, call: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>>;
+        fn call_remote_streamed(&self, call: Call) -> ExternResult<ZomeCallResponse>;
         fn emit_signal(&self, app_signal: AppSignal) -> ExternResult<()>;
         fn send_remote_signal(&self, remote_signal: RemoteSignal) -> ExternResult<()>;
+        fn send_remote_signal_acknowledged(
+            &self,
+            remote_signal: AcknowledgedRemoteSignal,
+        ) -> ExternResult<Vec<(AgentPubKey, RemoteSignalDelivery)>>;
         // Random
         fn random_bytes(&self, number_of_bytes: u32) -> ExternResult<Bytes>;
         // Time
         fn sys_time(&self, sys_time_input: ()) -> ExternResult<Timestamp>;
         fn schedule(&self, scheduled_fn: String) -> ExternResult<()>;
+        fn schedule_with_payload(&self, input: ScheduleWithPayloadInput) -> ExternResult<()>;
+        fn list_schedules(&self, list_schedules_input: ()) -> ExternResult<Vec<ScheduledFnInfo>>;
+        fn cancel_schedule(&self, input: CancelScheduleInput) -> ExternResult<()>;
         fn sleep(&self, wake_after: std::time::Duration) -> ExternResult<()>;
         // XSalsa20Poly1305
         fn x_salsa20_poly1305_shared_secret_create_random(
@@ -303,6 +339,15 @@ impl HdkT for ErrHdk {
     fn get_details(&self, _: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>> {
         Self::err()
     }
+    fn share_private_entry(
+        &self,
+        _: SharePrivateEntryInput,
+    ) -> ExternResult<Vec<(AgentPubKey, ZomeCallResponse)>> {
+        Self::err()
+    }
+    fn open_private_entry(&self, _: SealedPrivateEntry) -> ExternResult<Record> {
+        Self::err()
+    }
     // CounterSigning
     fn accept_countersigning_preflight_request(
         &self,
@@ -332,6 +377,19 @@ impl HdkT for ErrHdk {
     fn count_links(&self, _: LinkQuery) -> ExternResult<usize> {
         Self::err()
     }
+    // Local store
+    fn local_get(&self, _: Bytes) -> ExternResult<Option<Bytes>> {
+        Self::err()
+    }
+    fn local_put(&self, _: LocalPutInput) -> ExternResult<()> {
+        Self::err()
+    }
+    fn local_delete(&self, _: Bytes) -> ExternResult<bool> {
+        Self::err()
+    }
+    fn local_scan_prefix(&self, _: Bytes) -> ExternResult<Vec<(Bytes, Bytes)>> {
+        Self::err()
+    }
     // P2P
     fn block_agent(&self, _: BlockAgentInput) -> ExternResult<()> {
         Self::err()
@@ -342,12 +400,21 @@ impl HdkT for ErrHdk {
     fn call(&self, _: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>> {
         Self::err()
     }
+    fn call_remote_streamed(&self, _: Call) -> ExternResult<ZomeCallResponse> {
+        Self::err()
+    }
     fn emit_signal(&self, _: AppSignal) -> ExternResult<()> {
         Self::err()
     }
     fn send_remote_signal(&self, _: RemoteSignal) -> ExternResult<()> {
         Self::err()
     }
+    fn send_remote_signal_acknowledged(
+        &self,
+        _: AcknowledgedRemoteSignal,
+    ) -> ExternResult<Vec<(AgentPubKey, RemoteSignalDelivery)>> {
+        Self::err()
+    }
     // Random
     fn random_bytes(&self, _: u32) -> ExternResult<Bytes> {
         Self::err()
@@ -359,6 +426,15 @@ impl HdkT for ErrHdk {
     fn schedule(&self, _: String) -> ExternResult<()> {
         Self::err()
     }
+    fn schedule_with_payload(&self, _: ScheduleWithPayloadInput) -> ExternResult<()> {
+        Self::err()
+    }
+    fn list_schedules(&self, _: ()) -> ExternResult<Vec<ScheduledFnInfo>> {
+        Self::err()
+    }
+    fn cancel_schedule(&self, _: CancelScheduleInput) -> ExternResult<()> {
+        Self::err()
+    }
     fn sleep(&self, _: std::time::Duration) -> ExternResult<()> {
         Self::err()
     }
@@ -503,6 +579,18 @@ impl HdkT for HostHdk {
     fn get_details(&self, get_inputs: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>> {
         host_call::<Vec<GetInput>, Vec<Option<Details>>>(__hc__get_details_1, get_inputs)
     }
+    fn share_private_entry(
+        &self,
+        share_private_entry_input: SharePrivateEntryInput,
+    ) -> ExternResult<Vec<(AgentPubKey, ZomeCallResponse)>> {
+        host_call::<SharePrivateEntryInput, Vec<(AgentPubKey, ZomeCallResponse)>>(
+            __hc__share_private_entry_1,
+            share_private_entry_input,
+        )
+    }
+    fn open_private_entry(&self, sealed: SealedPrivateEntry) -> ExternResult<Record> {
+        host_call::<SealedPrivateEntry, Record>(__hc__open_private_entry_1, sealed)
+    }
     // CounterSigning
     fn accept_countersigning_preflight_request(
         &self,
@@ -537,6 +625,18 @@ impl HdkT for HostHdk {
     fn count_links(&self, query: LinkQuery) -> ExternResult<usize> {
         host_call::<LinkQuery, usize>(__hc__count_links_1, query)
     }
+    fn local_get(&self, key: Bytes) -> ExternResult<Option<Bytes>> {
+        host_call::<Bytes, Option<Bytes>>(__hc__local_get_1, key)
+    }
+    fn local_put(&self, local_put_input: LocalPutInput) -> ExternResult<()> {
+        host_call::<LocalPutInput, ()>(__hc__local_put_1, local_put_input)
+    }
+    fn local_delete(&self, key: Bytes) -> ExternResult<bool> {
+        host_call::<Bytes, bool>(__hc__local_delete_1, key)
+    }
+    fn local_scan_prefix(&self, prefix: Bytes) -> ExternResult<Vec<(Bytes, Bytes)>> {
+        host_call::<Bytes, Vec<(Bytes, Bytes)>>(__hc__local_scan_prefix_1, prefix)
+    }
     fn block_agent(&self, block_agent_input: BlockAgentInput) -> ExternResult<()> {
         host_call::<BlockAgentInput, ()>(__hc__block_agent_1, block_agent_input)
     }
@@ -546,12 +646,24 @@ impl HdkT for HostHdk {
     fn call(&self, call_input: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>> {
         host_call::<Vec<Call>, Vec<ZomeCallResponse>>(__hc__call_1, call_input)
     }
+    fn call_remote_streamed(&self, call_input: Call) -> ExternResult<ZomeCallResponse> {
+        host_call::<Call, ZomeCallResponse>(__hc__call_remote_streamed_1, call_input)
+    }
     fn emit_signal(&self, app_signal: AppSignal) -> ExternResult<()> {
         host_call::<AppSignal, ()>(__hc__emit_signal_1, app_signal)
     }
     fn send_remote_signal(&self, remote_signal: RemoteSignal) -> ExternResult<()> {
         host_call::<RemoteSignal, ()>(__hc__send_remote_signal_1, remote_signal)
     }
+    fn send_remote_signal_acknowledged(
+        &self,
+        remote_signal: AcknowledgedRemoteSignal,
+    ) -> ExternResult<Vec<(AgentPubKey, RemoteSignalDelivery)>> {
+        host_call::<AcknowledgedRemoteSignal, Vec<(AgentPubKey, RemoteSignalDelivery)>>(
+            __hc__send_remote_signal_acknowledged_1,
+            remote_signal,
+        )
+    }
     fn random_bytes(&self, number_of_bytes: u32) -> ExternResult<Bytes> {
         host_call::<u32, Bytes>(__hc__random_bytes_1, number_of_bytes)
     }
@@ -561,6 +673,15 @@ impl HdkT for HostHdk {
     fn schedule(&self, scheduled_fn: String) -> ExternResult<()> {
         host_call::<String, ()>(__hc__schedule_1, scheduled_fn)
     }
+    fn schedule_with_payload(&self, input: ScheduleWithPayloadInput) -> ExternResult<()> {
+        host_call::<ScheduleWithPayloadInput, ()>(__hc__schedule_with_payload_1, input)
+    }
+    fn list_schedules(&self, _: ()) -> ExternResult<Vec<ScheduledFnInfo>> {
+        host_call::<(), Vec<ScheduledFnInfo>>(__hc__list_schedules_1, ())
+    }
+    fn cancel_schedule(&self, input: CancelScheduleInput) -> ExternResult<()> {
+        host_call::<CancelScheduleInput, ()>(__hc__cancel_schedule_1, input)
+    }
     fn sleep(&self, wake_after: std::time::Duration) -> ExternResult<()> {
         host_call::<std::time::Duration, ()>(__hc__sleep_1, wake_after)
     }
@@ -165,6 +165,12 @@
 //!   - Close runs when an agent is deprecating an old source chain in favour of a new one.
 //!   - All zomes in a DNA migrate at the same time.
 //!   - Any failure fails the migration.
+//! - `fn on_integrated(ops: Vec<IntegratedOp>)`:
+//!   - Opt-in, runs for every coordinator zome of the DNA that defines it, on every running cell of the DNA.
+//!   - Executes after ops that the agent holds as a DHT authority are integrated, including ops authored by other agents.
+//!   - Input is the integrated ops along with the validation status they were integrated with.
+//!   - Can emit signals but cannot write to the source chain.
+//!   - Useful for building local indexes and telling UIs about data arriving at the agent's arc.
 //! - `fn post_commit(actions: Vec<SignedActionHashed>)`:
 //!   - Executes after the WASM call that originated the commits so not bound by the original atomic transaction.
 //!   - Input is all the action hashes that were committed.
@@ -530,6 +536,13 @@ pub mod info;
 /// __Links are not entries__, there is only an action with no associated entry, so links cannot reference other links or maintain or participate in a revision history.
 pub mod link;
 
+/// A key/value store for each zome, kept by the agent's cell outside of its source chain.
+///
+/// Use it for caches, preferences and other local state that doesn't need to be
+/// published, validated or kept forever.
+/// The store is not available to validation or genesis callbacks.
+pub mod local_store;
+
 /// Methods for interacting with peers in the same DHT network.
 ///
 /// Data on the DHT generally propagates at the speed of gossip and must be explicitly polled and retrieved.
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+/// Get the value stored under a key in this zome's local store.
+///
+/// Returns `None` if nothing is stored under the key.
+/// See [ `local_put` ] for what the local store is.
+pub fn local_get(key: impl Into<Vec<u8>>) -> ExternResult<Option<Bytes>> {
+    HDK.with(|h| h.borrow().local_get(Bytes::from(key.into())))
+}
+
+/// Store a value under a key in this zome's local store.
+///
+/// Any value already stored under the key is replaced.
+///
+/// The local store is a key/value store kept by the agent's cell outside of
+/// its source chain. Each zome has its own keys, nothing in the store is
+/// gossiped or validated and nothing is ever published, so it is a good
+/// place for caches, UI preferences and other state that would otherwise
+/// be committed as private entries forever.
+///
+/// Unlike writes to the source chain, the value is stored straight away and
+/// is kept even if the zome call that stored it fails.
+///
+/// The local store can't be used from validation or genesis callbacks.
+///
+/// ```ignore
+/// local_put("prefs/theme", "dark")?;
+/// assert_eq!(local_get("prefs/theme")?, Some(Bytes::from(b"dark".to_vec())));
+/// ```
+pub fn local_put(key: impl Into<Vec<u8>>, value: impl Into<Vec<u8>>) -> ExternResult<()> {
+    HDK.with(|h| {
+        h.borrow().local_put(LocalPutInput::new(
+            Bytes::from(key.into()),
+            Bytes::from(value.into()),
+        ))
+    })
+}
+
+/// Delete a key from this zome's local store.
+///
+/// Returns whether anything was stored under the key.
+pub fn local_delete(key: impl Into<Vec<u8>>) -> ExternResult<bool> {
+    HDK.with(|h| h.borrow().local_delete(Bytes::from(key.into())))
+}
+
+/// Get every key/value pair in this zome's local store whose key starts with
+/// the prefix, ordered by key.
+///
+/// An empty prefix returns everything in the zome's store.
+///
+/// ```ignore
+/// for (key, value) in local_scan_prefix("cache/")? {
+///     // ...
+/// }
+/// ```
+pub fn local_scan_prefix(prefix: impl Into<Vec<u8>>) -> ExternResult<Vec<(Bytes, Bytes)>> {
+    HDK.with(|h| h.borrow().local_scan_prefix(Bytes::from(prefix.into())))
+}
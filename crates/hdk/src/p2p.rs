@@ -95,6 +95,46 @@ where
         .unwrap())
 }
 
+/// Make a remote call like [ `call_remote` ] with a payload and response too large
+/// for a single network message, e.g. blobs of several megabytes.
+///
+/// The payload is sent to the remote agent in chunks, each waiting for the previous
+/// one to be acknowledged, and a chunk that fails is retried from wherever the remote
+/// got to. The response is fetched back in chunks the same way. Payloads and responses
+/// are limited to `MAX_STREAM_BYTES` (128 MiB), and the remote conductor may refuse
+/// the call with a [ `ZomeCallResponse::NetworkError` ] while it is buffering too much
+/// data for other streams.
+///
+/// The remote zome function is called exactly as with [ `call_remote` ] once the whole
+/// payload has arrived, so the same function can be called either way.
+///
+/// ```ignore
+/// ...
+/// let response = call_remote_streamed(bob, "files", "store_blob".into(), secret, blob)?;
+/// ...
+/// ```
+pub fn call_remote_streamed<I, Z>(
+    agent: AgentPubKey,
+    zome: Z,
+    fn_name: FunctionName,
+    cap_secret: Option<CapSecret>,
+    payload: I,
+) -> ExternResult<ZomeCallResponse>
+where
+    I: serde::Serialize + std::fmt::Debug,
+    Z: Into<ZomeName>,
+{
+    HDK.with(|h| {
+        h.borrow().call_remote_streamed(Call::new(
+            CallTarget::NetworkAgent(agent),
+            zome.into(),
+            fn_name,
+            cap_secret,
+            ExternIO::encode(payload).map_err(|e| wasm_error!(e))?,
+        ))
+    })
+}
+
 /// Emit an app-defined Signal.
 ///
 /// Only clients who have subscribed to signals from this Cell with the proper
@@ -156,3 +196,34 @@ where
         })
     })
 }
+
+/// ## Acknowledged Remote Signal
+/// Send a signal to a list of other agents and wait for each of them to
+/// acknowledge it.
+///
+/// Unlike [ `send_remote_signal` ] this blocks until every agent has either
+/// acknowledged the signal or the `timeout` has elapsed for them, and returns
+/// a [ `RemoteSignalDelivery` ] for each agent in the order they were given.
+/// This makes it possible to show delivery state, e.g. in a chat app.
+///
+/// ### Usage
+/// The same `recv_remote_signal` function and unrestricted cap grant are
+/// required as for [ `send_remote_signal` ]. An agent without a cap grant
+/// for `recv_remote_signal` reports [ `RemoteSignalDelivery::Unauthorized` ].
+pub fn send_remote_signal_acknowledged<I>(
+    input: I,
+    agents: Vec<AgentPubKey>,
+    timeout: std::time::Duration,
+) -> ExternResult<Vec<(AgentPubKey, RemoteSignalDelivery)>>
+where
+    I: serde::Serialize + std::fmt::Debug,
+{
+    HDK.with(|h| {
+        h.borrow()
+            .send_remote_signal_acknowledged(AcknowledgedRemoteSignal {
+                signal: ExternIO::encode(input).map_err(|e| wasm_error!(e))?,
+                agents,
+                timeout,
+            })
+    })
+}
@@ -23,6 +23,8 @@ pub use crate::entry::get_details;
 pub use crate::entry::must_get_action;
 pub use crate::entry::must_get_entry;
 pub use crate::entry::must_get_valid_record;
+pub use crate::entry::open_private_entry;
+pub use crate::entry::share_private_entry;
 pub use crate::entry::update;
 pub use crate::entry::update_entry;
 pub use crate::entry_def;
@@ -46,14 +48,23 @@ pub use crate::link::get_link_details;
 pub use crate::link::get_links;
 pub use crate::link::GetLinksInputBuilder;
 pub use crate::link::LinkTypeFilterExt;
+pub use crate::local_store::local_delete;
+pub use crate::local_store::local_get;
+pub use crate::local_store::local_put;
+pub use crate::local_store::local_scan_prefix;
 pub use crate::map_extern;
 pub use crate::map_extern::ExternResult;
 pub use crate::p2p::call;
 pub use crate::p2p::call_remote;
+pub use crate::p2p::call_remote_streamed;
 pub use crate::p2p::emit_signal;
 pub use crate::p2p::send_remote_signal;
+pub use crate::p2p::send_remote_signal_acknowledged;
 pub use crate::random::*;
+pub use crate::time::cancel_schedule;
+pub use crate::time::list_schedules;
 pub use crate::time::schedule;
+pub use crate::time::schedule_with_payload;
 pub use crate::time::sleep;
 pub use crate::time::sys_time;
 pub use crate::time::*;
@@ -147,14 +158,25 @@ macro_rules! holochain_externs {
             block_agent:1,
             unblock_agent:1,
             call:1,
+            call_remote_streamed:1,
             create:1,
             emit_signal:1,
             send_remote_signal:1,
+            send_remote_signal_acknowledged:1,
+            share_private_entry:1,
+            open_private_entry:1,
             create_link:1,
             delete_link:1,
+            local_get:1,
+            local_put:1,
+            local_delete:1,
+            local_scan_prefix:1,
             update:1,
             delete:1,
             schedule:1,
+            schedule_with_payload:1,
+            list_schedules:1,
+            cancel_schedule:1,
             sleep:1,
             x_salsa20_poly1305_shared_secret_create_random:1,
             x_salsa20_poly1305_shared_secret_export:1,
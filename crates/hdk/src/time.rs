@@ -136,6 +136,66 @@ pub fn schedule(scheduled_fn: &str) -> ExternResult<()> {
     HDK.with(|h| h.borrow().schedule(String::from(scheduled_fn)))
 }
 
+/// Adds a named schedule of a function from the current zome to the scheduler,
+/// with a payload that the function will be called with every time it triggers.
+///
+/// This works like [ `schedule` ] with these differences:
+///
+/// - The same function can be scheduled many times under different names,
+///   e.g. one schedule per reminder. Scheduling a function under a name it is
+///   already scheduled under replaces that schedule and its payload.
+/// - The first trigger can be given as `schedule`. `None` triggers the function
+///   on the next iteration of the scheduler like [ `schedule` ] does.
+/// - The function receives a [ `ScheduledFnInput` ] with its name, the schedule
+///   that triggered it and the payload, and still returns its next schedule.
+///   ```ignore
+///   #[hdk_extern(infallible)]
+///   fn remind(input: ScheduledFnInput) -> Option<Schedule> {}
+///   ```
+///
+/// The payload is supplied by whoever called `schedule_with_payload` but the
+/// function runs as the author, so zomes MUST guard the zome calls that
+/// schedule functions with appropriate cap grants.
+pub fn schedule_with_payload<I>(
+    scheduled_fn: &str,
+    name: impl Into<String>,
+    payload: I,
+    schedule: Option<Schedule>,
+) -> ExternResult<()>
+where
+    I: serde::Serialize + std::fmt::Debug,
+{
+    HDK.with(|h| {
+        h.borrow().schedule_with_payload(ScheduleWithPayloadInput {
+            fn_name: scheduled_fn.into(),
+            name: name.into(),
+            payload: ExternIO::encode(payload).map_err(|e| wasm_error!(e))?,
+            schedule,
+        })
+    })
+}
+
+/// Lists the pending schedules of functions in the current zome, soonest first.
+///
+/// Schedule changes made by the current zome call are not listed until the
+/// call has completed.
+pub fn list_schedules() -> ExternResult<Vec<ScheduledFnInfo>> {
+    HDK.with(|h| h.borrow().list_schedules(()))
+}
+
+/// Cancels the schedule of a function in the current zome under the given
+/// name. Functions scheduled with [ `schedule` ] have the empty name.
+///
+/// Cancelling a schedule that doesn't exist is a noop.
+pub fn cancel_schedule(scheduled_fn: &str, name: impl Into<String>) -> ExternResult<()> {
+    HDK.with(|h| {
+        h.borrow().cancel_schedule(CancelScheduleInput {
+            fn_name: scheduled_fn.into(),
+            name: name.into(),
+        })
+    })
+}
+
 /// @todo Not implemented
 pub fn sleep(wake_after: std::time::Duration) -> ExternResult<()> {
     HDK.with(|h| h.borrow().sleep(wake_after))
@@ -28,7 +28,7 @@ pub mod error;
 pub mod interface;
 pub mod kitsune_host_impl;
 pub mod manager;
-mod metrics;
+pub(crate) mod metrics;
 pub mod p2p_agent_store;
 pub mod paths;
 #[allow(missing_docs)]
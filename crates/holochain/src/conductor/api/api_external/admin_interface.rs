@@ -10,6 +10,7 @@ use crate::conductor::interface::error::InterfaceError;
 use crate::conductor::interface::error::InterfaceResult;
 use crate::conductor::ConductorHandle;
 use holochain_serialized_bytes::prelude::*;
+use holochain_sqlite::prelude::DatabaseError;
 use holochain_types::dna::DnaBundle;
 use holochain_types::prelude::*;
 use mr_bundle::Bundle;
@@ -99,11 +100,17 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                     }
                     DnaSource::Path(ref path) => {
                         let bundle = Bundle::read_from_file(path).await?;
+                        self.conductor_handle
+                            .check_bundle_signed(&bundle, Some(path))
+                            .await?;
                         let bundle: DnaBundle = bundle.into();
                         let (dna_file, _original_hash) = bundle.into_dna_file(modifiers).await?;
                         dna_file
                     }
                     DnaSource::Bundle(bundle) => {
+                        self.conductor_handle
+                            .check_bundle_signed::<ValidatedDnaManifest>(&bundle, None)
+                            .await?;
                         let (dna_file, _original_hash) = bundle.into_dna_file(modifiers).await?;
                         dna_file
                     }
@@ -128,10 +135,18 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                 let (coordinator_zomes, wasms) = match source {
                     CoordinatorSource::Path(ref path) => {
                         let bundle = Bundle::read_from_file(path).await?;
+                        self.conductor_handle
+                            .check_bundle_signed(&bundle, Some(path))
+                            .await?;
                         let bundle: CoordinatorBundle = bundle.into();
                         bundle.into_zomes().await?
                     }
-                    CoordinatorSource::Bundle(bundle) => bundle.into_zomes().await?,
+                    CoordinatorSource::Bundle(bundle) => {
+                        self.conductor_handle
+                            .check_bundle_signed::<CoordinatorManifest>(&bundle, None)
+                            .await?;
+                        bundle.into_zomes().await?
+                    }
                 };
 
                 self.conductor_handle
@@ -140,6 +155,16 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
 
                 Ok(AdminResponse::CoordinatorsUpdated)
             }
+            UpdateApp(payload) => {
+                let UpdateAppPayload {
+                    installed_app_id,
+                    source,
+                } = *payload;
+                self.conductor_handle
+                    .update_app(&installed_app_id, source)
+                    .await?;
+                Ok(AdminResponse::AppUpdated)
+            }
             InstallApp(payload) => {
                 let app: InstalledApp = self
                     .conductor_handle
@@ -147,11 +172,10 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                     .install_app_bundle(*payload)
                     .await?
                     .into();
-                let dna_definitions = self.conductor_handle.get_dna_definitions(&app)?;
-                Ok(AdminResponse::AppInstalled(AppInfo::from_installed_app(
-                    &app,
-                    &dna_definitions,
-                )))
+                let state = self.conductor_handle.get_state().await?;
+                Ok(AdminResponse::AppInstalled(
+                    self.conductor_handle.app_info(&app, &state)?,
+                ))
             }
             UninstallApp { installed_app_id } => {
                 self.conductor_handle
@@ -255,6 +279,13 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                 let stats = self.conductor_handle.dump_network_stats().await?;
                 Ok(AdminResponse::NetworkStatsDumped(stats))
             }
+            DumpValidationReceipts { cell_id } => {
+                let receipts = self
+                    .conductor_handle
+                    .dump_validation_receipts(&cell_id)
+                    .await?;
+                Ok(AdminResponse::ValidationReceiptsDumped(receipts))
+            }
             AddAgentInfo { agent_infos } => {
                 self.conductor_handle.add_agent_infos(agent_infos).await?;
                 Ok(AdminResponse::AgentInfoAdded)
@@ -291,6 +322,75 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
             StorageInfo => Ok(AdminResponse::StorageInfo(
                 self.conductor_handle.storage_info().await?,
             )),
+            ExportOps {
+                dna_hash,
+                filter,
+                signer,
+            } => {
+                let archive = self
+                    .conductor_handle
+                    .export_ops(dna_hash, filter, signer)
+                    .await?;
+                Ok(AdminResponse::OpsExported(Box::new(archive)))
+            }
+            ImportOps(archive) => {
+                let count = self.conductor_handle.import_ops(*archive).await?;
+                Ok(AdminResponse::OpsImported(count))
+            }
+            SetArcPolicy {
+                installed_app_id,
+                role_name,
+                arc_policy,
+            } => {
+                self.conductor_handle
+                    .set_arc_policy(installed_app_id, role_name, arc_policy)
+                    .await?;
+                Ok(AdminResponse::ArcPolicySet)
+            }
+            MigrateAgent {
+                from_cell_id,
+                to_cell_id,
+            } => {
+                self.conductor_handle
+                    .migrate_agent(from_cell_id, to_cell_id)
+                    .await?;
+                Ok(AdminResponse::AgentMigrated)
+            }
+            Block { target, interval } => {
+                // The interval may not have been checked when it was deserialized.
+                let interval =
+                    InclusiveTimestampInterval::try_new(interval.start(), interval.end())
+                        .map_err(DatabaseError::from)?;
+                self.conductor_handle
+                    .block(holochain_zome_types::block::Block::new(target, interval))
+                    .await?;
+                Ok(AdminResponse::Blocked)
+            }
+            Unblock { target, interval } => {
+                let interval =
+                    InclusiveTimestampInterval::try_new(interval.start(), interval.end())
+                        .map_err(DatabaseError::from)?;
+                self.conductor_handle
+                    .unblock(holochain_zome_types::block::Block::new(target, interval))
+                    .await?;
+                Ok(AdminResponse::Unblocked)
+            }
+            ListBlocks { at } => Ok(AdminResponse::BlocksListed(
+                self.conductor_handle.list_blocks(at).await?,
+            )),
+            ListSchedules { cell_id } => Ok(AdminResponse::SchedulesListed(
+                self.conductor_handle.list_schedules(&cell_id).await?,
+            )),
+            CancelSchedule {
+                cell_id,
+                zome_name,
+                fn_name,
+                name,
+            } => Ok(AdminResponse::ScheduleCancelled(
+                self.conductor_handle
+                    .cancel_schedule(&cell_id, ScheduledFn::named(zome_name, fn_name, name))
+                    .await?,
+            )),
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::conductor::api::error::SerializationError;
 use crate::conductor::interface::error::InterfaceError;
 use crate::conductor::interface::error::InterfaceResult;
 use crate::conductor::ConductorHandle;
+use crate::core::workflow::ZomeCallResult;
 
 use holochain_serialized_bytes::prelude::*;
 
@@ -64,26 +65,24 @@ impl AppInterfaceApi for RealAppInterfaceApi {
                     .await?,
             )),
             AppRequest::CallZome(call) => {
-                match self.conductor_handle.call_zome(*call.clone()).await? {
-                    Ok(ZomeCallResponse::Ok(output)) => Ok(AppResponse::ZomeCalled(Box::new(output))),
-                    Ok(ZomeCallResponse::Unauthorized(zome_call_authorization, _, zome_name, fn_name, _)) => Ok(AppResponse::Error(
-                        ExternalApiWireError::ZomeCallUnauthorized(format!(
-                            "Call was not authorized with reason {:?}, cap secret {:?} to call the function {} in zome {}",
-                            zome_call_authorization, call.cap_secret, fn_name, zome_name
-                        )),
-                    )),
-                    Ok(ZomeCallResponse::NetworkError(e)) => unreachable!(
-                        "Interface zome calls should never be routed to the network. This is a bug. Got {}",
-                        e
-                    ),
-                    Ok(ZomeCallResponse::CountersigningSession(e)) => Ok(AppResponse::Error(
-                        ExternalApiWireError::CountersigningSessionError(format!(
-                            "A countersigning session has failed to start on this zome call because: {}",
-                            e
-                        )),
-                    )),
-                    Err(e) => Ok(AppResponse::Error(e.into())),
-                }
+                let response = self.conductor_handle.call_zome(*call.clone()).await?;
+                Ok(match zome_call_output(&call, response) {
+                    Ok(output) => AppResponse::ZomeCalled(Box::new(output)),
+                    Err(error) => error,
+                })
+            }
+            AppRequest::CallZomeMetered(call) => {
+                let (response, metering_points) = self
+                    .conductor_handle
+                    .call_zome_metered(*call.clone())
+                    .await?;
+                Ok(match zome_call_output(&call, response) {
+                    Ok(output) => AppResponse::ZomeCalledMetered {
+                        output: Box::new(output),
+                        metering_points,
+                    },
+                    Err(error) => error,
+                })
             }
             AppRequest::CreateCloneCell(payload) => {
                 let clone_cell = self
@@ -115,10 +114,38 @@ impl AppInterfaceApi for RealAppInterfaceApi {
             AppRequest::ListWasmHostFunctions => Ok(AppResponse::ListWasmHostFunctions(
                 self.conductor_handle.list_wasm_host_functions().await?,
             )),
+            AppRequest::ListZomeFunctions { cell_id } => Ok(AppResponse::ZomeFunctionsListed(
+                self.conductor_handle.list_zome_functions(&cell_id).await?,
+            )),
         }
     }
 }
 
+/// Unwrap the output of a successful zome call, or turn the failure into
+/// the error response for the client.
+fn zome_call_output(call: &ZomeCall, response: ZomeCallResult) -> Result<ExternIO, AppResponse> {
+    match response {
+        Ok(ZomeCallResponse::Ok(output)) => Ok(output),
+        Ok(ZomeCallResponse::Unauthorized(zome_call_authorization, _, zome_name, fn_name, _)) => Err(AppResponse::Error(
+            ExternalApiWireError::ZomeCallUnauthorized(format!(
+                "Call was not authorized with reason {:?}, cap secret {:?} to call the function {} in zome {}",
+                zome_call_authorization, call.cap_secret, fn_name, zome_name
+            )),
+        )),
+        Ok(ZomeCallResponse::NetworkError(e)) => unreachable!(
+            "Interface zome calls should never be routed to the network. This is a bug. Got {}",
+            e
+        ),
+        Ok(ZomeCallResponse::CountersigningSession(e)) => Err(AppResponse::Error(
+            ExternalApiWireError::CountersigningSessionError(format!(
+                "A countersigning session has failed to start on this zome call because: {}",
+                e
+            )),
+        )),
+        Err(e) => Err(AppResponse::Error(e.into())),
+    }
+}
+
 #[async_trait::async_trait]
 impl InterfaceApi for RealAppInterfaceApi {
     type ApiRequest = AppRequest;
@@ -108,6 +108,9 @@ pub enum ConductorApiError {
     #[error(transparent)]
     ChcError(#[from] ChcError),
 
+    #[error(transparent)]
+    OpArchiveError(#[from] OpArchiveError),
+
     #[error(transparent)]
     RibosomeError(#[from] crate::core::ribosome::error::RibosomeError),
 
@@ -14,6 +14,9 @@ use crate::core::queue_consumer::spawn_queue_consumer_tasks;
 use crate::core::queue_consumer::InitialQueueTriggers;
 use crate::core::queue_consumer::QueueTriggers;
 use crate::core::ribosome::guest_callback::init::InitResult;
+use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentResult;
+use crate::core::ribosome::guest_callback::on_integrated::OnIntegratedHostAccess;
+use crate::core::ribosome::guest_callback::on_integrated::OnIntegratedInvocation;
 use crate::core::ribosome::real_ribosome::RealRibosome;
 use crate::core::ribosome::ZomeCallInvocation;
 use crate::core::workflow::call_zome_workflow;
@@ -21,6 +24,8 @@ use crate::core::workflow::countersigning_workflow::countersigning_success;
 use crate::core::workflow::countersigning_workflow::incoming_countersigning;
 use crate::core::workflow::genesis_workflow::genesis_workflow;
 use crate::core::workflow::initialize_zomes_workflow;
+use crate::core::workflow::migrate_agent_workflow::migrate_agent_workflow;
+use crate::core::workflow::migrate_agent_workflow::MigrateAgentWorkflowArgs;
 use crate::core::workflow::CallZomeWorkflowArgs;
 use crate::core::workflow::GenesisWorkflowArgs;
 use crate::core::workflow::GenesisWorkspace;
@@ -264,10 +269,20 @@ impl Cell {
             }
             Ok(lives) => {
                 let mut tasks = vec![];
-                for (scheduled_fn, schedule) in &lives {
+                for (scheduled_fn, schedule, maybe_payload) in &lives {
+                    // Functions scheduled with a payload are called with it
+                    // alongside their name and schedule.
+                    let input = match maybe_payload {
+                        Some(payload) => ExternIO::encode(ScheduledFnInput {
+                            name: scheduled_fn.name().to_string(),
+                            schedule: schedule.clone(),
+                            payload: payload.clone(),
+                        }),
+                        None => ExternIO::encode(schedule),
+                    };
                     // Failing to encode a schedule should never happen.
                     // If it does log the error and bail.
-                    let payload = match ExternIO::encode(schedule) {
+                    let payload = match input {
                         Ok(payload) => payload,
                         Err(e) => {
                             error!(
@@ -326,7 +341,9 @@ impl Cell {
                     .space
                     .authored_db
                     .write_async(move |txn: &mut Transaction| {
-                        for ((scheduled_fn, _), result) in lives.iter().zip(results.iter()) {
+                        for ((scheduled_fn, _, maybe_payload), result) in
+                            lives.iter().zip(results.iter())
+                        {
                             match result {
                                 Ok(Ok(ZomeCallResponse::Ok(extern_io))) => {
                                     let next_schedule: Schedule = match extern_io.decode() {
@@ -342,13 +359,25 @@ impl Cell {
                                     // Ignore errors so that failing to schedule
                                     // one function doesn't error others.
                                     // For example if a zome returns a bad cron.
-                                    if let Err(e) = schedule_fn(
-                                        txn,
-                                        &author,
-                                        scheduled_fn.clone(),
-                                        Some(next_schedule),
-                                        now,
-                                    ) {
+                                    // The payload is kept for the next trigger.
+                                    let rescheduled = match maybe_payload {
+                                        Some(payload) => schedule_fn_with_payload(
+                                            txn,
+                                            &author,
+                                            scheduled_fn.clone(),
+                                            payload,
+                                            Some(next_schedule),
+                                            now,
+                                        ),
+                                        None => schedule_fn(
+                                            txn,
+                                            &author,
+                                            scheduled_fn.clone(),
+                                            Some(next_schedule),
+                                            now,
+                                        ),
+                                    };
+                                    if let Err(e) = rescheduled {
                                         error!("scheduled zome call error in schedule_fn: {:?}", e);
                                         continue;
                                     }
@@ -363,6 +392,49 @@ impl Cell {
         }
     }
 
+    /// Run the `on_integrated` callback of each of the given zomes with ops
+    /// that this cell's DNA has integrated as an authority.
+    /// Like post commit, nothing handles the result of the callbacks so any
+    /// errors are logged.
+    pub(super) async fn dispatch_on_integrated(
+        &self,
+        zomes: Vec<CoordinatorZome>,
+        ops: Vec<IntegratedOp>,
+    ) -> CellResult<()> {
+        let ribosome = self.get_ribosome()?;
+        let keystore = self.conductor_api.keystore().clone();
+        let workspace = SourceChainWorkspace::new(
+            self.authored_db().clone(),
+            self.dht_db().clone(),
+            self.space.dht_query_cache.clone(),
+            self.cache().clone(),
+            keystore.clone(),
+            self.id.agent_pubkey().clone(),
+            Arc::new(ribosome.dna_def().as_content().clone()),
+        )
+        .await?;
+        for zome in zomes {
+            let host_access = OnIntegratedHostAccess {
+                workspace: workspace.clone().into(),
+                keystore: keystore.clone(),
+                network: self.holochain_p2p_dna().clone(),
+                signal_tx: self.signal_broadcaster(),
+            };
+            let invocation = OnIntegratedInvocation::new(zome, ops.clone());
+            let ribosome = ribosome.clone();
+            match tokio::task::spawn_blocking(move || {
+                ribosome.run_on_integrated(host_access, invocation)
+            })
+            .await
+            {
+                Ok(Ok(())) => (),
+                Ok(Err(e)) => error!(cell_id = ?self.id, "on_integrated callback error: {:?}", e),
+                Err(e) => error!(cell_id = ?self.id, "on_integrated callback panicked: {:?}", e),
+            }
+        }
+        Ok(())
+    }
+
     #[instrument(skip(self, evt))]
     /// Entry point for incoming messages from the network that need to be handled
     pub async fn handle_holochain_p2p_event(
@@ -993,6 +1065,64 @@ impl Cell {
         Ok(())
     }
 
+    /// Migrate this cell's agent away from this DNA, closing the chain, or
+    /// into this DNA, opening the chain with a reference to the DNA it came from.
+    #[tracing::instrument(skip(self))]
+    pub(crate) async fn migrate_agent(
+        &self,
+        migrate_agent: MigrateAgent,
+        other_dna_hash: DnaHash,
+    ) -> CellResult<()> {
+        let keystore = self.conductor_api.keystore().clone();
+        let ribosome = self.get_ribosome()?;
+        let dna_def = ribosome.dna_def().clone();
+
+        let workspace = SourceChainWorkspace::new(
+            self.authored_db().clone(),
+            self.dht_db().clone(),
+            self.space.dht_query_cache.clone(),
+            self.cache().clone(),
+            keystore,
+            self.id.agent_pubkey().clone(),
+            Arc::new(dna_def.into_content()),
+        )
+        .await?;
+
+        if let Some(close_chain) = workspace.source_chain().chain_closed().await? {
+            // Closing again towards the same DNA is a no-op, so that an
+            // interrupted migration can be retried.
+            if migrate_agent == MigrateAgent::Close && close_chain.new_dna_hash == other_dna_hash {
+                return Ok(());
+            }
+            return Err(CellError::ChainClosed(close_chain.new_dna_hash));
+        }
+        if migrate_agent == MigrateAgent::Open {
+            if let Some(open_chain) = workspace.source_chain().chain_opened().await? {
+                // Opening again from the same DNA is a no-op too, so that a
+                // retried migration doesn't commit a second `OpenChain`.
+                if open_chain.prev_dna_hash == other_dna_hash {
+                    return Ok(());
+                }
+            }
+        }
+
+        let args = MigrateAgentWorkflowArgs {
+            ribosome,
+            conductor_handle: self.conductor_handle.clone(),
+            migrate_agent,
+            other_dna_hash,
+            trigger_publish_dht_ops: self.queue_triggers.publish_dht_ops.clone(),
+            trigger_integrate_dht_ops: self.queue_triggers.integrate_dht_ops.clone(),
+        };
+        let result = migrate_agent_workflow(workspace, self.holochain_p2p_cell.clone(), args)
+            .await
+            .map_err(Box::new)?;
+        match result {
+            MigrateAgentResult::Pass => Ok(()),
+            r => Err(CellError::MigrateAgentFailed(r)),
+        }
+    }
+
     /// Clean up long-running managed tasks.
     pub async fn cleanup(&self) -> CellResult<()> {
         use holochain_p2p::HolochainP2pDnaT;
@@ -130,15 +130,25 @@ mod tests {
     use crate::test_utils::valid_arbitrary_chain;
     use pretty_assertions::assert_eq;
 
+    /// Run the reference CHC server from `hc_run_local_services` on a random port.
+    fn run_reference_server() -> url::Url {
+        use holochain_cli_run_local_services::chc;
+
+        let store = chc::ChcStore::in_memory().unwrap();
+        let (driver, addr) = chc::run(([127, 0, 0, 1], 0), store).unwrap();
+        tokio::spawn(driver);
+        url::Url::parse(&format!("http://{addr}/")).unwrap()
+    }
+
     #[tokio::test(flavor = "multi_thread")]
-    #[ignore = "this test requires a remote service, so it should only be run manually"]
     async fn test_add_records_remote() {
         let keystore = holochain_keystore::test_keystore();
         let agent = fake_agent_pubkey_1();
         let cell_id = CellId::new(::fixt::fixt!(DnaHash), agent.clone());
         let chc = Arc::new(ChcRemote::new(
-            url::Url::parse("http://127.0.0.1:40845/").unwrap(),
+            // To test against a deployed service, use its URL here instead, e.g.
             // url::Url::parse("https://chc.dev.holotest.net/v1/").unwrap(),
+            run_reference_server(),
             keystore.clone(),
             &cell_id,
         ));
@@ -209,4 +219,36 @@ mod tests {
             &[]
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_rejects_foreign_records() {
+        let keystore = holochain_keystore::test_keystore();
+        let agent = fake_agent_pubkey_1();
+        let url = run_reference_server();
+        let cell_id = CellId::new(::fixt::fixt!(DnaHash), agent.clone());
+        let chc = Arc::new(ChcRemote::new(url.clone(), keystore.clone(), &cell_id));
+
+        let mut g = random_generator();
+        let chain = valid_arbitrary_chain(&mut g, keystore.clone(), agent, 3).await;
+        chc.clone().add_records(chain.clone()).await.unwrap();
+
+        // Another agent can't write to this agent's chain
+        let other = fake_agent_pubkey_2();
+        let foreign_chain = valid_arbitrary_chain(&mut g, keystore.clone(), other.clone(), 3).await;
+        let foreign = Arc::new(ChcRemote::new(
+            url,
+            keystore,
+            &CellId::new(cell_id.dna_hash().clone(), other),
+        ));
+        let payload =
+            AddRecordPayload::from_records(foreign.keystore.clone(), foreign.agent.clone(), foreign_chain)
+                .await
+                .unwrap();
+        assert!(chc.add_records_request(payload).await.is_err());
+
+        assert_eq!(
+            chc.clone().get_record_data(None).await.unwrap(),
+            chain
+        );
+    }
 }
@@ -86,6 +86,7 @@ use holochain_conductor_api::FullIntegrationStateDump;
 use holochain_conductor_api::FullStateDump;
 use holochain_conductor_api::IntegrationStateDump;
 use holochain_conductor_api::JsonDump;
+use holochain_conductor_api::ZomeFunctions;
 use holochain_keystore::lair_keystore::spawn_lair_keystore;
 use holochain_keystore::lair_keystore::spawn_lair_keystore_in_proc;
 use holochain_keystore::MetaLairClient;
@@ -104,9 +105,13 @@ use itertools::Itertools;
 use kitsune_p2p::agent_store::AgentInfoSigned;
 use kitsune_p2p::KitsuneP2pError;
 use kitsune_p2p_types::config::JOIN_NETWORK_TIMEOUT;
+use mr_bundle::error::MrBundleResult;
+use mr_bundle::signature::detached_signature_path;
+use mr_bundle::signature::read_detached_signatures;
 use rusqlite::Transaction;
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -627,7 +632,10 @@ mod dna_impls {
             // try to join all the tasks and return the list of dna files
             let wasms = wasms.into_iter().map(|(dna_def, wasms)| async move {
                 let dna_file = DnaFile::new(dna_def.into_content(), wasms).await;
-                let ribosome = RealRibosome::new(dna_file, self.config.data_root_path.clone())?;
+                let ribosome = RealRibosome::new(dna_file, self.config.data_root_path.clone())?
+                    .with_metering_limits(
+                        self.config.conductor_tuning_params().wasm_metering_limits(),
+                    );
                 ConductorResult::Ok((ribosome.dna_hash().clone(), ribosome))
             });
             let dnas = futures::future::try_join_all(wasms).await?;
@@ -745,7 +753,8 @@ mod dna_impls {
 
         /// Install a [`DnaFile`](holochain_types::dna::DnaFile) in this Conductor
         pub async fn register_dna(&self, dna: DnaFile) -> ConductorResult<()> {
-            let ribosome = RealRibosome::new(dna, self.config.data_root_path.clone())?;
+            let ribosome = RealRibosome::new(dna, self.config.data_root_path.clone())?
+                .with_metering_limits(self.config.conductor_tuning_params().wasm_metering_limits());
             let entry_defs = self.register_dna_wasm(ribosome.clone()).await?;
             self.register_dna_entry_defs(entry_defs);
             self.add_ribosome_to_store(ribosome);
@@ -825,6 +834,14 @@ mod network_impls {
             self.spaces.unblock(input).await
         }
 
+        /// List all blocks, or only those in effect at the given time.
+        pub async fn list_blocks(
+            &self,
+            timestamp: Option<Timestamp>,
+        ) -> DatabaseResult<Vec<BlockSpan>> {
+            self.spaces.list_blocks(timestamp).await
+        }
+
         /// Check if some target is blocked.
         pub async fn is_blocked(
             &self,
@@ -856,6 +873,28 @@ mod network_impls {
             Ok(())
         }
 
+        /// Set the storage arc policy for all cells of an app role, overriding
+        /// the policy from the app manifest. If `None`, the conductor-wide arc
+        /// settings are used for the role.
+        ///
+        /// The new policy takes effect the next time each agent's info is
+        /// refreshed, according to `gossip_agent_info_update_interval_ms`.
+        pub async fn set_arc_policy(
+            &self,
+            installed_app_id: InstalledAppId,
+            role_name: RoleName,
+            arc_policy: Option<ArcPolicy>,
+        ) -> ConductorResult<()> {
+            self.update_state(move |mut state| {
+                state
+                    .get_app_mut(&installed_app_id)?
+                    .set_arc_policy(&role_name, arc_policy)?;
+                Ok(state)
+            })
+            .await?;
+            Ok(())
+        }
+
         pub(crate) async fn network_info(
             &self,
             payload: &NetworkInfoRequestPayload,
@@ -868,7 +907,13 @@ mod network_impls {
                 last_time_queried,
             } = payload;
 
+            let state = self.get_state().await?;
+            let state = &state;
+
             futures::future::join_all(dnas.iter().map(|dna| async move {
+                let arc_policy = state
+                    .arc_policy_for_cell(&CellId::new(dna.clone(), agent_pub_key.clone()))
+                    .cloned();
                 let diagnostics = self.holochain_p2p.get_diagnostics(dna.clone()).await?;
                 let fetch_pool_info = diagnostics
                     .fetch_pool
@@ -973,6 +1018,7 @@ mod network_impls {
                     total_network_peers,
                     bytes_since_last_time_queried,
                     completed_rounds_since_last_time_queried,
+                    arc_policy,
                 })
             }))
             .await
@@ -1251,12 +1297,48 @@ mod network_impls {
             Ok(RealRibosome::tooling_imports().await?)
         }
 
+        /// List the functions which can be called, and the callbacks which are
+        /// implemented, for each zome of a cell.
+        pub async fn list_zome_functions(
+            &self,
+            cell_id: &CellId,
+        ) -> ConductorApiResult<Vec<ZomeFunctions>> {
+            self.cell_by_id(cell_id, false).await?;
+            let ribosome = self.get_ribosome(cell_id.dna_hash())?;
+            let zomes = ribosome
+                .dna_def()
+                .all_zomes()
+                .map(|(zome_name, zome_def)| Zome::new(zome_name.clone(), zome_def.clone()))
+                .collect::<Vec<_>>();
+            zomes
+                .into_iter()
+                .map(|zome| {
+                    Ok(ZomeFunctions {
+                        functions: ribosome.list_zome_fns(&zome)?,
+                        callbacks: ribosome.list_callbacks(&zome)?,
+                        zome_name: zome.into_inner().0,
+                    })
+                })
+                .collect()
+        }
+
         /// Invoke a zome function on a Cell
         pub async fn call_zome(&self, call: ZomeCall) -> ConductorApiResult<ZomeCallResult> {
             let cell = self.cell_by_id(&call.cell_id, true).await?;
             Ok(cell.call_zome(call, None).await?)
         }
 
+        /// Make a zome call, also returning the wasm metering points used
+        /// by the zome function.
+        pub async fn call_zome_metered(
+            &self,
+            call: ZomeCall,
+        ) -> ConductorApiResult<(ZomeCallResult, u64)> {
+            let (result, metering_points) =
+                crate::core::ribosome::metering::metered(self.call_zome(call)).await;
+            Ok((result?, metering_points))
+        }
+
         pub(crate) async fn call_zome_with_workspace(
             &self,
             call: ZomeCall,
@@ -1338,6 +1420,47 @@ mod app_impls {
             Ok(())
         }
 
+        /// Get the app bundle from a source. If the conductor only trusts bundles
+        /// signed by certain keys, check that the bundle is signed by one of them.
+        async fn resolve_app_bundle(&self, source: AppBundleSource) -> ConductorResult<AppBundle> {
+            let path = match &source {
+                AppBundleSource::Path(path) => Some(path.clone()),
+                AppBundleSource::Bundle(_) => None,
+            };
+            let bundle = source.resolve().await?;
+            self.check_bundle_signed(&bundle, path.as_deref())
+                .await
+                .map_err(AppBundleError::from)?;
+            Ok(bundle)
+        }
+
+        /// If the conductor only trusts bundles signed by certain keys, check
+        /// that the bundle is signed by one of them. The signature of a bundle
+        /// read from `path` may also be in a detached signature file next to it.
+        pub async fn check_bundle_signed<M: mr_bundle::Manifest>(
+            &self,
+            bundle: &mr_bundle::Bundle<M>,
+            path: Option<&Path>,
+        ) -> MrBundleResult<()> {
+            if let Some(trusted_signers) = &self.config.trusted_app_signers {
+                let detached = match path.map(detached_signature_path) {
+                    Some(path) if path.exists() => read_detached_signatures(&path).await?,
+                    _ => vec![],
+                };
+                let trusted_signers: Vec<[u8; 32]> = trusted_signers
+                    .iter()
+                    .map(|signer| {
+                        AgentPubKey::from(signer.clone())
+                            .get_raw_32()
+                            .try_into()
+                            .expect("An agent key is 32 bytes")
+                    })
+                    .collect();
+                bundle.verify_signed_by(&trusted_signers, &detached)?;
+            }
+            Ok(())
+        }
+
         /// Install DNAs and set up Cells as specified by an AppBundle
         pub async fn install_app_bundle(
             self: Arc<Self>,
@@ -1358,7 +1481,7 @@ mod app_impls {
             } = payload;
 
             let bundle = {
-                let original_bundle = source.resolve().await?;
+                let original_bundle = self.resolve_app_bundle(source).await?;
                 if let Some(network_seed) = network_seed {
                     let mut manifest = original_bundle.manifest().to_owned();
                     manifest.set_network_seed(network_seed);
@@ -1411,6 +1534,20 @@ mod app_impls {
                 crate::conductor::conductor::genesis_cells(self.clone(), cells_to_create).await;
 
             if genesis_result.is_ok() || ignore_genesis_failure {
+                for cell_id in cell_ids.iter() {
+                    for (app_id, previous) in self
+                        .find_cells_in_dna_lineage(cell_id.dna_hash(), cell_id.agent_pubkey())
+                        .await?
+                    {
+                        tracing::info!(
+                            ?cell_id,
+                            previous_cell_id = ?previous,
+                            previous_app_id = %app_id,
+                            "Installed a new version of a DNA for an agent with a cell of a previous version",
+                        );
+                    }
+                }
+
                 let roles = ops.role_assignments;
                 let app = InstalledAppCommon::new(installed_app_id, agent_key, roles, manifest)?;
 
@@ -1428,6 +1565,195 @@ mod app_impls {
             }
         }
 
+        /// Update the coordinator zomes of an installed app from a new version
+        /// of its bundle.
+        ///
+        /// The new bundle must have the same roles, and the DNA of each role must
+        /// have the same hash and lineage once the installed modifiers are
+        /// applied, i.e. only the coordinator zomes may change. If so, the
+        /// coordinators of every cell of the app, including clone cells, are
+        /// updated. Otherwise nothing is changed, and the error lists every
+        /// difference found.
+        ///
+        /// Roles which use an existing cell have no DNA in the bundle, so they are
+        /// not updated.
+        ///
+        /// Either every DNA of the app is updated or, if anything fails, none
+        /// of them are.
+        pub async fn update_app(
+            &self,
+            installed_app_id: &InstalledAppId,
+            source: AppBundleSource,
+        ) -> ConductorResult<()> {
+            let bundle = self.resolve_app_bundle(source).await?;
+            let app = self.get_state().await?.get_app(installed_app_id)?.clone();
+            let mut new_dnas = bundle.resolve_dnas().await?;
+            let mut new_role_names: HashSet<_> = bundle
+                .manifest()
+                .app_roles()
+                .into_iter()
+                .map(|role| role.name)
+                .collect();
+
+            let mut diff = Vec::new();
+            let mut updates: HashMap<DnaHash, (RoleName, CoordinatorZomes, Vec<wasm::DnaWasm>)> =
+                HashMap::new();
+            for (role_name, role) in app.roles() {
+                if !new_role_names.remove(role_name) {
+                    diff.push(format!("role `{}` was removed", role_name));
+                    continue;
+                }
+                let Some(new_dna) = new_dnas.remove(role_name) else {
+                    diff.push(format!(
+                        "role `{}` would use an existing cell instead of its own DNA",
+                        role_name
+                    ));
+                    continue;
+                };
+                let dna_hash = role.dna_hash();
+                let installed = self
+                    .get_dna_def(dna_hash)
+                    .ok_or_else(|| DnaError::DnaMissing(dna_hash.clone()))?;
+                let new_dna = new_dna.update_modifiers(DnaModifiersOpt {
+                    network_seed: Some(installed.modifiers.network_seed.clone()),
+                    properties: Some(installed.modifiers.properties.clone()),
+                    origin_time: Some(installed.modifiers.origin_time),
+                    quantum_time: Some(installed.modifiers.quantum_time),
+                });
+                if new_dna.dna_hash() != dna_hash {
+                    diff.push(format!(
+                        "role `{}`: DNA hash would change from {} to {} ({})",
+                        role_name,
+                        dna_hash,
+                        new_dna.dna_hash(),
+                        integrity_zome_diff(&installed, new_dna.dna_def()),
+                    ));
+                    continue;
+                }
+                if new_dna.dna_def().lineage != installed.lineage {
+                    diff.push(format!("role `{}`: DNA lineage would change", role_name));
+                    continue;
+                }
+
+                let coordinator_zomes = new_dna.dna_def().coordinator_zomes.clone();
+                let wasms = coordinator_zomes
+                    .iter()
+                    .map(|(zome_name, _)| new_dna.get_wasm_for_zome(zome_name).cloned())
+                    .collect::<Result<Vec<_>, _>>()?;
+                let cell_ids = std::iter::once(role.cell_id())
+                    .chain(
+                        app.clone_cells_for_role_name(role_name)
+                            .into_iter()
+                            .flat_map(|c| c.values()),
+                    )
+                    .chain(
+                        app.disabled_clone_cells_for_role_name(role_name)
+                            .into_iter()
+                            .flat_map(|c| c.values()),
+                    );
+                for cell_id in cell_ids {
+                    match updates.get(cell_id.dna_hash()) {
+                        Some((other_role, other_zomes, _)) => {
+                            if *other_zomes != coordinator_zomes {
+                                diff.push(format!(
+                                    "roles `{}` and `{}` share DNA {} but would get different coordinator zomes",
+                                    other_role,
+                                    role_name,
+                                    cell_id.dna_hash(),
+                                ));
+                            }
+                        }
+                        None => {
+                            updates.insert(
+                                cell_id.dna_hash().clone(),
+                                (role_name.clone(), coordinator_zomes.clone(), wasms.clone()),
+                            );
+                        }
+                    }
+                }
+            }
+            diff.extend(
+                new_role_names
+                    .into_iter()
+                    .map(|role_name| format!("role `{}` was added", role_name)),
+            );
+            if !diff.is_empty() {
+                diff.sort();
+                return Err(ConductorError::AppUpdateRejected(
+                    installed_app_id.clone(),
+                    diff,
+                ));
+            }
+
+            // Prepare every updated ribosome before changing anything. Storing the
+            // new wasm code only adds to the wasm database, so it's harmless if
+            // the update fails later.
+            let mut previous = Vec::with_capacity(updates.len());
+            let mut updated = Vec::with_capacity(updates.len());
+            for (dna_hash, (_, coordinator_zomes, wasms)) in updates {
+                let ribosome = self
+                    .ribosome_store()
+                    .share_ref(|d| d.get_ribosome(&dna_hash))
+                    .ok_or_else(|| DnaError::DnaMissing(dna_hash.clone()))?;
+                let mut new_ribosome = ribosome.clone();
+                new_ribosome
+                    .dna_file
+                    .update_coordinators(coordinator_zomes, wasms.clone())
+                    .await?;
+                self.put_wasm_code(
+                    new_ribosome.dna_def().clone(),
+                    wasms.into_iter(),
+                    Vec::with_capacity(0),
+                )
+                .await?;
+                previous.push(ribosome);
+                updated.push(new_ribosome);
+            }
+
+            // Swap in all the updated ribosomes at once, and swap the previous
+            // ones back if the app can't be updated.
+            self.ribosome_store().share_mut(|d| {
+                for ribosome in updated {
+                    d.add_ribosome(ribosome);
+                }
+            });
+            let manifest = bundle.manifest().clone();
+            let app_id = installed_app_id.clone();
+            let result = self
+                .update_state(move |mut state| {
+                    state.get_app_mut(&app_id)?.update_manifest(manifest);
+                    Ok(state)
+                })
+                .await;
+            if let Err(err) = result {
+                self.ribosome_store().share_mut(|d| {
+                    for ribosome in previous {
+                        d.add_ribosome(ribosome);
+                    }
+                });
+                return Err(err);
+            }
+            Ok(())
+        }
+
+        /// Find the cells of an agent, in any installed app, whose DNA is one
+        /// of the previous versions listed in the lineage of the given DNA.
+        ///
+        /// This lets a new version of a DNA locate the data an agent created
+        /// under the versions it supersedes.
+        pub async fn find_cells_in_dna_lineage(
+            &self,
+            dna_hash: &DnaHash,
+            agent: &AgentPubKey,
+        ) -> ConductorResult<Vec<(InstalledAppId, CellId)>> {
+            let lineage = match self.get_dna_def(dna_hash) {
+                Some(dna_def) if !dna_def.lineage.is_empty() => dna_def.lineage,
+                _ => return Ok(vec![]),
+            };
+            let state = self.get_state().await?;
+            Ok(cells_in_lineage(&state, &lineage, agent))
+        }
+
         /// Uninstall an app
         #[tracing::instrument(skip(self))]
         pub async fn uninstall_app(
@@ -1547,12 +1873,38 @@ mod app_impls {
         ) -> ConductorResult<Option<AppInfo>> {
             match state.installed_apps().get(app_id) {
                 None => Ok(None),
-                Some(app) => {
-                    let dna_definitions = self.get_dna_definitions(app)?;
-                    Ok(Some(AppInfo::from_installed_app(app, &dna_definitions)))
-                }
+                Some(app) => Ok(Some(self.app_info(app, state)?)),
             }
         }
+
+        /// Get the info of an app, including the cells of the previous
+        /// versions of each role's DNA.
+        pub(crate) fn app_info(
+            &self,
+            app: &InstalledApp,
+            state: &ConductorState,
+        ) -> ConductorResult<AppInfo> {
+            let dna_definitions = self.get_dna_definitions(app)?;
+            let lineage_cells = app
+                .roles()
+                .iter()
+                .filter_map(|(role_name, role)| {
+                    let dna_def = dna_definitions.get(role.provisioned_cell()?)?;
+                    Some((
+                        role_name.clone(),
+                        cells_in_lineage(state, &dna_def.lineage, app.agent_key())
+                            .into_iter()
+                            .map(|(_, cell_id)| cell_id)
+                            .collect(),
+                    ))
+                })
+                .collect();
+            Ok(AppInfo::from_installed_app(
+                app,
+                &dna_definitions,
+                lineage_cells,
+            ))
+        }
     }
 }
 
@@ -1591,6 +1943,45 @@ mod cell_impls {
             }
         }
 
+        /// Migrate an agent from a cell of one DNA to a cell of another, typically
+        /// a newer version of the same DNA with breaking integrity changes.
+        ///
+        /// The `migrate_agent(Close)` callbacks are run in the old cell, whose chain
+        /// is then ended with a `CloseChain` action. Then the `migrate_agent(Open)`
+        /// callbacks are run in the new cell, whose chain records where it came
+        /// from with an `OpenChain` action. Nothing may be committed to the old
+        /// chain afterwards.
+        ///
+        /// If opening the new chain fails, the migration can be retried once
+        /// the problem is fixed. Each step is skipped if it has already been
+        /// done, i.e. if the old chain is already closed towards the new DNA or
+        /// the new chain already has an `OpenChain` from the old DNA.
+        pub async fn migrate_agent(
+            &self,
+            from_cell_id: CellId,
+            to_cell_id: CellId,
+        ) -> ConductorResult<()> {
+            if from_cell_id.agent_pubkey() != to_cell_id.agent_pubkey()
+                || from_cell_id.dna_hash() == to_cell_id.dna_hash()
+            {
+                return Err(ConductorError::InvalidAgentMigration(
+                    from_cell_id,
+                    to_cell_id,
+                ));
+            }
+            // Make sure both cells are running before touching either chain
+            let from_cell = self.cell_by_id(&from_cell_id, false).await?;
+            let to_cell = self.cell_by_id(&to_cell_id, false).await?;
+
+            from_cell
+                .migrate_agent(MigrateAgent::Close, to_cell_id.dna_hash().clone())
+                .await?;
+            to_cell
+                .migrate_agent(MigrateAgent::Open, from_cell_id.dna_hash().clone())
+                .await?;
+            Ok(())
+        }
+
         /// Iterator over only the cells which are fully "live", meaning they have been
         /// fully initialized and are registered with the kitsune network layer.
         /// Generally used to handle conductor interface requests.
@@ -2178,14 +2569,152 @@ mod scheduler_impls {
                 .map(|cell_arc| cell_arc.dispatch_scheduled_fns(now));
             futures::future::join_all(tasks).await;
         }
+
+        /// List the pending schedules of all zomes for a cell, soonest first.
+        pub async fn list_schedules(
+            &self,
+            cell_id: &CellId,
+        ) -> ConductorResult<Vec<ScheduledFnInfo>> {
+            let cell = self.cell_by_id(cell_id, false).await?;
+            let author = cell_id.agent_pubkey().clone();
+            Ok(cell
+                .authored_db()
+                .read_async(move |txn| holochain_state::schedule::list_scheduled_fns(&txn, &author))
+                .await?)
+        }
+
+        /// Cancel a pending schedule for a cell.
+        /// Returns whether the function was scheduled.
+        pub async fn cancel_schedule(
+            &self,
+            cell_id: &CellId,
+            scheduled_fn: ScheduledFn,
+        ) -> ConductorResult<bool> {
+            let cell = self.cell_by_id(cell_id, false).await?;
+            let author = cell_id.agent_pubkey().clone();
+            Ok(cell
+                .authored_db()
+                .write_async(move |txn| cancel_scheduled_fn(txn, &author, &scheduled_fn))
+                .await?)
+        }
+    }
+}
+
+/// Methods related to the `on_integrated` callback
+mod on_integrated_impls {
+    use super::*;
+    use crate::core::workflow::app_validation_workflow::dhtop_to_op;
+    use holochain_cascade::CascadeImpl;
+
+    impl Conductor {
+        /// Run the `on_integrated` callback on every joined cell of a DNA for
+        /// the ops that were integrated into the DNA's DHT database after
+        /// `integrated_after`, up to and including `integrated_until`.
+        /// This returns immediately and the callbacks are run in a spawned task.
+        pub(crate) fn dispatch_on_integrated(
+            self: &Arc<Self>,
+            dna_hash: DnaHash,
+            integrated_after: Timestamp,
+            integrated_until: Timestamp,
+        ) {
+            let conductor = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = conductor
+                    .run_on_integrated(&dna_hash, integrated_after, integrated_until)
+                    .await
+                {
+                    tracing::error!(?dna_hash, "Failed to run on_integrated callbacks: {:?}", e);
+                }
+            });
+        }
+
+        async fn run_on_integrated(
+            &self,
+            dna_hash: &DnaHash,
+            integrated_after: Timestamp,
+            integrated_until: Timestamp,
+        ) -> ConductorResult<()> {
+            // Only coordinator zomes which export the callback opt in to it,
+            // so there is nothing to look up if none of them do.
+            let ribosome = self.get_ribosome(dna_hash)?;
+            let on_integrated = FunctionName::from("on_integrated");
+            let mut zomes = Vec::new();
+            for zome in ribosome.dna_def().get_all_coordinators() {
+                if ribosome
+                    .list_callbacks(&zome.clone().erase_type())?
+                    .contains(&on_integrated)
+                {
+                    zomes.push(zome);
+                }
+            }
+            if zomes.is_empty() {
+                return Ok(());
+            }
+
+            let mut cells = Vec::new();
+            for cell_id in self.running_cell_ids(Some(CellStatusFilter::Joined)) {
+                if cell_id.dna_hash() == dna_hash {
+                    cells.push(self.cell_by_id(&cell_id, false).await?);
+                }
+            }
+            let Some(first_cell) = cells.first() else {
+                return Ok(());
+            };
+
+            let integrated = self
+                .spaces
+                .get_integrated_ops(dna_hash, integrated_after, integrated_until)
+                .await?;
+            if integrated.is_empty() {
+                return Ok(());
+            }
+
+            // Integrated ops have been validated, so anything needed to turn
+            // them into the ops zomes see is held locally or can be fetched.
+            let space = self.get_or_create_space(dna_hash)?;
+            let cascade = Arc::new(
+                CascadeImpl::empty()
+                    .with_dht(space.dht_db.clone().into())
+                    .with_network(first_cell.holochain_p2p_dna().clone(), space.cache_db),
+            );
+            let mut ops = Vec::with_capacity(integrated.len());
+            for (hash, op, validation_status) in integrated {
+                match dhtop_to_op(op, cascade.clone()).await {
+                    Ok(op) => ops.push(IntegratedOp {
+                        op,
+                        validation_status,
+                    }),
+                    Err(e) => {
+                        tracing::warn!(
+                            ?hash,
+                            "Integrated op is not passed to on_integrated: {:?}",
+                            e
+                        )
+                    }
+                }
+            }
+
+            for cell in cells {
+                if let Err(e) = cell
+                    .dispatch_on_integrated(zomes.clone(), ops.clone())
+                    .await
+                {
+                    tracing::error!(cell_id = ?cell.id(), "Failed to run on_integrated callbacks: {:?}", e);
+                }
+            }
+            Ok(())
+        }
     }
 }
 
 /// Miscellaneous methods
 mod misc_impls {
+    use holochain_p2p::dht::region::RegionBounds;
     use holochain_zome_types::action::builder;
+    use std::collections::BTreeMap;
 
     use super::*;
+    use crate::conductor::api::error::ConductorApiError;
 
     impl Conductor {
         /// Grant a zome call capability for a cell
@@ -2278,6 +2807,22 @@ mod misc_impls {
             Ok(out)
         }
 
+        /// List the validation receipts received for the ops authored by a cell.
+        /// The cell must exist, so that no database is created just to be read.
+        pub async fn dump_validation_receipts(
+            &self,
+            cell_id: &CellId,
+        ) -> ConductorApiResult<Vec<SignedValidationReceipt>> {
+            let cell = self.cell_by_id(cell_id, false).await?;
+            let author = cell_id.agent_pubkey().clone();
+            Ok(cell
+                .dht_db()
+                .read_async(move |txn| {
+                    holochain_state::validation_receipts::list_receipts_for_author(&txn, &author)
+                })
+                .await?)
+        }
+
         /// JSON dump of network metrics
         pub async fn dump_network_metrics(
             &self,
@@ -2334,6 +2879,88 @@ mod misc_impls {
             .await
         }
 
+        /// Export the integrated ops held for a DNA which match the filter
+        /// into an archive signed by the given agent, so that they can be
+        /// imported into another conductor with [`Conductor::import_ops`].
+        pub async fn export_ops(
+            &self,
+            dna_hash: DnaHash,
+            filter: OpExportFilter,
+            signer: AgentPubKey,
+        ) -> ConductorApiResult<SignedOpArchive> {
+            let full_arc = (0u32, u32::MAX);
+            let regions = match &filter {
+                OpExportFilter::All => {
+                    vec![RegionBounds::new(
+                        full_arc,
+                        (Timestamp::MIN, Timestamp::MAX),
+                    )]
+                }
+                OpExportFilter::TimeWindow { start, end } => {
+                    vec![RegionBounds::new(full_arc, (*start, *end))]
+                }
+                OpExportFilter::Regions(coords) => {
+                    let cutoff = self
+                        .get_config()
+                        .network
+                        .tuning_params
+                        .danger_gossip_recent_threshold();
+                    let topo = self
+                        .get_dna_def(&dna_hash)
+                        .ok_or_else(|| DnaError::DnaMissing(dna_hash.clone()))?
+                        .topology(cutoff);
+                    coords.iter().map(|c| c.to_bounds(&topo)).collect()
+                }
+            };
+
+            // Regions may overlap, so make sure each op is only exported once.
+            let ops: BTreeMap<_, _> = self
+                .spaces
+                .handle_fetch_op_data_by_regions(&dna_hash, regions)
+                .await?
+                .into_iter()
+                .collect();
+
+            Ok(
+                OpArchive::new(dna_hash, filter, ops.into_values().collect())
+                    .sign(&self.keystore, signer)
+                    .await?,
+            )
+        }
+
+        /// Import the ops from an archive created by [`Conductor::export_ops`].
+        ///
+        /// After checking the archive's signature, the ops are sent through the
+        /// incoming dht ops workflow, so they are fully validated just as if
+        /// they had been received over the network. A cell for the archive's
+        /// DNA must be running on this conductor.
+        ///
+        /// Returns the number of ops which were submitted for validation.
+        pub async fn import_ops(&self, archive: SignedOpArchive) -> ConductorApiResult<usize> {
+            archive.verify().await?;
+            let OpArchive { dna_hash, ops, .. } = archive.into_archive();
+
+            if self.get_dna_def(&dna_hash).is_none() {
+                return Err(DnaError::DnaMissing(dna_hash).into());
+            }
+            if !self
+                .running_cell_ids(None)
+                .iter()
+                .any(|cell_id| cell_id.dna_hash() == &dna_hash)
+            {
+                return Err(ConductorApiError::other(format!(
+                    "Cannot import ops for DNA {} because no cell for it is running",
+                    dna_hash
+                )));
+            }
+
+            let count = ops.len();
+            self.spaces
+                .handle_publish(&dna_hash, false, false, ops)
+                .await?;
+            Ok(count)
+        }
+
         /// Update coordinator zomes on an existing dna.
         pub async fn update_coordinators(
             &self,
@@ -2373,6 +3000,59 @@ mod misc_impls {
     }
 }
 
+/// Find the cells of an agent, in any installed app, whose DNA is in the
+/// given lineage.
+fn cells_in_lineage(
+    state: &ConductorState,
+    lineage: &HashSet<DnaHash>,
+    agent: &AgentPubKey,
+) -> Vec<(InstalledAppId, CellId)> {
+    state
+        .installed_apps()
+        .iter()
+        .flat_map(|(app_id, app)| {
+            app.all_cells()
+                .filter(|cell_id| {
+                    cell_id.agent_pubkey() == agent && lineage.contains(cell_id.dna_hash())
+                })
+                .map(|cell_id| (app_id.clone(), cell_id.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Describe how the integrity zomes of two versions of a DNA differ.
+fn integrity_zome_diff(old: &DnaDef, new: &DnaDef) -> String {
+    let mut changes = Vec::new();
+    for (zome_name, def) in new.integrity_zomes.iter() {
+        match old
+            .integrity_zomes
+            .iter()
+            .find(|(name, _)| name == zome_name)
+        {
+            None => changes.push(format!("integrity zome `{}` was added", zome_name)),
+            Some((_, old_def)) if old_def != def => {
+                changes.push(format!("integrity zome `{}` was changed", zome_name))
+            }
+            _ => (),
+        }
+    }
+    for (zome_name, _) in old.integrity_zomes.iter() {
+        if !new
+            .integrity_zomes
+            .iter()
+            .any(|(name, _)| name == zome_name)
+        {
+            changes.push(format!("integrity zome `{}` was removed", zome_name));
+        }
+    }
+    if changes.is_empty() {
+        "the order of the integrity zomes was changed".to_string()
+    } else {
+        changes.join(", ")
+    }
+}
+
 /// Pure accessor methods
 mod accessor_impls {
     use super::*;
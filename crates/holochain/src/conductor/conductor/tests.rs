@@ -779,6 +779,122 @@ async fn name_has_no_effect_on_dna_hash() {
     assert_eq!(clone2.cell_id.dna_hash(), clone3.cell_id.dna_hash());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn find_cells_in_dna_lineage() {
+    holochain_trace::test_run().ok();
+    let mut conductor = SweetConductor::from_standard_config().await;
+    let (alice, bob) = SweetAgents::two(conductor.keystore()).await;
+
+    let dna_v1 = SweetDnaFile::unique_empty().await;
+    let dna_v2 = {
+        let dna = SweetDnaFile::unique_empty().await;
+        let mut dna_def = dna.dna_def().clone();
+        dna_def.lineage = [dna_v1.dna_hash().clone()].into_iter().collect();
+        DnaFile::new(dna_def, dna.code().values().cloned()).await
+    };
+
+    let app_v1 = conductor
+        .setup_app_for_agent("app_v1", alice.clone(), [&dna_v1])
+        .await
+        .unwrap();
+    conductor
+        .setup_app_for_agent("app_v2", alice.clone(), [&dna_v2])
+        .await
+        .unwrap();
+    let (cell_v1,) = app_v1.into_tuple();
+
+    assert_eq!(
+        conductor
+            .find_cells_in_dna_lineage(dna_v2.dna_hash(), &alice)
+            .await
+            .unwrap(),
+        vec![("app_v1".to_string(), cell_v1.cell_id().clone())]
+    );
+    // Only cells of the same agent are found
+    assert!(conductor
+        .find_cells_in_dna_lineage(dna_v2.dna_hash(), &bob)
+        .await
+        .unwrap()
+        .is_empty());
+    // The lineage only points backwards
+    assert!(conductor
+        .find_cells_in_dna_lineage(dna_v1.dna_hash(), &alice)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // The cells are exposed in the info of the new version's app
+    let app_info = conductor
+        .get_app_info(&"app_v2".to_string())
+        .await
+        .unwrap()
+        .unwrap();
+    let role_name = app_info.cell_info.keys().next().unwrap().clone();
+    assert_eq!(
+        app_info.lineage_cells,
+        [(role_name, vec![cell_v1.cell_id().clone()])]
+            .into_iter()
+            .collect::<HashMap<_, _>>()
+    );
+    let app_info = conductor
+        .get_app_info(&"app_v1".to_string())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(app_info
+        .lineage_cells
+        .values()
+        .all(|cells| cells.is_empty()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn block_unblock_and_list_blocks() {
+    holochain_trace::test_run().ok();
+    let conductor = SweetConductor::from_standard_config().await;
+
+    let interval = InclusiveTimestampInterval::try_new(Timestamp::MIN, Timestamp::now()).unwrap();
+    let ip = BlockTarget::Ip(
+        std::net::Ipv6Addr::LOCALHOST.into(),
+        kitsune_p2p_block::IpBlockReason::DOS.into(),
+    );
+    let node = BlockTarget::Node(
+        Arc::new([1; 32]).into(),
+        kitsune_p2p_block::NodeBlockReason::DOS.into(),
+    );
+    for target in [ip.clone(), node.clone()] {
+        conductor
+            .block(Block::new(target, interval.clone()))
+            .await
+            .unwrap();
+    }
+
+    // A node with no known agents is still blocked when blocked directly.
+    for target in [&ip, &node] {
+        assert!(conductor
+            .is_blocked(target.clone().into(), Timestamp::MIN)
+            .await
+            .unwrap());
+    }
+    assert_eq!(2, conductor.list_blocks(None).await.unwrap().len());
+
+    conductor
+        .unblock(Block::new(ip.clone(), interval.clone()))
+        .await
+        .unwrap();
+    assert!(!conductor
+        .is_blocked(ip.into(), Timestamp::MIN)
+        .await
+        .unwrap());
+    assert_eq!(
+        vec![BlockSpan {
+            target: node,
+            start: interval.start(),
+            end: interval.end(),
+        }],
+        conductor.list_blocks(Some(Timestamp::MIN)).await.unwrap()
+    );
+}
+
 fn unwrap_cell_info_clone(cell_info: CellInfo) -> holochain_conductor_api::ClonedCell {
     match cell_info {
         CellInfo::Cloned(cell) => cell,
@@ -1192,3 +1308,101 @@ async fn test_init_concurrency() {
     assert_eq!(num_calls_clone.fetch_add(0, Ordering::SeqCst), 100);
     assert_eq!(num_inits_clone.fetch_add(0, Ordering::SeqCst), 1);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_migrate_agent() {
+    holochain_trace::test_run().ok();
+    let (dna_old, _, _) = mk_dna(("zome", simple_create_entry_zome())).await;
+    let (dna_new, _, _) = mk_dna(("zome", simple_create_entry_zome())).await;
+
+    let mut conductor = SweetConductor::from_standard_config().await;
+    let alice = SweetAgents::one(conductor.keystore()).await;
+    let (cell_old,) = conductor
+        .setup_app_for_agent("old", alice.clone(), [&dna_old])
+        .await
+        .unwrap()
+        .into_tuple();
+    let (cell_new,) = conductor
+        .setup_app_for_agent("new", alice.clone(), [&dna_new])
+        .await
+        .unwrap()
+        .into_tuple();
+    let _: ActionHash = conductor.call(&cell_old.zome("zome"), "create", ()).await;
+
+    // Cells of different agents can't be migrated between
+    let bob = SweetAgents::one(conductor.keystore()).await;
+    assert_matches!(
+        conductor
+            .migrate_agent(
+                cell_old.cell_id().clone(),
+                CellId::new(dna_new.dna_hash().clone(), bob)
+            )
+            .await,
+        Err(ConductorError::InvalidAgentMigration(_, _))
+    );
+
+    conductor
+        .migrate_agent(cell_old.cell_id().clone(), cell_new.cell_id().clone())
+        .await
+        .unwrap();
+
+    let last_action = |cell_id: CellId| {
+        let conductor = conductor.raw_handle();
+        async move {
+            conductor
+                .dump_full_cell_state(&cell_id, None)
+                .await
+                .unwrap()
+                .source_chain_dump
+                .records
+                .pop()
+                .unwrap()
+                .action
+        }
+    };
+    assert_matches!(
+        last_action(cell_old.cell_id().clone()).await,
+        Action::CloseChain(CloseChain { new_dna_hash, .. }) if new_dna_hash == *dna_new.dna_hash()
+    );
+    assert_matches!(
+        last_action(cell_new.cell_id().clone()).await,
+        Action::OpenChain(OpenChain { prev_dna_hash, .. }) if prev_dna_hash == *dna_old.dna_hash()
+    );
+
+    // Nothing more can be committed to the closed chain
+    assert!(conductor
+        .call_fallible::<_, ActionHash>(&cell_old.zome("zome"), "create", ())
+        .await
+        .is_err());
+
+    // The new chain carries on as normal
+    let _: ActionHash = conductor.call(&cell_new.zome("zome"), "create", ()).await;
+
+    // Retrying the migration doesn't close or open either chain again
+    conductor
+        .migrate_agent(cell_old.cell_id().clone(), cell_new.cell_id().clone())
+        .await
+        .unwrap();
+    let count_actions = |cell_id: CellId, action_type: ActionType| {
+        let conductor = conductor.raw_handle();
+        async move {
+            conductor
+                .dump_full_cell_state(&cell_id, None)
+                .await
+                .unwrap()
+                .source_chain_dump
+                .records
+                .into_iter()
+                .filter(|record| record.action.action_type() == action_type)
+                .count()
+        }
+    };
+    assert_eq!(
+        count_actions(cell_old.cell_id().clone(), ActionType::CloseChain).await,
+        1
+    );
+    assert_eq!(
+        count_actions(cell_new.cell_id().clone(), ActionType::OpenChain).await,
+        1
+    );
+}
@@ -91,6 +91,9 @@ pub enum ConductorError {
     #[error("Tried to access an app that was not installed: {0}")]
     AppNotInstalled(InstalledAppId),
 
+    #[error("App {0} cannot be updated, because more than its coordinator zomes would change:\n{}", .1.join("\n"))]
+    AppUpdateRejected(InstalledAppId, Vec<String>),
+
     #[error("Tried to install an app using an already-used InstalledAppId: {0}")]
     AppAlreadyInstalled(InstalledAppId),
 
@@ -133,6 +136,9 @@ pub enum ConductorError {
     #[error(transparent)]
     WebsocketError(#[from] WebsocketError),
 
+    #[error("An agent can only be migrated between two different DNAs and must keep the same key, but tried to migrate from {0:?} to {1:?}")]
+    InvalidAgentMigration(CellId, CellId),
+
     /// Other
     #[error("Other: {0}")]
     Other(Box<dyn std::error::Error + Send + Sync>),
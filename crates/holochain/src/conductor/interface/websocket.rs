@@ -500,6 +500,7 @@ pub mod test {
                             total_network_peers: 1,
                             bytes_since_last_time_queried: 1844,
                             completed_rounds_since_last_time_queried: 0,
+                            arc_policy: None,
                         }]
                     )
                 }
@@ -878,6 +879,7 @@ pub mod test {
                     .map(TestZomes::from)
                     .map(|z| z.coordinator.into_inner())
                     .collect(),
+                lineage: Default::default(),
             },
             zomes.into_iter().flat_map(|t| Vec::<DnaWasm>::from(t)),
         )
@@ -4,7 +4,7 @@ mod query_region_op_hashes;
 mod query_region_set;
 mod query_size_limited_regions;
 use holochain_conductor_api::conductor::ConductorConfig;
-use kitsune_p2p_bin_data::KitsuneSpace;
+use kitsune_p2p_bin_data::{KitsuneAgent, KitsuneSpace};
 pub use query_region_op_hashes::query_region_op_hashes;
 pub use query_region_set::query_region_set;
 pub use query_size_limited_regions::query_size_limited_regions;
@@ -13,17 +13,20 @@ use std::sync::Arc;
 
 use super::{ribosome_store::RibosomeStore, space::Spaces};
 use futures::FutureExt;
-use holo_hash::DnaHash;
+use holo_hash::{AgentPubKey, DnaHash};
 use holochain_p2p::{
-    dht::{spacetime::Topology, ArqStrat},
-    DhtOpHashExt, DnaHashExt, FetchContextExt,
+    dht::{
+        spacetime::{TelescopingTimes, Topology},
+        ArqStrat,
+    },
+    AgentPubKeyExt, DhtOpHashExt, DnaHashExt, FetchContextExt,
 };
 use holochain_sqlite::prelude::{AsP2pMetricStoreTxExt, AsP2pStateTxExt};
 use holochain_types::{
     prelude::{DhtOpHash, DnaError},
     share::RwShare,
 };
-use holochain_zome_types::prelude::Timestamp;
+use holochain_zome_types::prelude::{CellId, Timestamp};
 use kitsune_p2p::{
     agent_store::AgentInfoSigned,
     dependencies::kitsune_p2p_fetch::{OpHashSized, RoughSized, TransferMethod},
@@ -176,8 +179,27 @@ impl KitsuneHost for KitsuneHostImpl {
         async move {
             let topology = self.get_topology(space.clone()).await?;
             let db = self.spaces.dht_db(&dna_hash)?;
+            let times = TelescopingTimes::historical(&topology);
             let region_set =
-                query_region_set(db, topology.clone(), &self.strat, dht_arc_set).await?;
+                query_region_set(db, topology.clone(), &self.strat, dht_arc_set, times).await?;
+            Ok(region_set)
+        }
+        .boxed()
+        .into()
+    }
+
+    fn query_region_set_up_to_now(
+        &self,
+        space: Arc<kitsune_p2p::KitsuneSpace>,
+        dht_arc_set: Arc<holochain_p2p::dht_arc::DhtArcSet>,
+    ) -> KitsuneHostResult<holochain_p2p::dht::region_set::RegionSetLtcs> {
+        let dna_hash = DnaHash::from_kitsune(&space);
+        async move {
+            let topology = self.get_topology(space.clone()).await?;
+            let db = self.spaces.dht_db(&dna_hash)?;
+            let times = TelescopingTimes::up_to_now(&topology);
+            let region_set =
+                query_region_set(db, topology.clone(), &self.strat, dht_arc_set, times).await?;
             Ok(region_set)
         }
         .boxed()
@@ -319,6 +341,33 @@ impl KitsuneHost for KitsuneHostImpl {
         });
     }
 
+    fn gossip_strategy(&self, space: &KitsuneSpace) -> Option<String> {
+        let dna_hash = DnaHash::from_kitsune_raw(space.clone());
+        self.config
+            .gossip_strategy_overrides
+            .get(&dna_hash.into())
+            .cloned()
+    }
+
+    fn local_agent_arq_strat(
+        &self,
+        space: Arc<KitsuneSpace>,
+        agent: Arc<KitsuneAgent>,
+    ) -> KitsuneHostResult<Option<ArqStrat>> {
+        let cell_id = CellId::new(
+            DnaHash::from_kitsune(&space),
+            AgentPubKey::from_kitsune(&agent),
+        );
+        async move {
+            let state = self.spaces.get_state().await?;
+            Ok(state
+                .arc_policy_for_cell(&cell_id)
+                .map(|arc_policy| arc_policy.to_arq_strat(&self.strat)))
+        }
+        .boxed()
+        .into()
+    }
+
     fn lair_tag(&self) -> Option<Arc<str>> {
         self.lair_tag.clone()
     }
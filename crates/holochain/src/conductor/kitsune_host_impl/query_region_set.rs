@@ -20,11 +20,16 @@ const LOG_RATE_MS: i64 = 1000;
 /// If we calculate our region hash only by integrated ops, we will experience
 /// mismatches for a large number of ops repeatedly until we have integrated
 /// those ops. Note that when *sending* ops we filter out ops in limbo.
+///
+/// The `times` determine how far forward in time the regions extend:
+/// usually this is [`TelescopingTimes::historical`], but full-sync gossip
+/// uses [`TelescopingTimes::up_to_now`].
 pub async fn query_region_set(
     db: DbWrite<DbKindDht>,
     topology: Topology,
     strat: &ArqStrat,
     dht_arc_set: Arc<DhtArcSet>,
+    times: TelescopingTimes,
 ) -> ConductorResult<RegionSetLtcs> {
     let arq_set =
         ArqSet::from_dht_arc_set_exact(&topology, strat, &dht_arc_set).unwrap_or_else(|| {
@@ -63,7 +68,6 @@ pub async fn query_region_set(
             arq_set
         });
 
-    let times = TelescopingTimes::historical(&topology);
     let coords = RegionCoordSetLtcs::new(times, arq_set);
 
     let region_set = db
@@ -127,9 +131,12 @@ mod tests {
         let strat = ArqStrat::default();
         let arcset = Arc::new(DhtArcSet::Full);
 
-        let regions_empty = query_region_set(db.to_db(), topo.clone(), &strat, arcset.clone())
-            .await
-            .unwrap();
+        let times = TelescopingTimes::historical(&topo);
+
+        let regions_empty =
+            query_region_set(db.to_db(), topo.clone(), &strat, arcset.clone(), times)
+                .await
+                .unwrap();
         {
             let sum: RegionData = regions_empty.regions().map(|r| r.data).sum();
             assert_eq!(sum.count, 0);
@@ -170,7 +177,7 @@ mod tests {
             }
         });
 
-        let regions = query_region_set(db.to_db(), topo, &strat, arcset)
+        let regions = query_region_set(db.to_db(), topo, &strat, arcset, times)
             .await
             .unwrap();
 
@@ -2,6 +2,7 @@ use opentelemetry_api::{global::meter_with_version, metrics::*};
 
 pub type P2pEventDurationMetric = Histogram<f64>;
 pub type PostCommitDurationMetric = Histogram<f64>;
+pub type WasmMeteringMetric = Histogram<u64>;
 
 pub fn create_p2p_event_duration_metric() -> P2pEventDurationMetric {
     meter_with_version(
@@ -28,3 +29,16 @@ pub fn create_post_commit_duration_metric() -> P2pEventDurationMetric {
     .with_description("The time spent executing a post commit")
     .init()
 }
+
+pub fn create_wasm_metering_metric() -> WasmMeteringMetric {
+    meter_with_version(
+        "hc.conductor",
+        None::<&'static str>,
+        None::<&'static str>,
+        Some(vec![]),
+    )
+    .u64_histogram("hc.ribosome.wasm.usage")
+    .with_unit(Unit::new("points"))
+    .with_description("The metering points used by a call into a wasm zome")
+    .init()
+}
@@ -209,6 +209,36 @@ pub async fn reveal_peer_info(
     }
 }
 
+/// Reveal only the given agents in a single conductor to every agent in another.
+#[cfg(any(test, feature = "test_utils"))]
+pub async fn reveal_agents_peer_info(
+    observer_envs: Vec<DbWrite<DbKindP2pAgents>>,
+    seen_envs: Vec<DbWrite<DbKindP2pAgents>>,
+    agents_to_reveal: impl IntoIterator<Item = &AgentPubKey>,
+) {
+    use kitsune_p2p_types::KAgent;
+    use std::collections::HashSet;
+
+    let agents_to_reveal: HashSet<KAgent> = agents_to_reveal
+        .into_iter()
+        .map(|a| a.to_kitsune())
+        .collect();
+
+    for seen in seen_envs.iter() {
+        let infos: Vec<AgentInfoSigned> = all_agent_infos(seen.clone().into())
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|info| agents_to_reveal.contains(&info.agent))
+            .collect();
+        for observer in observer_envs.iter() {
+            inject_agent_infos(observer.clone(), infos.iter())
+                .await
+                .unwrap();
+        }
+    }
+}
+
 /// Get agent info for a single agent
 pub async fn get_agent_info_signed(
     environ: DbRead<DbKindP2pAgents>,
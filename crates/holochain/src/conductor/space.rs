@@ -195,6 +195,16 @@ impl Spaces {
             .collect())
     }
 
+    /// List all blocks, or only those in effect at the given time.
+    pub async fn list_blocks(
+        &self,
+        timestamp: Option<Timestamp>,
+    ) -> DatabaseResult<Vec<BlockSpan>> {
+        self.conductor_db
+            .read_async(move |txn| holochain_state::block::query_blocks(&txn, timestamp))
+            .await
+    }
+
     /// Check if some target is blocked.
     pub async fn is_blocked(
         &self,
@@ -215,39 +225,36 @@ impl Spaces {
                 )
                 .await?
             }
-            // @todo
-            BlockTargetId::Ip(_) => {
-                vec![]
-            }
+            // IPs aren't associated with any cells, so only direct blocks apply.
+            BlockTargetId::Ip(_) => vec![],
         };
 
-        // If node_agents_in_spaces is not yet initialized, we can't know anything about
-        // which cells are blocked, so avoid the race condition by returning false
-        // TODO: actually fix the preflight, because this could be a loophole for someone
-        //       to evade a block in some circumstances
-        if cell_ids.is_empty() {
-            return Ok(false);
-        }
-
         self.conductor_db
             .read_async(move |txn| {
-                Ok(
-                    // If the target_id is directly blocked then we always return true.
-                    holochain_state::block::query_is_blocked(&txn, target_id, timestamp)?
-            // If there are zero unblocked cells then return true.
-            || {
-                let mut all_blocked_cell_ids = true;
+                // If the target_id is directly blocked then we always return true.
+                if holochain_state::block::query_is_blocked(&txn, target_id, timestamp)? {
+                    return Ok(true);
+                }
+
+                // If node_agents_in_spaces is not yet initialized, we can't know anything about
+                // which cells are blocked, so avoid the race condition by returning false
+                // TODO: actually fix the preflight, because this could be a loophole for someone
+                //       to evade a block in some circumstances
+                if cell_ids.is_empty() {
+                    return Ok(false);
+                }
+
+                // If there are zero unblocked cells then return true.
                 for cell_id in cell_ids {
                     if !holochain_state::block::query_is_blocked(
                         &txn,
-                        BlockTargetId::Cell(cell_id), timestamp)? {
-                            all_blocked_cell_ids = false;
-                            break;
-                        }
+                        BlockTargetId::Cell(cell_id),
+                        timestamp,
+                    )? {
+                        return Ok(false);
+                    }
                 }
-                all_blocked_cell_ids
-            },
-                )
+                Ok(true)
             })
             .await
     }
@@ -613,6 +620,44 @@ impl Spaces {
         Ok(results)
     }
 
+    /// Get the ops which were integrated into the DHT database of a DNA after
+    /// `integrated_after`, up to and including `integrated_until`, along with
+    /// the validation status they were integrated with.
+    pub async fn get_integrated_ops(
+        &self,
+        dna_hash: &DnaHash,
+        integrated_after: Timestamp,
+        integrated_until: Timestamp,
+    ) -> ConductorResult<Vec<(DhtOpHash, DhtOp, ValidationStatus)>> {
+        let sql = holochain_sqlite::sql::sql_cell::SELECT_INTEGRATED_OPS;
+        Ok(self
+            .dht_db(dna_hash)?
+            .read_async(move |txn| {
+                let mut stmt = txn.prepare_cached(sql).map_err(StateQueryError::from)?;
+                let ops = stmt
+                    .query_and_then(
+                        named_params! {
+                            ":integrated_after": integrated_after,
+                            ":integrated_until": integrated_until,
+                        },
+                        |row| {
+                            let hash: DhtOpHash = row.get("hash").map_err(StateQueryError::from)?;
+                            let validation_status: ValidationStatus = row
+                                .get("validation_status")
+                                .map_err(StateQueryError::from)?;
+                            Ok(map_sql_dht_op_common(row)?.map(|op| (hash, op, validation_status)))
+                        },
+                    )
+                    .map_err(StateQueryError::from)?
+                    .collect::<Result<Vec<Option<_>>, StateQueryError>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                StateQueryResult::Ok(ops)
+            })
+            .await?)
+    }
+
     #[instrument(skip(self, request_validation_receipt, ops))]
     /// we are receiving a "publish" event from the network
     pub async fn handle_publish(
@@ -10,7 +10,7 @@ use holochain_keystore::test_keystore;
 use holochain_p2p::dht::hash::RegionHash;
 use holochain_p2p::dht::prelude::Dimension;
 use holochain_p2p::dht::region::RegionData;
-use holochain_p2p::dht::spacetime::STANDARD_QUANTUM_TIME;
+use holochain_p2p::dht::spacetime::{TelescopingTimes, STANDARD_QUANTUM_TIME};
 use holochain_p2p::dht_arc::DhtArcSet;
 use holochain_types::dht_op::{DhtOp, DhtOpHashed};
 use holochain_types::facts::valid_dht_op;
@@ -83,9 +83,15 @@ async fn test_region_queries() {
     let mut ops = vec![];
 
     // - Check that we have no ops to begin with
-    let region_set = query_region_set(db.clone(), topo.clone(), &strat, Arc::new(DhtArcSet::Full))
-        .await
-        .unwrap();
+    let region_set = query_region_set(
+        db.clone(),
+        topo.clone(),
+        &strat,
+        Arc::new(DhtArcSet::Full),
+        TelescopingTimes::historical(&topo),
+    )
+    .await
+    .unwrap();
     let region_sum: RegionData = region_set.regions().map(|r| r.data).sum();
     assert_eq!(region_sum.count as usize, 0);
 
@@ -110,9 +116,15 @@ async fn test_region_queries() {
         let op2 = DhtOpHashed::from_content_sync(op2);
         fill_db(&db, op2).await;
     }
-    let region_set = query_region_set(db.clone(), topo.clone(), &strat, Arc::new(DhtArcSet::Full))
-        .await
-        .unwrap();
+    let region_set = query_region_set(
+        db.clone(),
+        topo.clone(),
+        &strat,
+        Arc::new(DhtArcSet::Full),
+        TelescopingTimes::historical(&topo),
+    )
+    .await
+    .unwrap();
 
     // - Check that the aggregate of all region data matches expectations
     let region_sum: RegionData = region_set.regions().map(|r| r.data).sum();
@@ -126,6 +138,19 @@ async fn test_region_queries() {
     assert_eq!(region_sum.count as usize, NUM_OPS);
     assert_eq!(region_sum.hash, hash_sum);
 
+    // - Check that querying up to now also includes the recent ops
+    let full_region_set = query_region_set(
+        db.clone(),
+        topo.clone(),
+        &strat,
+        Arc::new(DhtArcSet::Full),
+        TelescopingTimes::up_to_now(&topo),
+    )
+    .await
+    .unwrap();
+    let full_region_sum: RegionData = full_region_set.regions().map(|r| r.data).sum();
+    assert_eq!(full_region_sum.count as usize, NUM_OPS * 2);
+
     let mut fetched_ops: Vec<_> = spaces
         .handle_fetch_op_data_by_regions(
             dna_def.as_hash(),
@@ -148,6 +148,13 @@ impl ConductorState {
             .ok_or_else(|| ConductorError::AppNotInstalled(id.clone()))
     }
 
+    /// Get the storage arc policy of the app role which a cell belongs to.
+    pub fn arc_policy_for_cell(&self, cell_id: &CellId) -> Option<&ArcPolicy> {
+        self.installed_apps
+            .values()
+            .find_map(|app| app.arc_policy_for_cell(cell_id))
+    }
+
     /// Getter for a mutable reference to a single app. Returns error if app missing.
     pub fn get_app_mut(&mut self, id: &InstalledAppId) -> ConductorResult<&mut InstalledApp> {
         self.installed_apps
@@ -36,6 +36,7 @@ async fn clone_only_provisioning_creates_no_cell_and_allows_cloning() {
                 clone_limit,
             },
             provisioning: Some(CellProvisioning::CloneOnly),
+            arc_policy: None,
         }];
 
         let manifest = AppManifestCurrentBuilder::default()
@@ -154,6 +155,7 @@ async fn reject_duplicate_app_for_same_agent() {
             clone_limit: 0,
         },
         provisioning: Some(CellProvisioning::Create { deferred: false }),
+        arc_policy: None,
     }];
 
     let manifest = AppManifestCurrentBuilder::default()
@@ -266,6 +268,7 @@ async fn can_install_app_a_second_time_using_nothing_but_the_manifest_from_app_i
             clone_limit: 0,
         },
         provisioning: Some(CellProvisioning::Create { deferred: false }),
+        arc_policy: None,
     }];
 
     let manifest = AppManifestCurrentBuilder::default()
@@ -369,6 +372,7 @@ async fn network_seed_regression() {
                 clone_limit: 0,
             },
             provisioning: None,
+            arc_policy: None,
         }];
 
         AppManifestCurrentBuilder::default()
@@ -600,6 +604,7 @@ impl TestCase {
                         clone_limit: 10,
                     },
                     provisioning: Some(CellProvisioning::Create { deferred: false }),
+                    arc_policy: None,
                 }];
                 let manifest = AppManifestCurrentBuilder::default()
                     .name(case_str.clone())
@@ -624,6 +629,7 @@ impl TestCase {
                         clone_limit: 0,
                     },
                     provisioning: None,
+                    arc_policy: None,
                 }];
 
                 let manifest = AppManifestCurrentBuilder::default()
@@ -673,3 +679,157 @@ impl TestCase {
         (installed_hash, case)
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn install_requires_signature_by_trusted_signer() {
+    use crate::conductor::api::{
+        AdminInterfaceApi, AdminRequest, AdminResponse, RealAdminInterfaceApi,
+    };
+    use mr_bundle::error::{MrBundleError, SignatureError};
+    use mr_bundle::signature::{detached_signature_path, write_detached_signatures};
+    use mr_bundle::BundleSignature;
+
+    holochain_trace::test_run().ok();
+
+    let signer_keystore = holochain_keystore::spawn_test_keystore().await.unwrap();
+    let signer = SweetAgents::one(signer_keystore.clone()).await;
+    let mut config = SweetConductorConfig::standard();
+    config.trusted_app_signers = Some(vec![signer.clone().into()]);
+    let conductor = SweetConductor::from_config(config).await;
+    let agent = SweetAgents::one(conductor.keystore()).await;
+
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Foo]).await;
+    let bundle = app_bundle_from_dnas([&dna]).await.into_inner();
+    let copy = |bundle: &mr_bundle::Bundle<AppManifest>| {
+        mr_bundle::Bundle::<AppManifest>::decode(&bundle.encode().unwrap()).unwrap()
+    };
+    let sign = |bundle: &mr_bundle::Bundle<AppManifest>| {
+        let keystore = signer_keystore.clone();
+        let signer = signer.clone();
+        let bytes = bundle.canonical_bytes().unwrap();
+        async move {
+            let signature = keystore.sign(signer.clone(), bytes.into()).await.unwrap();
+            BundleSignature::new(signer.get_raw_32(), signature.0.to_vec())
+        }
+    };
+    let payload = |source, installed_app_id: &str| InstallAppPayload {
+        source,
+        agent_key: agent.clone(),
+        installed_app_id: Some(installed_app_id.into()),
+        membrane_proofs: HashMap::new(),
+        network_seed: Some(installed_app_id.into()),
+        #[cfg(feature = "chc")]
+        ignore_genesis_failure: false,
+    };
+
+    // An unsigned bundle is rejected.
+    let err = conductor
+        .clone()
+        .install_app_bundle(payload(
+            AppBundleSource::Bundle(copy(&bundle).into()),
+            "unsigned",
+        ))
+        .await
+        .unwrap_err();
+    assert_matches!(
+        err,
+        ConductorError::AppBundleError(AppBundleError::MrBundleError(
+            MrBundleError::SignatureError(SignatureError::Untrusted)
+        ))
+    );
+
+    // A bundle signed by an untrusted key is rejected.
+    let other_keystore = holochain_keystore::spawn_test_keystore().await.unwrap();
+    let other = SweetAgents::one(other_keystore.clone()).await;
+    let other_signature = other_keystore
+        .sign(other.clone(), bundle.canonical_bytes().unwrap().into())
+        .await
+        .unwrap();
+    let mut untrusted_bundle = copy(&bundle);
+    untrusted_bundle
+        .add_signature(BundleSignature::new(
+            other.get_raw_32(),
+            other_signature.0.to_vec(),
+        ))
+        .unwrap();
+    assert!(conductor
+        .clone()
+        .install_app_bundle(payload(
+            AppBundleSource::Bundle(untrusted_bundle.into()),
+            "untrusted",
+        ))
+        .await
+        .is_err());
+
+    // A bundle with an embedded signature by a trusted key is installed.
+    let mut signed_bundle = copy(&bundle);
+    signed_bundle.add_signature(sign(&bundle).await).unwrap();
+    conductor
+        .clone()
+        .install_app_bundle(payload(
+            AppBundleSource::Bundle(signed_bundle.into()),
+            "embedded",
+        ))
+        .await
+        .unwrap();
+
+    // A bundle file with a detached signature by a trusted key is installed.
+    let dir = tempdir().unwrap();
+    let bundle_path = dir.path().join("app.happ");
+    bundle.write_to_file(&bundle_path).await.unwrap();
+    write_detached_signatures(
+        &detached_signature_path(&bundle_path),
+        &[sign(&bundle).await],
+    )
+    .await
+    .unwrap();
+    conductor
+        .clone()
+        .install_app_bundle(payload(AppBundleSource::Path(bundle_path), "detached"))
+        .await
+        .unwrap();
+
+    // DNAs can only be registered from a signed bundle too.
+    let admin_api = RealAdminInterfaceApi::new(conductor.raw_handle());
+    let register = |bundle: mr_bundle::Bundle<ValidatedDnaManifest>| {
+        AdminRequest::RegisterDna(Box::new(RegisterDnaPayload {
+            modifiers: DnaModifiersOpt::none(),
+            source: DnaSource::Bundle(Box::new(bundle.into())),
+        }))
+    };
+    let (other_dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Foo]).await;
+    let dna_bundle: mr_bundle::Bundle<ValidatedDnaManifest> = mr_bundle::Bundle::decode(
+        &DnaBundle::from_dna_file(other_dna)
+            .unwrap()
+            .encode()
+            .unwrap(),
+    )
+    .unwrap();
+    let err = admin_api
+        .handle_admin_request_inner(register(
+            mr_bundle::Bundle::decode(&dna_bundle.encode().unwrap()).unwrap(),
+        ))
+        .await
+        .unwrap_err();
+    assert_matches!(
+        err,
+        ConductorApiError::MrBundleError(MrBundleError::SignatureError(SignatureError::Untrusted))
+    );
+    let signature = signer_keystore
+        .sign(signer.clone(), dna_bundle.canonical_bytes().unwrap().into())
+        .await
+        .unwrap();
+    let mut signed_dna_bundle = dna_bundle;
+    signed_dna_bundle
+        .add_signature(BundleSignature::new(
+            signer.get_raw_32(),
+            signature.0.to_vec(),
+        ))
+        .unwrap();
+    assert_matches!(
+        admin_api
+            .handle_admin_request_inner(register(signed_dna_bundle))
+            .await,
+        Ok(AdminResponse::DnaRegistered(_))
+    );
+}
@@ -1,9 +1,13 @@
 use holo_hash::ActionHash;
-use holochain_types::prelude::{InstalledAppId, NetworkInfoRequestPayload};
+use holochain_types::prelude::{ArcPolicy, InstalledAppId, NetworkInfoRequestPayload};
 use holochain_wasm_test_utils::TestWasm;
 use holochain_zome_types::prelude::Timestamp;
 
-use crate::sweettest::{consistency_10s, SweetConductorBatch, SweetDnaFile, SweetZome};
+use crate::sweettest::{
+    consistency_10s, SweetConductor, SweetConductorBatch, SweetConductorConfig, SweetDnaFile,
+    SweetZome,
+};
+use crate::test_utils::WaitFor;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn network_info() {
@@ -34,6 +38,7 @@ async fn network_info() {
     assert_eq!(network_info[0].total_network_peers, 3);
     assert_eq!(network_info[0].completed_rounds_since_last_time_queried, 0);
     assert!(network_info[0].bytes_since_last_time_queried > 0);
+    assert_eq!(network_info[0].arc_policy, None);
 
     // query since previous query should return 0 received bytes
     let last_time_queried = Timestamp::now();
@@ -73,3 +78,57 @@ async fn network_info() {
     let network_info = conductors[1].network_info(&payload).await.unwrap();
     assert!(network_info[0].bytes_since_last_time_queried > 0);
 }
+
+/// An arc policy set for an app role through the admin API is applied to the
+/// role's cell and reported back in the network info.
+#[tokio::test(flavor = "multi_thread")]
+async fn network_info_reports_arc_policy() {
+    holochain_trace::test_run().ok();
+
+    let config = SweetConductorConfig::standard().tune(|tuning| {
+        tuning.gossip_agent_info_update_interval_ms = 100;
+    });
+    let mut conductor = SweetConductor::from_config(config).await;
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Create]).await;
+    let app_id: InstalledAppId = "app".into();
+    let app = conductor.setup_app(&app_id, [&dna]).await.unwrap();
+    let role_name = dna.dna_hash().to_string();
+
+    let payload = NetworkInfoRequestPayload {
+        agent_pub_key: app.agent().clone(),
+        dnas: vec![dna.dna_hash().clone()],
+        last_time_queried: None,
+    };
+
+    for (arc_policy, expected_arc_size) in [
+        (ArcPolicy::Empty, 0.0),
+        (ArcPolicy::Fixed { percent: 50 }, 0.5),
+        (ArcPolicy::Full, 1.0),
+    ] {
+        conductor
+            .set_arc_policy(app_id.clone(), role_name.clone(), Some(arc_policy.clone()))
+            .await
+            .unwrap();
+
+        let mut wait = WaitFor::new(std::time::Duration::from_secs(10), 100);
+        loop {
+            let network_info = conductor.network_info(&payload).await.unwrap();
+            assert_eq!(network_info[0].arc_policy, Some(arc_policy.clone()));
+            if (network_info[0].arc_size - expected_arc_size).abs() < 0.05 {
+                break;
+            }
+            assert!(
+                wait.wait_any().await,
+                "Arc size {} never reached {} with policy {:?}",
+                network_info[0].arc_size,
+                expected_arc_size,
+                arc_policy
+            );
+        }
+    }
+
+    assert!(conductor
+        .set_arc_policy(app_id, role_name, Some(ArcPolicy::Fixed { percent: 101 }))
+        .await
+        .is_err());
+}
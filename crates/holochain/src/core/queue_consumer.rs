@@ -119,7 +119,7 @@ pub async fn spawn_queue_consumer_tasks(
             dna_hash.clone(),
             dht_db.clone(),
             dht_query_cache.clone(),
-            conductor.task_manager(),
+            conductor.clone(),
             tx_receipt.clone(),
             network.clone(),
         )
@@ -1,28 +1,30 @@
 //! The workflow and queue consumer for DhtOp integration
 
 use super::*;
-use crate::conductor::manager::TaskManagerClient;
 use crate::core::workflow::integrate_dht_ops_workflow::integrate_dht_ops_workflow;
 use holochain_types::db_cache::DhtDbQueryCache;
 
 use tracing::*;
 
 /// Spawn the QueueConsumer for DhtOpIntegration workflow
-#[instrument(skip(env, trigger_receipt, tm, network, dht_query_cache))]
+#[instrument(skip(env, trigger_receipt, conductor, network, dht_query_cache))]
 pub fn spawn_integrate_dht_ops_consumer(
     dna_hash: Arc<DnaHash>,
     env: DbWrite<DbKindDht>,
     dht_query_cache: DhtDbQueryCache,
-    tm: TaskManagerClient,
+    conductor: ConductorHandle,
     trigger_receipt: TriggerSender,
     network: HolochainP2pDna,
 ) -> TriggerSender {
     let (tx, rx) = TriggerSender::new();
+    // Ops integrated before the consumer started are not passed on to the
+    // `on_integrated` callback.
+    let integrated_since = Arc::new(parking_lot::Mutex::new(Timestamp::now()));
 
     super::queue_consumer_dna_bound(
         "integrate_dht_ops_consumer",
-        dna_hash,
-        tm,
+        dna_hash.clone(),
+        conductor.task_manager(),
         (tx.clone(), rx),
         move || {
             integrate_dht_ops_workflow(
@@ -30,6 +32,20 @@ pub fn spawn_integrate_dht_ops_consumer(
                 dht_query_cache.clone(),
                 trigger_receipt.clone(),
                 network.clone(),
+                {
+                    let conductor = conductor.clone();
+                    let dna_hash = dna_hash.clone();
+                    let integrated_since = integrated_since.clone();
+                    move |integrated_until| {
+                        let integrated_after =
+                            std::mem::replace(&mut *integrated_since.lock(), integrated_until);
+                        conductor.dispatch_on_integrated(
+                            (*dna_hash).clone(),
+                            integrated_after,
+                            integrated_until,
+                        );
+                    }
+                },
             )
         },
     );
@@ -52,6 +52,7 @@ pub mod guest_callback;
 /// - Ensure the convenience wrapper in the HDI/HDK references the latest version
 ///   of the host_fn
 pub mod host_fn;
+pub mod metering;
 pub mod real_ribosome;
 
 use crate::conductor::api::CellConductorHandle;
@@ -65,6 +66,7 @@ use crate::core::ribosome::guest_callback::init::InitInvocation;
 use crate::core::ribosome::guest_callback::init::InitResult;
 use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentInvocation;
 use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentResult;
+use crate::core::ribosome::guest_callback::on_integrated::OnIntegratedInvocation;
 use crate::core::ribosome::guest_callback::post_commit::PostCommitInvocation;
 use crate::core::ribosome::guest_callback::validate::ValidateInvocation;
 use crate::core::ribosome::guest_callback::validate::ValidateResult;
@@ -74,6 +76,7 @@ use error::RibosomeResult;
 use guest_callback::entry_defs::EntryDefsHostAccess;
 use guest_callback::init::InitHostAccess;
 use guest_callback::migrate_agent::MigrateAgentHostAccess;
+use guest_callback::on_integrated::OnIntegratedHostAccess;
 use guest_callback::post_commit::PostCommitHostAccess;
 use guest_callback::validate::ValidateHostAccess;
 use holo_hash::AgentPubKey;
@@ -84,6 +87,7 @@ use holochain_serialized_bytes::prelude::*;
 use holochain_state::host_fn_workspace::HostFnWorkspace;
 use holochain_state::host_fn_workspace::HostFnWorkspaceRead;
 use holochain_state::nonce::WitnessNonceResult;
+use holochain_state::source_chain::SourceChain;
 use holochain_types::prelude::*;
 use holochain_types::zome_types::GlobalZomeTypes;
 use holochain_zome_types::block::BlockTargetId;
@@ -146,6 +150,7 @@ pub enum HostContext {
     GenesisSelfCheckV2(GenesisSelfCheckHostAccessV2),
     Init(InitHostAccess),
     MigrateAgent(MigrateAgentHostAccess),
+    OnIntegrated(OnIntegratedHostAccess),
     PostCommit(PostCommitHostAccess), // MAYBE: add emit_signal access here?
     Validate(ValidateHostAccess),
     ZomeCall(ZomeCallHostAccess),
@@ -162,6 +167,7 @@ impl From<&HostContext> for HostFnAccess {
             HostContext::EntryDefs(access) => access.into(),
             HostContext::MigrateAgent(access) => access.into(),
             HostContext::PostCommit(access) => access.into(),
+            HostContext::OnIntegrated(access) => access.into(),
         }
     }
 }
@@ -173,7 +179,8 @@ impl HostContext {
             Self::ZomeCall(ZomeCallHostAccess { workspace, .. })
             | Self::Init(InitHostAccess { workspace, .. })
             | Self::MigrateAgent(MigrateAgentHostAccess { workspace, .. })
-            | Self::PostCommit(PostCommitHostAccess { workspace, .. }) => workspace.into(),
+            | Self::PostCommit(PostCommitHostAccess { workspace, .. })
+            | Self::OnIntegrated(OnIntegratedHostAccess { workspace, .. }) => workspace.into(),
             Self::Validate(ValidateHostAccess { workspace, .. }) => workspace,
             _ => panic!(
                 "Gave access to a host function that uses the workspace without providing a workspace"
@@ -194,12 +201,33 @@ impl HostContext {
         }
     }
 
+    /// Get the source chain that owns the zome's local key/value store,
+    /// panics if none was provided.
+    /// Unlike [`Self::workspace_write`] this is available to callbacks that
+    /// can't write to the source chain, as the store is kept outside of it.
+    pub fn local_store_chain(&self) -> &SourceChain {
+        match self {
+            Self::ZomeCall(ZomeCallHostAccess { workspace, .. })
+            | Self::Init(InitHostAccess { workspace, .. })
+            | Self::MigrateAgent(MigrateAgentHostAccess { workspace, .. })
+            | Self::PostCommit(PostCommitHostAccess { workspace, .. })
+            | Self::OnIntegrated(OnIntegratedHostAccess { workspace, .. }) => workspace
+                .source_chain()
+                .as_ref()
+                .expect("Must have a source chain to use the local store"),
+            _ => panic!(
+                "Gave access to a host function that uses the local store without providing a workspace"
+            ),
+        }
+    }
+
     /// Get the keystore, panics if none was provided
     pub fn keystore(&self) -> &MetaLairClient {
         match self {
             Self::ZomeCall(ZomeCallHostAccess { keystore, .. })
             | Self::Init(InitHostAccess { keystore, .. })
-            | Self::PostCommit(PostCommitHostAccess { keystore, .. }) => keystore,
+            | Self::PostCommit(PostCommitHostAccess { keystore, .. })
+            | Self::OnIntegrated(OnIntegratedHostAccess { keystore, .. }) => keystore,
             _ => panic!(
                 "Gave access to a host function that uses the keystore without providing a keystore"
             ),
@@ -212,6 +240,7 @@ impl HostContext {
             Self::ZomeCall(ZomeCallHostAccess { network, .. })
             | Self::Init(InitHostAccess { network, .. })
             | Self::PostCommit(PostCommitHostAccess { network, .. })
+            | Self::OnIntegrated(OnIntegratedHostAccess { network, .. })
             | Self::Validate(ValidateHostAccess { network, .. }) => network,
             _ => panic!(
                 "Gave access to a host function that uses the network without providing a network"
@@ -225,6 +254,7 @@ impl HostContext {
             Self::ZomeCall(ZomeCallHostAccess { signal_tx, .. })
             | Self::Init(InitHostAccess { signal_tx, .. })
             | Self::PostCommit(PostCommitHostAccess { signal_tx, .. })
+            | Self::OnIntegrated(OnIntegratedHostAccess { signal_tx, .. })
             => signal_tx,
             _ => panic!(
                 "Gave access to a host function that uses the signal broadcaster without providing one"
@@ -678,19 +708,12 @@ pub trait RibosomeT: Sized + std::fmt::Debug + Send + Sync {
     /// values without needing to make holochain a dependency.
     fn get_const_fn(&self, zome: &Zome, name: &str) -> Result<Option<i32>, RibosomeError>;
 
-    /// @todo list out all the available callbacks and maybe cache them somewhere
-    fn list_callbacks(&self) {
-        unimplemented!()
-        // pseudocode
-        // self.instance().exports().filter(|e| e.is_callback())
-    }
+    /// List the callbacks which a zome implements, sorted by name.
+    fn list_callbacks(&self, zome: &Zome) -> RibosomeResult<Vec<FunctionName>>;
 
-    /// @todo list out all the available zome functions and maybe cache them somewhere
-    fn list_zome_fns(&self) {
-        unimplemented!()
-        // pseudocode
-        // self.instance().exports().filter(|e| !e.is_callback())
-    }
+    /// List the functions of a zome which can be called, i.e. every function
+    /// it exports which is not a callback, sorted by name.
+    fn list_zome_fns(&self, zome: &Zome) -> RibosomeResult<Vec<FunctionName>>;
 
     fn run_genesis_self_check(
         &self,
@@ -722,6 +745,12 @@ pub trait RibosomeT: Sized + std::fmt::Debug + Send + Sync {
         invocation: PostCommitInvocation,
     ) -> RibosomeResult<()>;
 
+    fn run_on_integrated(
+        &self,
+        access: OnIntegratedHostAccess,
+        invocation: OnIntegratedInvocation,
+    ) -> RibosomeResult<()>;
+
     /// Helper function for running a validation callback. Calls
     /// private fn `do_callback!` under the hood.
     fn run_validate(
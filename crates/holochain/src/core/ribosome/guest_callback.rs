@@ -2,6 +2,7 @@ pub mod entry_defs;
 pub mod genesis_self_check;
 pub mod init;
 pub mod migrate_agent;
+pub mod on_integrated;
 pub mod post_commit;
 pub mod validate;
 use super::HostContext;
@@ -12,6 +13,34 @@ use crate::core::ribosome::RibosomeT;
 use fallible_iterator::FallibleIterator;
 use holochain_types::prelude::*;
 
+/// The callback the host calls on the recipients of a remote signal. Unlike the
+/// other callbacks it has no invocation type, so it is named here directly.
+pub const RECV_REMOTE_SIGNAL_FN_NAME: &str = "recv_remote_signal";
+
+/// The names of all the functions the host may call as callbacks.
+///
+/// These must match the `fn_components` of the callback invocations in this
+/// module, which `test_callback_fn_names_match_invocations` checks.
+pub const CALLBACK_FN_NAMES: &[&str] = &[
+    "entry_defs",
+    "genesis_self_check",
+    "genesis_self_check_1",
+    "genesis_self_check_2",
+    "init",
+    "migrate_agent",
+    "migrate_agent_close",
+    "migrate_agent_open",
+    "on_integrated",
+    "post_commit",
+    RECV_REMOTE_SIGNAL_FN_NAME,
+    "validate",
+];
+
+/// Whether the host may call the function with this name as a callback.
+pub fn is_callback(fn_name: &FunctionName) -> bool {
+    CALLBACK_FN_NAMES.contains(&fn_name.0.as_str())
+}
+
 pub struct CallIterator<R: RibosomeT, I: Invocation> {
     host_context: HostContext,
     ribosome: R,
@@ -69,6 +98,8 @@ impl<R: RibosomeT, I: Invocation + 'static> FallibleIterator for CallIterator<R,
 #[cfg(feature = "slow_tests")]
 mod tests {
     use super::CallIterator;
+    use super::CALLBACK_FN_NAMES;
+    use super::RECV_REMOTE_SIGNAL_FN_NAME;
     use crate::core::ribosome::FnComponents;
     use crate::core::ribosome::MockInvocation;
     use crate::core::ribosome::MockRibosomeT;
@@ -151,4 +182,37 @@ mod tests {
         let output: Vec<(_, ExternIO)> = call_iterator.collect().unwrap();
         assert_eq!(output.len(), zomes.len() * fn_components.0.len());
     }
+
+    #[test]
+    fn test_callback_fn_names_match_invocations() {
+        use crate::core::ribosome::guest_callback::genesis_self_check::v1;
+        use crate::core::ribosome::guest_callback::genesis_self_check::v2;
+        use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentInvocation;
+        use crate::core::ribosome::guest_callback::validate::ValidateInvocation;
+        use crate::core::ribosome::Invocation;
+        use crate::fixt::*;
+        use ::fixt::prelude::*;
+        use arbitrary::Arbitrary;
+        use std::collections::BTreeSet;
+
+        let mut u = arbitrary::Unstructured::new(&NOISE);
+        let components = vec![
+            fixt!(EntryDefsInvocation).fn_components(),
+            v1::slow_tests::invocation_fixture().fn_components(),
+            v2::slow_tests::invocation_fixture().fn_components(),
+            fixt!(InitInvocation).fn_components(),
+            MigrateAgentInvocation::new(fixt!(DnaDef), MigrateAgent::Open).fn_components(),
+            MigrateAgentInvocation::new(fixt!(DnaDef), MigrateAgent::Close).fn_components(),
+            fixt!(OnIntegratedInvocation).fn_components(),
+            fixt!(PostCommitInvocation).fn_components(),
+            ValidateInvocation::arbitrary(&mut u)
+                .unwrap()
+                .fn_components(),
+        ];
+        let mut expected: BTreeSet<String> = components.into_iter().flatten().collect();
+        expected.insert(RECV_REMOTE_SIGNAL_FN_NAME.to_string());
+
+        let actual: BTreeSet<String> = CALLBACK_FN_NAMES.iter().map(|s| s.to_string()).collect();
+        assert_eq!(expected, actual);
+    }
 }
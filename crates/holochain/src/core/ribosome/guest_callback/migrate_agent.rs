@@ -190,6 +190,7 @@ mod test {
                 bindings_deterministic: Allow,
                 keystore: Deny,
                 keystore_deterministic: Deny,
+                local_store: Deny,
             }
         );
     }
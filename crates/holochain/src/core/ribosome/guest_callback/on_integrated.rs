@@ -0,0 +1,204 @@
+use crate::conductor::interface::SignalBroadcaster;
+use crate::core::ribosome::FnComponents;
+use crate::core::ribosome::HostContext;
+use crate::core::ribosome::Invocation;
+use crate::core::ribosome::InvocationAuth;
+use crate::core::ribosome::ZomesToInvoke;
+use derive_more::Constructor;
+use holochain_keystore::MetaLairClient;
+use holochain_p2p::HolochainP2pDna;
+use holochain_serialized_bytes::prelude::*;
+use holochain_state::host_fn_workspace::HostFnWorkspace;
+use holochain_types::prelude::*;
+
+#[derive(Clone)]
+pub struct OnIntegratedInvocation {
+    zome: CoordinatorZome,
+    ops: Vec<IntegratedOp>,
+}
+
+impl OnIntegratedInvocation {
+    pub fn new(zome: CoordinatorZome, ops: Vec<IntegratedOp>) -> Self {
+        Self { zome, ops }
+    }
+}
+
+#[derive(Clone, Constructor)]
+pub struct OnIntegratedHostAccess {
+    pub workspace: HostFnWorkspace,
+    pub keystore: MetaLairClient,
+    pub network: HolochainP2pDna,
+    pub signal_tx: SignalBroadcaster,
+}
+
+impl std::fmt::Debug for OnIntegratedHostAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnIntegratedHostAccess").finish()
+    }
+}
+
+impl From<OnIntegratedHostAccess> for HostContext {
+    fn from(on_integrated_host_access: OnIntegratedHostAccess) -> Self {
+        Self::OnIntegrated(on_integrated_host_access)
+    }
+}
+
+impl From<&OnIntegratedHostAccess> for HostFnAccess {
+    fn from(_: &OnIntegratedHostAccess) -> Self {
+        let mut access = Self::all();
+        // The callback is run for data other agents authored, on every cell
+        // of the DNA, so it must not write to any agent's source chain.
+        access.write_workspace = Permission::Deny;
+        access
+    }
+}
+
+impl Invocation for OnIntegratedInvocation {
+    fn zomes(&self) -> ZomesToInvoke {
+        ZomesToInvoke::OneCoordinator(self.zome.to_owned())
+    }
+    fn fn_components(&self) -> FnComponents {
+        vec!["on_integrated".into()].into()
+    }
+    fn host_input(self) -> Result<ExternIO, SerializedBytesError> {
+        ExternIO::encode(self.ops)
+    }
+    fn auth(&self) -> InvocationAuth {
+        InvocationAuth::LocalCallback
+    }
+}
+
+impl TryFrom<OnIntegratedInvocation> for ExternIO {
+    type Error = SerializedBytesError;
+    fn try_from(on_integrated_invocation: OnIntegratedInvocation) -> Result<Self, Self::Error> {
+        ExternIO::encode(&on_integrated_invocation.ops)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::ribosome::Invocation;
+    use crate::core::ribosome::ZomesToInvoke;
+    use crate::fixt::OnIntegratedHostAccessFixturator;
+    use crate::fixt::OnIntegratedInvocationFixturator;
+    use holochain_types::prelude::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn on_integrated_invocation_access() {
+        let on_integrated_host_access =
+            OnIntegratedHostAccessFixturator::new(::fixt::Unpredictable)
+                .next()
+                .unwrap();
+        let mut expected = HostFnAccess::all();
+        expected.write_workspace = Permission::Deny;
+        assert_eq!(HostFnAccess::from(&on_integrated_host_access), expected);
+    }
+
+    #[test]
+    fn on_integrated_invocation_zomes() {
+        let on_integrated_invocation = OnIntegratedInvocationFixturator::new(::fixt::Unpredictable)
+            .next()
+            .unwrap();
+        let zome = on_integrated_invocation.zome.clone();
+        assert_eq!(
+            ZomesToInvoke::OneCoordinator(zome),
+            on_integrated_invocation.zomes(),
+        );
+    }
+
+    #[test]
+    fn on_integrated_invocation_fn_components() {
+        let on_integrated_invocation = OnIntegratedInvocationFixturator::new(::fixt::Unpredictable)
+            .next()
+            .unwrap();
+
+        let mut expected = vec!["on_integrated"];
+        for fn_component in on_integrated_invocation.fn_components() {
+            assert_eq!(fn_component, expected.pop().unwrap());
+        }
+    }
+
+    #[test]
+    fn on_integrated_invocation_host_input() {
+        let on_integrated_invocation = OnIntegratedInvocationFixturator::new(::fixt::Empty)
+            .next()
+            .unwrap();
+
+        let host_input = on_integrated_invocation.clone().host_input().unwrap();
+
+        assert_eq!(
+            host_input,
+            ExternIO::encode(Vec::<IntegratedOp>::new()).unwrap(),
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+mod slow_tests {
+    use crate::core::ribosome::RibosomeT;
+    use crate::fixt::curve::Zomes;
+    use crate::fixt::OnIntegratedHostAccessFixturator;
+    use crate::fixt::OnIntegratedInvocationFixturator;
+    use crate::fixt::RealRibosomeFixturator;
+    use crate::prelude::Signal;
+    use crate::sweettest::*;
+    use hdk::prelude::*;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_on_integrated_unimplemented() {
+        let host_access = OnIntegratedHostAccessFixturator::new(::fixt::Unpredictable)
+            .next()
+            .unwrap();
+        let ribosome = RealRibosomeFixturator::new(Zomes(vec![TestWasm::Foo]))
+            .next()
+            .unwrap();
+        let mut on_integrated_invocation = OnIntegratedInvocationFixturator::new(::fixt::Empty)
+            .next()
+            .unwrap();
+        on_integrated_invocation.zome = CoordinatorZome::from(TestWasm::Foo);
+
+        let result = ribosome
+            .run_on_integrated(host_access, on_integrated_invocation)
+            .unwrap();
+        assert_eq!(result, ());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "test_utils")]
+    async fn on_integrated_signals_ops_held_as_authority() {
+        holochain_trace::test_run().ok();
+        let mut conductors = SweetConductorBatch::from_standard_config(2).await;
+        let (dna_file, _, _) =
+            SweetDnaFile::unique_from_test_wasms(vec![TestWasm::OnIntegrated]).await;
+        let apps = conductors.setup_app("app", &[dna_file]).await.unwrap();
+        let ((alice,), (bob,)) = apps.into_tuples();
+        conductors.exchange_peer_info().await;
+
+        let mut bob_signals = conductors[1].signals();
+
+        let action_hash: ActionHash = conductors[0]
+            .call(&alice.zome(TestWasm::OnIntegrated), "create", ())
+            .await;
+        consistency_60s([&alice, &bob]).await;
+
+        // Bob holds all the data as a full arc authority, so bob's zome is
+        // told when alice's new record is integrated.
+        let seen = tokio::time::timeout(std::time::Duration::from_secs(30), async {
+            use tokio_stream::StreamExt;
+            while let Some(signal) = bob_signals.next().await {
+                let Signal::App { signal, .. } = signal else {
+                    continue;
+                };
+                let integrated: Vec<(ActionHash, ValidationStatus)> =
+                    signal.into_inner().decode().unwrap();
+                if integrated.contains(&(action_hash.clone(), ValidationStatus::Valid)) {
+                    return;
+                }
+            }
+        })
+        .await;
+        assert!(seen.is_ok());
+    }
+}
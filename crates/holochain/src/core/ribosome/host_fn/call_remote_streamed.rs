@@ -0,0 +1,143 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_nonce::fresh_nonce;
+use holochain_p2p::HolochainP2pDnaT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Same as a `call` to a network agent, but the payload and the response are
+/// sent in chunks so they can be much larger than a single network message.
+#[tracing::instrument(skip(ribosome, call_context, input))]
+pub fn call_remote_streamed(
+    ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: Call,
+) -> Result<ZomeCallResponse, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            write_network: Permission::Allow,
+            agent_info: Permission::Allow,
+            ..
+        } => {
+            let Call {
+                target,
+                zome_name,
+                fn_name,
+                cap_secret,
+                payload,
+            } = input;
+            let target_agent = match target {
+                CallTarget::NetworkAgent(target_agent) => target_agent,
+                CallTarget::ConductorCell(_) => {
+                    return Err(wasm_error!(WasmErrorInner::Host(
+                        "Streamed calls can only be made to network agents".to_string()
+                    ))
+                    .into())
+                }
+            };
+            let provenance = call_context
+                .host_context
+                .workspace()
+                .source_chain()
+                .as_ref()
+                .expect("Must have source chain to know provenance")
+                .agent_pubkey()
+                .clone();
+            let (nonce, expires_at) =
+                fresh_nonce(Timestamp::now()).map_err(|e| -> RuntimeError {
+                    wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                })?;
+            let zome_call_unsigned = ZomeCallUnsigned {
+                provenance,
+                cell_id: CellId::new(ribosome.dna_def().as_hash().clone(), target_agent.clone()),
+                zome_name,
+                fn_name,
+                cap_secret,
+                payload,
+                nonce,
+                expires_at,
+            };
+            tokio_helper::block_forever_on(async move {
+                let signature = zome_call_unsigned
+                    .provenance
+                    .sign_raw(
+                        call_context.host_context.keystore(),
+                        zome_call_unsigned
+                            .data_to_sign()
+                            .map_err(|e| -> RuntimeError { wasm_error!(e.to_string()).into() })?,
+                    )
+                    .await
+                    .map_err(|e| -> RuntimeError {
+                        wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                    })?;
+                match call_context
+                    .host_context()
+                    .network()
+                    .call_remote_streamed(
+                        zome_call_unsigned.provenance,
+                        signature,
+                        target_agent,
+                        zome_call_unsigned.zome_name,
+                        zome_call_unsigned.fn_name,
+                        zome_call_unsigned.cap_secret,
+                        zome_call_unsigned.payload,
+                        zome_call_unsigned.nonce,
+                        zome_call_unsigned.expires_at,
+                    )
+                    .await
+                {
+                    Ok(serialized_bytes) => ZomeCallResponse::try_from(serialized_bytes)
+                        .map_err(|e| -> RuntimeError { wasm_error!(e).into() }),
+                    Err(e) => Ok(ZomeCallResponse::NetworkError(e.to_string())),
+                }
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "call_remote_streamed".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+pub mod wasm_test {
+    use crate::core::ribosome::wasm_test::RibosomeTestFixture;
+    use holochain_p2p::actor::STREAM_CHUNK_BYTES;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// we can stream a payload larger than a chunk to a remote and back
+    async fn call_remote_streamed_test() {
+        holochain_trace::test_run().ok();
+        let RibosomeTestFixture {
+            conductor,
+            alice,
+            bob,
+            bob_pubkey,
+            ..
+        } = RibosomeTestFixture::new(TestWasm::WhoAmI).await;
+
+        let _: () = conductor.call(&bob, "set_access", ()).await;
+        let bytes: Vec<u8> = (0..2 * STREAM_CHUNK_BYTES + 17)
+            .map(|i| (i % 128) as u8)
+            .collect();
+        let echoed: Vec<u8> = conductor
+            .call(
+                &alice,
+                "echo_bytes_remote_streamed",
+                (bob_pubkey, bytes.clone()),
+            )
+            .await;
+        assert_eq!(echoed, bytes);
+    }
+}
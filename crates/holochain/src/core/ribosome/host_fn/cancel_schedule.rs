@@ -0,0 +1,47 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+pub fn cancel_schedule(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: CancelScheduleInput,
+) -> Result<(), RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            write_workspace: Permission::Allow,
+            ..
+        } => {
+            let CancelScheduleInput { fn_name, name } = input;
+            call_context
+                .host_context()
+                .workspace_write()
+                .source_chain()
+                .as_ref()
+                .expect("Must have source chain if write_workspace access is given")
+                .scratch()
+                .apply(|scratch| {
+                    scratch.cancel_scheduled_fn(ScheduledFn::named(
+                        call_context.zome.zome_name().clone(),
+                        fn_name,
+                        name,
+                    ));
+                })
+                .map_err(|e| wasm_error!(WasmErrorInner::Host(e.to_string())))?;
+            Ok(())
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "cancel_schedule".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
@@ -218,12 +218,14 @@ pub mod wasm_test {
                 let size_fact = SizedNetworkFact {
                     nodes: 7,
                     agents: 1..=3,
+                    label: None,
                 };
                 let partition_fact = StrictlyPartitionedNetworkFact {
                     partitions: 1,
                     efficiency: 1.0,
+                    label: None,
                 };
-                let density_fact = DenseNetworkFact { density: 0.8 };
+                let density_fact = DenseNetworkFact::new(0.8);
 
                 let mut facts = facts![size_fact, partition_fact, density_fact];
 
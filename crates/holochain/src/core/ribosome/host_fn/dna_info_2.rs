@@ -28,6 +28,7 @@ pub fn dna_info_2(
                 .iter()
                 .map(|(zome_name, _zome_def)| zome_name.to_owned())
                 .collect(),
+            lineage: ribosome.dna_def().lineage.clone(),
         }),
         _ => Err(wasm_error!(WasmErrorInner::Host(
             RibosomeError::HostFnPermissions(
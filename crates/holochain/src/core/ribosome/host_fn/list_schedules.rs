@@ -0,0 +1,53 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_state::schedule::list_scheduled_fns;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// List the pending schedules of the current zome for the author of the
+/// source chain. Schedules staged by the current call are not included
+/// until the call's writes are flushed.
+pub fn list_schedules(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    _input: (),
+) -> Result<Vec<ScheduledFnInfo>, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            read_workspace: Permission::Allow,
+            agent_info: Permission::Allow,
+            ..
+        } => {
+            let workspace = call_context.host_context().workspace();
+            let author = workspace
+                .author()
+                .expect("Must have source chain if agent_info access is given");
+            let (authored_db, _, _) = workspace.databases();
+            let zome_name = call_context.zome.zome_name().clone();
+            let schedules = tokio_helper::block_forever_on(async move {
+                authored_db
+                    .read_async(move |txn| list_scheduled_fns(&txn, &author))
+                    .await
+            })
+            .map_err(|e| -> RuntimeError {
+                wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+            })?;
+            Ok(schedules
+                .into_iter()
+                .filter(|schedule| schedule.zome_name == zome_name)
+                .collect())
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "list_schedules".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
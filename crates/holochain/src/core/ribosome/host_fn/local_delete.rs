@@ -0,0 +1,46 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_state::mutations;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Delete a key from the calling zome's local store.
+/// Returns whether the key was stored.
+/// Like [`super::local_put::local_put`] the write is made straight away.
+pub fn local_delete(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    key: Bytes,
+) -> Result<bool, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            local_store: Permission::Allow,
+            ..
+        } => {
+            let source_chain = call_context.host_context.local_store_chain();
+            let author = source_chain.agent_pubkey().clone();
+            let zome_name = call_context.zome.zome_name().clone();
+            let db = source_chain.author_db().clone();
+            tokio_helper::block_forever_on(async move {
+                db.write_async(move |txn| mutations::local_delete(txn, &author, &zome_name, &key))
+                    .await
+            })
+            .map_err(|e| -> RuntimeError {
+                wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "local_delete".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
@@ -0,0 +1,184 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_state::mutations;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Store a value under a key in the calling zome's local store.
+/// The write is made straight away rather than when the call's source chain
+/// writes are flushed, so it is kept even if the call fails.
+pub fn local_put(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: LocalPutInput,
+) -> Result<(), RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            local_store: Permission::Allow,
+            ..
+        } => {
+            let source_chain = call_context.host_context.local_store_chain();
+            let author = source_chain.agent_pubkey().clone();
+            let zome_name = call_context.zome.zome_name().clone();
+            let db = source_chain.author_db().clone();
+            tokio_helper::block_forever_on(async move {
+                db.write_async(move |txn| {
+                    mutations::local_put(txn, &author, &zome_name, &input.key, &input.value)
+                })
+                .await
+            })
+            .map_err(|e| -> RuntimeError {
+                wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "local_put".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+mod tests {
+    use crate::prelude::InlineZomeSet;
+    use crate::sweettest::*;
+    use hdk::prelude::*;
+
+    fn bytes(b: &str) -> Bytes {
+        Bytes::from(b.as_bytes().to_vec())
+    }
+
+    fn with_local_store_fns(zomes: InlineZomeSet, zome: &'static str) -> InlineZomeSet {
+        zomes
+            .function(zome, "get", move |api, key: Bytes| {
+                api.local_get(key).map_err(Into::into)
+            })
+            .function(zome, "put", move |api, input: LocalPutInput| {
+                api.local_put(input).map_err(Into::into)
+            })
+            .function(zome, "delete", move |api, key: Bytes| {
+                api.local_delete(key).map_err(Into::into)
+            })
+            .function(zome, "scan", move |api, prefix: Bytes| {
+                api.local_scan_prefix(prefix).map_err(Into::into)
+            })
+            .function(zome, "chain_len", move |api, ()| {
+                Ok(api.query(ChainQueryFilter::new())?.len())
+            })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn local_store_is_kept_outside_the_chain() {
+        holochain_trace::test_run().ok();
+
+        let zomes = InlineZomeSet::new_unique([("integrity", vec![], 0)], ["zome", "other"]);
+        let zomes = with_local_store_fns(with_local_store_fns(zomes, "zome"), "other");
+        let (dna_file, _, _) = SweetDnaFile::unique_from_inline_zomes(zomes).await;
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let (alice, bob) = SweetAgents::two(conductor.keystore()).await;
+        let apps = conductor
+            .setup_app_for_agents("app", &[alice, bob], &[dna_file])
+            .await
+            .unwrap();
+        let ((alice,), (bob,)) = apps.into_tuples();
+        let zome = alice.zome("zome");
+
+        let chain_len: usize = conductor.call(&zome, "chain_len", ()).await;
+
+        for (key, value) in [
+            ("a/1", "one"),
+            ("a/2", "two"),
+            ("b/1", "three"),
+            ("a/1", "uno"),
+        ] {
+            let _: () = conductor
+                .call(&zome, "put", LocalPutInput::new(bytes(key), bytes(value)))
+                .await;
+        }
+
+        let value: Option<Bytes> = conductor.call(&zome, "get", bytes("a/1")).await;
+        assert_eq!(value, Some(bytes("uno")));
+        let scanned: Vec<(Bytes, Bytes)> = conductor.call(&zome, "scan", bytes("a/")).await;
+        assert_eq!(
+            scanned,
+            vec![(bytes("a/1"), bytes("uno")), (bytes("a/2"), bytes("two"))]
+        );
+
+        // Other zomes and other agents on the same DNA have their own stores.
+        let value: Option<Bytes> = conductor
+            .call(&alice.zome("other"), "get", bytes("a/1"))
+            .await;
+        assert_eq!(value, None);
+        let value: Option<Bytes> = conductor.call(&bob.zome("zome"), "get", bytes("a/1")).await;
+        assert_eq!(value, None);
+
+        let deleted: bool = conductor.call(&zome, "delete", bytes("a/1")).await;
+        assert!(deleted);
+        let deleted: bool = conductor.call(&zome, "delete", bytes("a/1")).await;
+        assert!(!deleted);
+        let scanned: Vec<(Bytes, Bytes)> = conductor.call(&zome, "scan", bytes("")).await;
+        assert_eq!(
+            scanned,
+            vec![(bytes("a/2"), bytes("two")), (bytes("b/1"), bytes("three"))]
+        );
+
+        // Nothing was written to the source chain.
+        let after: usize = conductor.call(&zome, "chain_len", ()).await;
+        assert_eq!(after, chain_len);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn local_put_is_not_allowed_in_validation() {
+        holochain_trace::test_run().ok();
+
+        let zomes = InlineZomeSet::new_unique(
+            [("integrity", vec![EntryDef::default_from_id("unit")], 0)],
+            ["zome"],
+        )
+        .function("integrity", "validate", |api, op: Op| match op {
+            Op::StoreEntry(_) => {
+                match api.local_put(LocalPutInput::new(bytes("key"), bytes("value"))) {
+                    Ok(()) => Ok(ValidateCallbackResult::Valid),
+                    Err(e) => Ok(ValidateCallbackResult::Invalid(e.to_string())),
+                }
+            }
+            _ => Ok(ValidateCallbackResult::Valid),
+        })
+        .function("zome", "create", |api, ()| {
+            let entry = Entry::app(().try_into().unwrap()).unwrap();
+            let hash = api.create(CreateInput::new(
+                InlineZomeSet::get_entry_location(&api, EntryDefIndex(0)),
+                EntryVisibility::Public,
+                entry,
+                ChainTopOrdering::default(),
+            ))?;
+            Ok(hash)
+        });
+        let (dna_file, _, _) = SweetDnaFile::unique_from_inline_zomes(zomes).await;
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor.setup_app("app", [&dna_file]).await.unwrap();
+        let (cell,) = app.into_tuple();
+
+        let err = conductor
+            .call_fallible::<_, ActionHash>(&cell.zome("zome"), "create", ())
+            .await
+            .unwrap_err();
+        let expected = crate::core::ribosome::error::RibosomeError::HostFnPermissions(
+            "integrity".into(),
+            "validate".into(),
+            "local_put".into(),
+        )
+        .to_string();
+        assert!(err.to_string().contains(&expected), "{err}");
+    }
+}
@@ -0,0 +1,53 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_state::local_store;
+use holochain_state::query::StateQueryResult;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Get all the key/value pairs in the calling zome's local store whose key
+/// starts with the prefix, ordered by key.
+pub fn local_scan_prefix(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    prefix: Bytes,
+) -> Result<Vec<(Bytes, Bytes)>, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            local_store: Permission::Allow,
+            ..
+        } => {
+            let source_chain = call_context.host_context.local_store_chain();
+            let author = source_chain.agent_pubkey().clone();
+            let zome_name = call_context.zome.zome_name().clone();
+            let db = source_chain.author_db().clone();
+            tokio_helper::block_forever_on(async move {
+                db.read_async(move |txn| -> StateQueryResult<_> {
+                    Ok(
+                        local_store::local_scan_prefix(&txn, &author, &zome_name, &prefix)?
+                            .into_iter()
+                            .map(|(key, value)| (Bytes::from(key), Bytes::from(value)))
+                            .collect(),
+                    )
+                })
+                .await
+            })
+            .map_err(|e| -> RuntimeError {
+                wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "local_scan_prefix".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
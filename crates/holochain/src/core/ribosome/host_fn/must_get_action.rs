@@ -49,6 +49,7 @@ pub fn must_get_action<'a>(
                         | HostContext::GenesisSelfCheckV2(_)
                         | HostContext::MigrateAgent(_)
                         | HostContext::PostCommit(_)
+                        | HostContext::OnIntegrated(_)
                         | HostContext::ZomeCall(_) => Err(wasm_error!(WasmErrorInner::Host(
                             format!("Failed to get SignedActionHashed {}", action_hash)
                         ))
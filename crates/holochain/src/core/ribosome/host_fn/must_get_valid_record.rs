@@ -52,6 +52,7 @@ pub fn must_get_valid_record<'a>(
                         | HostContext::GenesisSelfCheckV2(_)
                         | HostContext::MigrateAgent(_)
                         | HostContext::PostCommit(_)
+                        | HostContext::OnIntegrated(_)
                         | HostContext::ZomeCall(_) => Err(wasm_error!(WasmErrorInner::Host(
                             format!("Failed to get Record {}", action_hash)
                         ))
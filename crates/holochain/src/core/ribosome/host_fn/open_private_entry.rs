@@ -0,0 +1,98 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use crate::core::sys_validate::check_entry_hash;
+use crate::core::sys_validate::verify_action_signature;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Open a record sealed by `share_private_entry` and check that it is the
+/// author's signed action and the entry it commits to.
+/// Whether the action was actually published is left to the caller,
+/// which can check it with `must_get_action`.
+pub fn open_private_entry(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: SealedPrivateEntry,
+) -> Result<Record, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            keystore_deterministic: Permission::Allow,
+            ..
+        } => {
+            let SealedPrivateEntry {
+                author,
+                action_hash,
+                sender,
+                recipient,
+                sealed_record,
+            } = input;
+            let host_error = |message: String| -> RuntimeError {
+                wasm_error!(WasmErrorInner::Host(message)).into()
+            };
+            tokio_helper::block_forever_on(async move {
+                let mut s_pk: [u8; 32] = [0; 32];
+                s_pk.copy_from_slice(sender.as_ref());
+                let mut r_pk: [u8; 32] = [0; 32];
+                r_pk.copy_from_slice(recipient.as_ref());
+                let mut nonce: [u8; 24] = [0; 24];
+                nonce.copy_from_slice(sealed_record.as_nonce_ref().as_ref());
+                let data = sealed_record.as_encrypted_data_ref().to_vec();
+
+                let opened = call_context
+                    .host_context
+                    .keystore()
+                    .crypto_box_xsalsa_open(s_pk.into(), r_pk.into(), nonce, data.into())
+                    .await
+                    .map_err(|keystore_error| host_error(keystore_error.to_string()))?;
+                let record: Record = holochain_serialized_bytes::decode(&opened)
+                    .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?;
+
+                // The hash carried in the record can't be trusted so compute it again.
+                let action = record.action();
+                if ActionHash::with_data_sync(action) != action_hash
+                    || record.action_address() != &action_hash
+                {
+                    return Err(host_error(format!(
+                        "Shared record does not have the action hash {}",
+                        action_hash
+                    )));
+                }
+                if action.author() != &author {
+                    return Err(host_error(format!(
+                        "Shared record was not authored by {}",
+                        author
+                    )));
+                }
+                verify_action_signature(record.signature(), action)
+                    .await
+                    .map_err(|e| host_error(e.to_string()))?;
+                match (action.entry_hash(), record.entry().as_option()) {
+                    (Some(entry_hash), Some(entry)) => check_entry_hash(entry_hash, entry)
+                        .map_err(|e| host_error(e.to_string()))?,
+                    _ => {
+                        return Err(host_error(format!(
+                            "Shared record for action {} has no entry",
+                            action_hash
+                        )))
+                    }
+                }
+                Ok(record)
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "open_private_entry".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+// The sealing and opening round trip is tested in share_private_entry.rs
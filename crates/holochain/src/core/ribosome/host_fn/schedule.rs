@@ -176,14 +176,19 @@ mod tests {
                 assert_eq!(
                     vec![(
                         persisted_scheduled_fn.clone(),
-                        Some(persisted_schedule.clone())
+                        Some(persisted_schedule.clone()),
+                        None
                     )],
                     live_scheduled_fns(txn, the_future, &alice_pubkey,).unwrap(),
                 );
                 assert_eq!(
                     vec![
-                        (persisted_scheduled_fn, Some(persisted_schedule)),
-                        (ephemeral_scheduled_fn, Some(ephemeral_future_schedule)),
+                        (persisted_scheduled_fn, Some(persisted_schedule), None),
+                        (
+                            ephemeral_scheduled_fn,
+                            Some(ephemeral_future_schedule),
+                            None
+                        ),
                     ],
                     live_scheduled_fns(txn, the_distant_future, &alice_pubkey,).unwrap(),
                 );
@@ -357,4 +362,113 @@ mod tests {
 
         Ok(())
     }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Reminder {
+        name: String,
+        text: String,
+    }
+
+    fn reminder_names(schedules: Vec<ScheduledFnInfo>) -> Vec<String> {
+        let mut names: Vec<_> = schedules
+            .into_iter()
+            .filter(|schedule| schedule.fn_name == "remind".into())
+            .map(|schedule| schedule.name)
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "test_utils")]
+    async fn schedule_with_payload_test_wasm() -> anyhow::Result<()> {
+        holochain_trace::test_run().ok();
+        let RibosomeTestFixture {
+            conductor,
+            alice,
+            alice_cell,
+            ..
+        } = RibosomeTestFixture::new(TestWasm::Schedule).await;
+
+        // We don't want the scheduler running and messing with our calculations.
+        conductor
+            .raw_handle()
+            .start_scheduler(std::time::Duration::from_millis(1000_000_000))
+            .await;
+
+        let _: () = conductor
+            .call(
+                &alice,
+                "schedule_reminders",
+                vec![
+                    ("a".to_string(), "Buy milk".to_string()),
+                    ("b".to_string(), "Call mum".to_string()),
+                    ("c".to_string(), "Water plants".to_string()),
+                ],
+            )
+            .await;
+
+        // The same function is scheduled once per name.
+        let schedules: Vec<ScheduledFnInfo> = conductor.call(&alice, "list_schedules", ()).await;
+        assert_eq!(vec!["a", "b", "c"], reminder_names(schedules));
+
+        // The zome can cancel its own schedules.
+        let _: () = conductor.call(&alice, "cancel_reminder", "b").await;
+        let schedules: Vec<ScheduledFnInfo> = conductor.call(&alice, "list_schedules", ()).await;
+        assert_eq!(vec!["a", "c"], reminder_names(schedules));
+
+        // So can an admin.
+        let remind_c = ScheduledFn::named(TestWasm::Schedule.into(), "remind".into(), "c".into());
+        assert!(
+            conductor
+                .raw_handle()
+                .cancel_schedule(alice_cell.cell_id(), remind_c.clone())
+                .await?
+        );
+        assert!(
+            !conductor
+                .raw_handle()
+                .cancel_schedule(alice_cell.cell_id(), remind_c)
+                .await?
+        );
+
+        let schedules = conductor
+            .raw_handle()
+            .list_schedules(alice_cell.cell_id())
+            .await?;
+        let remind_a = schedules
+            .iter()
+            .find(|schedule| schedule.fn_name == "remind".into())
+            .unwrap();
+        assert_eq!("a", remind_a.name);
+        assert_eq!(None, remind_a.schedule);
+        assert_eq!(Some(ExternIO::encode("Buy milk")?), remind_a.payload);
+        assert_eq!(vec!["a"], reminder_names(schedules));
+
+        // Only the remaining schedule runs, with its payload.
+        conductor
+            .raw_handle()
+            .dispatch_scheduled_fns(Timestamp::now())
+            .await;
+        loop {
+            let reminders: Vec<Reminder> = conductor.call(&alice, "query_reminders", ()).await;
+            if !reminders.is_empty() {
+                assert_eq!(
+                    vec![Reminder {
+                        name: "a".to_string(),
+                        text: "Buy milk".to_string(),
+                    }],
+                    reminders
+                );
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        }
+
+        // It returned no further schedule so it is no longer pending.
+        let schedules: Vec<ScheduledFnInfo> = conductor.call(&alice, "list_schedules", ()).await;
+        assert!(reminder_names(schedules).is_empty());
+
+        Ok(())
+    }
 }
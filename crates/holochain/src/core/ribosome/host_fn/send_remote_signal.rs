@@ -1,3 +1,4 @@
+use crate::core::ribosome::guest_callback::RECV_REMOTE_SIGNAL_FN_NAME;
 use crate::core::ribosome::CallContext;
 use crate::core::ribosome::HostFnAccess;
 use crate::core::ribosome::RibosomeError;
@@ -30,7 +31,7 @@ pub fn send_remote_signal(
             agent_info: Permission::Allow,
             ..
         } => {
-            const FN_NAME: &str = "recv_remote_signal";
+            const FN_NAME: &str = RECV_REMOTE_SIGNAL_FN_NAME;
             let from_agent = super::agent_info::agent_info(_ribosome, call_context.clone(), ())?
                 .agent_latest_pubkey;
             // Timeouts and errors are ignored,
@@ -0,0 +1,223 @@
+use crate::core::ribosome::guest_callback::RECV_REMOTE_SIGNAL_FN_NAME;
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use futures::future::join_all;
+use holochain_keystore::AgentPubKeyExt;
+use holochain_nonce::fresh_nonce;
+use holochain_p2p::HolochainP2pDnaT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Same as `send_remote_signal` but each signal is sent as a remote call to
+/// `recv_remote_signal` so that the recipient's conductor acknowledges it,
+/// and the delivery outcome for every agent is returned to the caller.
+#[tracing::instrument(skip(_ribosome, call_context, input))]
+pub fn send_remote_signal_acknowledged(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: AcknowledgedRemoteSignal,
+) -> Result<Vec<(AgentPubKey, RemoteSignalDelivery)>, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            write_network: Permission::Allow,
+            agent_info: Permission::Allow,
+            ..
+        } => {
+            const FN_NAME: &str = RECV_REMOTE_SIGNAL_FN_NAME;
+            let from_agent = super::agent_info::agent_info(_ribosome, call_context.clone(), ())?
+                .agent_latest_pubkey;
+            let network = call_context.host_context().network().clone();
+            let AcknowledgedRemoteSignal {
+                agents,
+                signal,
+                timeout,
+            } = input;
+            let zome_name = call_context.zome().zome_name().clone();
+            let fn_name: FunctionName = FN_NAME.into();
+
+            let (nonce, expires_at) =
+                fresh_nonce(Timestamp::now()).map_err(|e| -> RuntimeError {
+                    wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                })?;
+
+            tokio_helper::block_forever_on(async move {
+                join_all(agents.into_iter().map(|agent| {
+                    let zome_call_unsigned = ZomeCallUnsigned {
+                        provenance: from_agent.clone(),
+                        cell_id: CellId::new(network.dna_hash(), agent.clone()),
+                        zome_name: zome_name.clone(),
+                        fn_name: fn_name.clone(),
+                        cap_secret: None,
+                        payload: signal.clone(),
+                        nonce,
+                        expires_at,
+                    };
+                    let network = network.clone();
+                    let keystore = call_context.host_context.keystore().clone();
+                    async move {
+                        let signature =
+                            zome_call_unsigned
+                                .provenance
+                                .sign_raw(
+                                    &keystore,
+                                    zome_call_unsigned.data_to_sign().map_err(
+                                        |e| -> RuntimeError { wasm_error!(e.to_string()).into() },
+                                    )?,
+                                )
+                                .await
+                                .map_err(|e| -> RuntimeError {
+                                    wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                                })?;
+                        let delivery = match tokio::time::timeout(
+                            timeout,
+                            network.call_remote(
+                                zome_call_unsigned.provenance,
+                                signature,
+                                agent.clone(),
+                                zome_call_unsigned.zome_name,
+                                zome_call_unsigned.fn_name,
+                                zome_call_unsigned.cap_secret,
+                                zome_call_unsigned.payload,
+                                zome_call_unsigned.nonce,
+                                zome_call_unsigned.expires_at,
+                            ),
+                        )
+                        .await
+                        {
+                            Err(_) => RemoteSignalDelivery::Timeout,
+                            Ok(Err(e)) => {
+                                tracing::info!("Failed to send remote signal because of {:?}", e);
+                                RemoteSignalDelivery::Offline
+                            }
+                            Ok(Ok(response)) => match ZomeCallResponse::try_from(response)
+                                .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?
+                            {
+                                ZomeCallResponse::Ok(_) => RemoteSignalDelivery::Delivered,
+                                ZomeCallResponse::Unauthorized(
+                                    ZomeCallAuthorization::BlockedProvenance,
+                                    ..,
+                                ) => RemoteSignalDelivery::Blocked,
+                                ZomeCallResponse::Unauthorized(..) => {
+                                    RemoteSignalDelivery::Unauthorized
+                                }
+                                ZomeCallResponse::NetworkError(_)
+                                | ZomeCallResponse::CountersigningSession(_) => {
+                                    RemoteSignalDelivery::Offline
+                                }
+                            },
+                        };
+                        Ok((agent, delivery))
+                    }
+                }))
+                .await
+                .into_iter()
+                .collect()
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "send_remote_signal_acknowledged".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+mod tests {
+    use crate::sweettest::*;
+    use futures::future;
+    use hdk::prelude::*;
+
+    fn test_zome() -> InlineIntegrityZome {
+        InlineIntegrityZome::new_unique(vec![], 0)
+            .function("signal_others", move |api, agents: Vec<AgentPubKey>| {
+                api.send_remote_signal_acknowledged(AcknowledgedRemoteSignal {
+                    agents,
+                    signal: ExternIO::encode("Hey").unwrap(),
+                    timeout: std::time::Duration::from_secs(5),
+                })
+                .map_err(Into::into)
+            })
+            .function("recv_remote_signal", move |api, signal: ExternIO| {
+                api.emit_signal(AppSignal::new(signal)).map_err(Into::into)
+            })
+            .function("init", move |api, ()| {
+                let mut fns = BTreeSet::new();
+                fns.insert((api.zome_info(()).unwrap().name, "recv_remote_signal".into()));
+                api.create(CreateInput::new(
+                    EntryDefLocation::CapGrant,
+                    EntryVisibility::Private,
+                    Entry::CapGrant(CapGrantEntry {
+                        tag: "".into(),
+                        // empty access converts to unrestricted
+                        access: ().into(),
+                        functions: GrantedFunctions::Listed(fns),
+                    }),
+                    ChainTopOrdering::default(),
+                ))
+                .unwrap();
+
+                Ok(InitCallbackResult::Pass)
+            })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn acknowledged_remote_signal_reports_delivery_per_agent() {
+        holochain_trace::test_run().ok();
+
+        let mut conductors = SweetConductorBatch::from_standard_config(3).await;
+        let agents =
+            future::join_all(conductors.iter().map(|c| SweetAgents::one(c.keystore()))).await;
+        let (dna_file, _, _) = SweetDnaFile::unique_from_inline_zomes(("zome", test_zome())).await;
+        let apps = conductors
+            .setup_app_for_zipped_agents("app", &agents, &[dna_file.clone().into()])
+            .await
+            .unwrap();
+        conductors.exchange_peer_info().await;
+        let cells = apps.cells_flattened();
+
+        // Carol blocks alice, so she refuses alice's signals.
+        conductors[2]
+            .raw_handle()
+            .block(Block::new(
+                BlockTarget::Cell(cells[0].cell_id().clone(), CellBlockReason::BadCrypto),
+                InclusiveTimestampInterval::try_new(Timestamp::MIN, Timestamp::MAX).unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        // Nobody is running a cell for this agent.
+        let nobody = SweetAgents::one(conductors[0].keystore()).await;
+
+        let deliveries: Vec<(AgentPubKey, RemoteSignalDelivery)> = conductors[0]
+            .call(
+                &cells[0].zome("zome"),
+                "signal_others",
+                vec![agents[1].clone(), agents[2].clone(), nobody.clone()],
+            )
+            .await;
+
+        assert_eq!(
+            deliveries[0],
+            (agents[1].clone(), RemoteSignalDelivery::Delivered)
+        );
+        assert_eq!(
+            deliveries[1],
+            (agents[2].clone(), RemoteSignalDelivery::Blocked)
+        );
+        assert_eq!(deliveries[2].0, nobody);
+        assert!(matches!(
+            deliveries[2].1,
+            RemoteSignalDelivery::Offline | RemoteSignalDelivery::Timeout
+        ));
+    }
+}
@@ -0,0 +1,315 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use futures::future::join_all;
+use holochain_cascade::CascadeImpl;
+use holochain_keystore::AgentPubKeyExt;
+use holochain_nonce::fresh_nonce;
+use holochain_p2p::HolochainP2pDnaT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Seal a private record from this agent's chain for each recipient and
+/// deliver it as a remote call to their `recv_private_entry` function.
+#[tracing::instrument(skip(_ribosome, call_context, input))]
+pub fn share_private_entry(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: SharePrivateEntryInput,
+) -> Result<Vec<(AgentPubKey, ZomeCallResponse)>, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            read_workspace: Permission::Allow,
+            write_network: Permission::Allow,
+            agent_info: Permission::Allow,
+            keystore: Permission::Allow,
+            ..
+        } => {
+            const FN_NAME: &str = "recv_private_entry";
+            let author = super::agent_info::agent_info(_ribosome, call_context.clone(), ())?
+                .agent_latest_pubkey;
+            let network = call_context.host_context().network().clone();
+            let SharePrivateEntryInput {
+                action_hash,
+                sender,
+                recipients,
+            } = input;
+            let zome_name = call_context.zome().zome_name().clone();
+            let fn_name: FunctionName = FN_NAME.into();
+
+            let (nonce, expires_at) =
+                fresh_nonce(Timestamp::now()).map_err(|e| -> RuntimeError {
+                    wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                })?;
+
+            tokio_helper::block_forever_on(async move {
+                // Only the author's own workspace has access to the private entry.
+                let record = CascadeImpl::from_workspace_and_network(
+                    &call_context.host_context.workspace(),
+                    network.clone(),
+                )
+                .dht_get(action_hash.clone().into(), GetOptions::content())
+                .await
+                .map_err(|cascade_error| -> RuntimeError {
+                    wasm_error!(WasmErrorInner::Host(cascade_error.to_string())).into()
+                })?;
+                let record = match record {
+                    Some(record)
+                        if record.action().author() == &author
+                            && record.action().entry_type().map_or(false, |entry_type| {
+                                *entry_type.visibility() == EntryVisibility::Private
+                            })
+                            && record.entry().as_option().is_some() =>
+                    {
+                        record
+                    }
+                    _ => {
+                        return Err(wasm_error!(WasmErrorInner::Host(format!(
+                            "No private entry authored by {} was found for action {}",
+                            author, action_hash
+                        )))
+                        .into())
+                    }
+                };
+                let data: Arc<[u8]> = holochain_serialized_bytes::encode(&record)
+                    .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?
+                    .into();
+
+                let mut s_pk: [u8; 32] = [0; 32];
+                s_pk.copy_from_slice(sender.as_ref());
+
+                join_all(recipients.into_iter().map(|(agent, recipient)| {
+                    let network = network.clone();
+                    let keystore = call_context.host_context.keystore().clone();
+                    let author = author.clone();
+                    let action_hash = action_hash.clone();
+                    let zome_name = zome_name.clone();
+                    let fn_name = fn_name.clone();
+                    let data = data.clone();
+                    async move {
+                        let mut r_pk: [u8; 32] = [0; 32];
+                        r_pk.copy_from_slice(recipient.as_ref());
+                        let (box_nonce, cipher) = keystore
+                            .crypto_box_xsalsa(s_pk.into(), r_pk.into(), data)
+                            .await
+                            .map_err(|keystore_error| -> RuntimeError {
+                                wasm_error!(WasmErrorInner::Host(keystore_error.to_string())).into()
+                            })?;
+                        let sealed = SealedPrivateEntry {
+                            author: author.clone(),
+                            action_hash,
+                            sender,
+                            recipient,
+                            sealed_record: XSalsa20Poly1305EncryptedData::new(
+                                box_nonce.into(),
+                                cipher.to_vec(),
+                            ),
+                        };
+                        let zome_call_unsigned = ZomeCallUnsigned {
+                            provenance: author,
+                            cell_id: CellId::new(network.dna_hash(), agent.clone()),
+                            zome_name,
+                            fn_name,
+                            cap_secret: None,
+                            payload: ExternIO::encode(sealed)
+                                .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?,
+                            nonce,
+                            expires_at,
+                        };
+                        let signature =
+                            zome_call_unsigned
+                                .provenance
+                                .sign_raw(
+                                    &keystore,
+                                    zome_call_unsigned.data_to_sign().map_err(
+                                        |e| -> RuntimeError { wasm_error!(e.to_string()).into() },
+                                    )?,
+                                )
+                                .await
+                                .map_err(|e| -> RuntimeError {
+                                    wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                                })?;
+                        let response = match network
+                            .call_remote(
+                                zome_call_unsigned.provenance,
+                                signature,
+                                agent.clone(),
+                                zome_call_unsigned.zome_name,
+                                zome_call_unsigned.fn_name,
+                                zome_call_unsigned.cap_secret,
+                                zome_call_unsigned.payload,
+                                zome_call_unsigned.nonce,
+                                zome_call_unsigned.expires_at,
+                            )
+                            .await
+                        {
+                            Ok(serialized_bytes) => ZomeCallResponse::try_from(serialized_bytes)
+                                .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?,
+                            Err(e) => ZomeCallResponse::NetworkError(e.to_string()),
+                        };
+                        Ok((agent, response))
+                    }
+                }))
+                .await
+                .into_iter()
+                .collect()
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "share_private_entry".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+mod tests {
+    use crate::prelude::InlineZomeSet;
+    use crate::sweettest::*;
+    use futures::future;
+    use hdk::prelude::*;
+
+    fn secret(s: &str) -> Entry {
+        Entry::app(UnsafeBytes::from(s.as_bytes().to_vec()).into()).unwrap()
+    }
+
+    fn test_zome() -> InlineIntegrityZome {
+        let entry_def = EntryDef {
+            id: "secret".into(),
+            visibility: EntryVisibility::Private,
+            ..EntryDef::default_from_id("secret")
+        };
+        InlineIntegrityZome::new_unique(vec![entry_def], 0)
+            .function("create_x25519_keypair", move |api, ()| {
+                api.create_x25519_keypair(()).map_err(Into::into)
+            })
+            .function("create_secret", move |api, s: String| {
+                let hash = api.create(CreateInput::new(
+                    InlineZomeSet::get_entry_location(&api, EntryDefIndex(0)),
+                    EntryVisibility::Private,
+                    secret(&s),
+                    ChainTopOrdering::default(),
+                ))?;
+                Ok(hash)
+            })
+            .function("get", move |api, hash: ActionHash| {
+                let record = api
+                    .get(vec![GetInput::new(hash.into(), Default::default())])?
+                    .pop()
+                    .unwrap();
+                Ok(record)
+            })
+            .function("share", move |api, input: SharePrivateEntryInput| {
+                api.share_private_entry(input).map_err(Into::into)
+            })
+            .function(
+                "recv_private_entry",
+                move |api, sealed: SealedPrivateEntry| {
+                    let action_hash = sealed.action_hash.clone();
+                    let record = api.open_private_entry(sealed)?;
+                    // The shared record must match the action the author published.
+                    let published = api.must_get_action(MustGetActionInput::new(action_hash))?;
+                    assert_eq!(&published, record.signed_action());
+                    Ok(record)
+                },
+            )
+            .function("open", move |api, sealed: SealedPrivateEntry| {
+                api.open_private_entry(sealed).map_err(Into::into)
+            })
+            .function("init", move |api, ()| {
+                let mut fns = BTreeSet::new();
+                fns.insert((api.zome_info(()).unwrap().name, "recv_private_entry".into()));
+                api.create(CreateInput::new(
+                    EntryDefLocation::CapGrant,
+                    EntryVisibility::Private,
+                    Entry::CapGrant(CapGrantEntry {
+                        tag: "".into(),
+                        // empty access converts to unrestricted
+                        access: ().into(),
+                        functions: GrantedFunctions::Listed(fns),
+                    }),
+                    ChainTopOrdering::default(),
+                ))
+                .unwrap();
+
+                Ok(InitCallbackResult::Pass)
+            })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn share_private_entry_with_chosen_agents() {
+        holochain_trace::test_run().ok();
+
+        let mut conductors = SweetConductorBatch::from_standard_config(2).await;
+        let agents =
+            future::join_all(conductors.iter().map(|c| SweetAgents::one(c.keystore()))).await;
+        let (dna_file, _, _) = SweetDnaFile::unique_from_inline_zomes(("zome", test_zome())).await;
+        let apps = conductors
+            .setup_app_for_zipped_agents("app", &agents, &[dna_file.clone().into()])
+            .await
+            .unwrap();
+        conductors.exchange_peer_info().await;
+        let ((alice,), (bob,)) = apps.into_tuples();
+
+        let alice_x25519: X25519PubKey = conductors[0]
+            .call(&alice.zome("zome"), "create_x25519_keypair", ())
+            .await;
+        let bob_x25519: X25519PubKey = conductors[1]
+            .call(&bob.zome("zome"), "create_x25519_keypair", ())
+            .await;
+
+        let action_hash: ActionHash = conductors[0]
+            .call(&alice.zome("zome"), "create_secret", "shh".to_string())
+            .await;
+        consistency_10s([&alice, &bob]).await;
+
+        let mut responses: Vec<(AgentPubKey, ZomeCallResponse)> = conductors[0]
+            .call(
+                &alice.zome("zome"),
+                "share",
+                SharePrivateEntryInput {
+                    action_hash: action_hash.clone(),
+                    sender: alice_x25519.clone(),
+                    recipients: vec![(bob.agent_pubkey().clone(), bob_x25519.clone())],
+                },
+            )
+            .await;
+
+        let (agent, response) = responses.pop().unwrap();
+        assert_eq!(&agent, bob.agent_pubkey());
+        let record: Record = match response {
+            ZomeCallResponse::Ok(extern_io) => extern_io.decode().unwrap(),
+            other => panic!("unexpected response {:?}", other),
+        };
+        assert_eq!(record.action_address(), &action_hash);
+        assert_eq!(record.entry().as_option(), Some(&secret("shh")));
+
+        // Bob can't read the private entry from the DHT.
+        let bob_get: Option<Record> = conductors[1]
+            .call(&bob.zome("zome"), "get", action_hash.clone())
+            .await;
+        assert!(bob_get.map_or(true, |r| r.entry().as_option().is_none()));
+
+        // Anything that can't be opened by bob is refused.
+        let sealed = SealedPrivateEntry {
+            author: bob.agent_pubkey().clone(),
+            action_hash: action_hash.clone(),
+            sender: alice_x25519.clone(),
+            recipient: bob_x25519.clone(),
+            sealed_record: XSalsa20Poly1305EncryptedData::new([0; 24].into(), vec![]),
+        };
+        let result: Result<Record, _> = conductors[1]
+            .call_fallible(&bob.zome("zome"), "open", sealed)
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,60 @@
+//! Accounting of the wasm metering points used by calls into zomes.
+//!
+//! Every call into a wasm zome is given a budget of metering points, one
+//! point per operation, according to the ribosome's [`WasmMeteringLimits`].
+//! The points used by each call are recorded as a metric, and the points used
+//! by a zome function call can be read by running it inside [`metered`].
+
+use crate::conductor::metrics::create_wasm_metering_metric;
+use crate::conductor::metrics::WasmMeteringMetric;
+use once_cell::sync::Lazy;
+use std::cell::Cell;
+use std::future::Future;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+#[cfg(doc)]
+use holochain_types::prelude::WasmMeteringLimits;
+
+static WASM_METERING_METRIC: Lazy<WasmMeteringMetric> = Lazy::new(create_wasm_metering_metric);
+
+thread_local! {
+    /// Points used by wasm calls on this thread since the last [`take_points_used`].
+    /// Calls into the ribosome are synchronous so this can't be polluted by
+    /// other calls running concurrently.
+    static POINTS_USED: Cell<u64> = Cell::new(0);
+}
+
+tokio::task_local! {
+    static ZOME_CALL_METER: Arc<AtomicU64>;
+}
+
+/// Record the points used by a single call into a wasm zome.
+pub(crate) fn record_points_used(points: u64, attributes: &[opentelemetry_api::KeyValue]) {
+    POINTS_USED.with(|used| used.set(used.get().saturating_add(points)));
+    WASM_METERING_METRIC.record(points, attributes);
+}
+
+/// Take the points used by wasm calls on this thread, resetting the count.
+pub(crate) fn take_points_used() -> u64 {
+    POINTS_USED.with(|used| used.replace(0))
+}
+
+/// Add the points used by a zome function call to the meter of the
+/// enclosing [`metered`] future, if there is one.
+pub(crate) fn add_to_zome_call_meter(points: u64) {
+    let _ = ZOME_CALL_METER.try_with(|meter| meter.fetch_add(points, Ordering::Relaxed));
+}
+
+/// Run a future which makes zome calls, returning its output along with the
+/// number of metering points which were used by those zome functions.
+///
+/// Only the zome functions themselves are counted. Callbacks such as `init`
+/// and `validate`, and zome calls made from within a zome function to other
+/// cells, are metered separately.
+pub async fn metered<F: Future>(f: F) -> (F::Output, u64) {
+    let meter = Arc::new(AtomicU64::new(0));
+    let output = ZOME_CALL_METER.scope(meter.clone(), f).await;
+    (output, meter.load(Ordering::Relaxed))
+}
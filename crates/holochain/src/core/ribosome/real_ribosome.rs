@@ -1,5 +1,6 @@
 use super::guest_callback::entry_defs::EntryDefsHostAccess;
 use super::guest_callback::init::InitHostAccess;
+use super::guest_callback::is_callback;
 use super::guest_callback::migrate_agent::MigrateAgentHostAccess;
 use super::guest_callback::post_commit::PostCommitHostAccess;
 use super::guest_callback::validate::ValidateHostAccess;
@@ -21,6 +22,8 @@ use crate::core::ribosome::guest_callback::init::InitInvocation;
 use crate::core::ribosome::guest_callback::init::InitResult;
 use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentInvocation;
 use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentResult;
+use crate::core::ribosome::guest_callback::on_integrated::OnIntegratedHostAccess;
+use crate::core::ribosome::guest_callback::on_integrated::OnIntegratedInvocation;
 use crate::core::ribosome::guest_callback::post_commit::PostCommitInvocation;
 use crate::core::ribosome::guest_callback::validate::ValidateInvocation;
 use crate::core::ribosome::guest_callback::validate::ValidateResult;
@@ -30,6 +33,8 @@ use crate::core::ribosome::host_fn::agent_info::agent_info;
 use crate::core::ribosome::host_fn::block_agent::block_agent;
 use crate::core::ribosome::host_fn::call::call;
 use crate::core::ribosome::host_fn::call_info::call_info;
+use crate::core::ribosome::host_fn::call_remote_streamed::call_remote_streamed;
+use crate::core::ribosome::host_fn::cancel_schedule::cancel_schedule;
 use crate::core::ribosome::host_fn::capability_claims::capability_claims;
 use crate::core::ribosome::host_fn::capability_grants::capability_grants;
 use crate::core::ribosome::host_fn::capability_info::capability_info;
@@ -46,14 +51,23 @@ use crate::core::ribosome::host_fn::get_details::get_details;
 use crate::core::ribosome::host_fn::get_link_details::get_link_details;
 use crate::core::ribosome::host_fn::get_links::get_links;
 use crate::core::ribosome::host_fn::hash::hash;
+use crate::core::ribosome::host_fn::list_schedules::list_schedules;
+use crate::core::ribosome::host_fn::local_delete::local_delete;
+use crate::core::ribosome::host_fn::local_get::local_get;
+use crate::core::ribosome::host_fn::local_put::local_put;
+use crate::core::ribosome::host_fn::local_scan_prefix::local_scan_prefix;
 use crate::core::ribosome::host_fn::must_get_action::must_get_action;
 use crate::core::ribosome::host_fn::must_get_agent_activity::must_get_agent_activity;
 use crate::core::ribosome::host_fn::must_get_entry::must_get_entry;
 use crate::core::ribosome::host_fn::must_get_valid_record::must_get_valid_record;
+use crate::core::ribosome::host_fn::open_private_entry::open_private_entry;
 use crate::core::ribosome::host_fn::query::query;
 use crate::core::ribosome::host_fn::random_bytes::random_bytes;
 use crate::core::ribosome::host_fn::schedule::schedule;
+use crate::core::ribosome::host_fn::schedule_with_payload::schedule_with_payload;
 use crate::core::ribosome::host_fn::send_remote_signal::send_remote_signal;
+use crate::core::ribosome::host_fn::send_remote_signal_acknowledged::send_remote_signal_acknowledged;
+use crate::core::ribosome::host_fn::share_private_entry::share_private_entry;
 use crate::core::ribosome::host_fn::sign::sign;
 use crate::core::ribosome::host_fn::sign_ephemeral::sign_ephemeral;
 use crate::core::ribosome::host_fn::sleep::sleep;
@@ -91,6 +105,8 @@ use wasmer::Module;
 use wasmer::RuntimeError;
 use wasmer::Store;
 use wasmer::Type;
+use wasmer_middlewares::metering;
+use wasmer_middlewares::metering::MeteringPoints;
 // This is here because there were errors about different crate versions
 // without it.
 use kitsune_p2p_types::dependencies::lair_keystore_api::dependencies::parking_lot::RwLock;
@@ -132,6 +148,9 @@ pub struct RealRibosome {
     pub serialized_module_cache: Arc<RwLock<SerializedModuleCache>>,
 
     pub instance_cache: Arc<RwLock<InstanceCache>>,
+
+    /// The metering points each kind of call into the wasm may use.
+    pub metering_limits: WasmMeteringLimits,
 }
 
 struct HostFnBuilder {
@@ -267,6 +286,7 @@ impl RealRibosome {
                 maybe_fs_dir,
             })),
             instance_cache: Arc::new(RwLock::new(InstanceCache::default())),
+            metering_limits: WasmMeteringLimits::default(),
         };
 
         // Collect the number of entry and link types
@@ -366,6 +386,22 @@ impl RealRibosome {
                 maybe_fs_dir: None,
             })),
             instance_cache: Arc::new(RwLock::new(InstanceCache::default())),
+            metering_limits: WasmMeteringLimits::default(),
+        }
+    }
+
+    /// Set the metering points each kind of call into the wasm may use.
+    pub fn with_metering_limits(mut self, metering_limits: WasmMeteringLimits) -> Self {
+        self.metering_limits = metering_limits;
+        self
+    }
+
+    /// The metering points a call with this context may use.
+    fn metering_limit(&self, host_context: &HostContext) -> u64 {
+        match host_context {
+            HostContext::Validate(_) => self.metering_limits.validation,
+            HostContext::Init(_) => self.metering_limits.init,
+            _ => self.metering_limits.zome_call,
         }
     }
 
@@ -589,6 +625,7 @@ impl RealRibosome {
             },
             integrity_zomes: Default::default(),
             coordinator_zomes: Default::default(),
+            lineage: Default::default(),
         };
         let empty_dna_file = DnaFile::new(empty_dna_def, vec![]).await;
         let empty_ribosome = RealRibosome::new(empty_dna_file, None)?;
@@ -708,7 +745,19 @@ impl RealRibosome {
             )
             .with_host_function(&mut ns, "__hc__query_1", query)
             .with_host_function(&mut ns, "__hc__send_remote_signal_1", send_remote_signal)
+            .with_host_function(
+                &mut ns,
+                "__hc__send_remote_signal_acknowledged_1",
+                send_remote_signal_acknowledged,
+            )
+            .with_host_function(&mut ns, "__hc__share_private_entry_1", share_private_entry)
+            .with_host_function(&mut ns, "__hc__open_private_entry_1", open_private_entry)
             .with_host_function(&mut ns, "__hc__call_1", call)
+            .with_host_function(
+                &mut ns,
+                "__hc__call_remote_streamed_1",
+                call_remote_streamed,
+            )
             .with_host_function(&mut ns, "__hc__create_1", create)
             .with_host_function(&mut ns, "__hc__emit_signal_1", emit_signal)
             .with_host_function(&mut ns, "__hc__create_link_1", create_link)
@@ -716,6 +765,17 @@ impl RealRibosome {
             .with_host_function(&mut ns, "__hc__update_1", update)
             .with_host_function(&mut ns, "__hc__delete_1", delete)
             .with_host_function(&mut ns, "__hc__schedule_1", schedule)
+            .with_host_function(
+                &mut ns,
+                "__hc__schedule_with_payload_1",
+                schedule_with_payload,
+            )
+            .with_host_function(&mut ns, "__hc__list_schedules_1", list_schedules)
+            .with_host_function(&mut ns, "__hc__cancel_schedule_1", cancel_schedule)
+            .with_host_function(&mut ns, "__hc__local_get_1", local_get)
+            .with_host_function(&mut ns, "__hc__local_put_1", local_put)
+            .with_host_function(&mut ns, "__hc__local_delete_1", local_delete)
+            .with_host_function(&mut ns, "__hc__local_scan_prefix_1", local_scan_prefix)
             .with_host_function(&mut ns, "__hc__unblock_agent_1", unblock_agent);
 
         imports.register_namespace("env", ns);
@@ -742,23 +802,41 @@ impl RealRibosome {
             // there is a callback to_call and it is implemented in the wasm
             // it is important to fully instantiate this (e.g. don't try to use the module above)
             // because it builds guards against memory leaks and handles imports correctly
+            let metering_limit = self.metering_limit(&call_context.host_context);
             let (instance_with_store, context_key) = self.instance_with_store(call_context)?;
 
             let result: Result<ExternIO, RuntimeError>;
+            let points_used: u64;
             {
                 let instance = instance_with_store.instance.clone();
                 let mut store_lock = instance_with_store.store.lock();
                 let mut store_mut = store_lock.as_store_mut();
+                // Cached instances keep whatever points were left over from
+                // their last call, so the budget is reset for every call.
+                metering::set_remaining_points(&mut store_mut, &instance, metering_limit);
                 result = holochain_wasmer_host::guest::call(
                     &mut store_mut,
-                    instance,
+                    instance.clone(),
                     to_call.as_ref(),
                     // be aware of this clone!
                     // the whole invocation is cloned!
                     // @todo - is this a problem for large payloads like entries?
                     invocation.to_owned().host_input()?,
                 );
+                points_used = match metering::get_remaining_points(&mut store_mut, &instance) {
+                    MeteringPoints::Remaining(remaining) => {
+                        metering_limit.saturating_sub(remaining)
+                    }
+                    MeteringPoints::Exhausted => metering_limit,
+                };
             }
+            crate::core::ribosome::metering::record_points_used(
+                points_used,
+                &[
+                    opentelemetry_api::KeyValue::new("zome", zome.zome_name().to_string()),
+                    opentelemetry_api::KeyValue::new("fn", to_call.to_string()),
+                ],
+            );
 
             // a bit of typefu to avoid cloning the result.
             let (can_cache, result) = match result {
@@ -829,6 +907,26 @@ impl RealRibosome {
         }
     }
 
+    /// The functions a zome exports, without the internals of the HDK, sorted
+    /// by name.
+    fn exported_fns(&self, zome: &Zome) -> RibosomeResult<Vec<FunctionName>> {
+        match zome.zome_def() {
+            ZomeDef::Wasm(wasm_zome) => {
+                let module_with_store = if let Some(path) = wasm_zome.preserialized_path.as_ref() {
+                    self.precompiled_module(path)?
+                } else {
+                    self.runtime_compiled_module(zome.zome_name())?
+                };
+                Ok(self
+                    .get_extern_fns_for_wasm(module_with_store.module.clone())
+                    .into_iter()
+                    .filter(|name| !name.0.starts_with("__"))
+                    .collect())
+            }
+            ZomeDef::Inline { inline_zome, .. } => Ok(inline_zome.0.functions()),
+        }
+    }
+
     pub fn get_extern_fns_for_wasm(&self, module: Arc<Module>) -> Vec<FunctionName> {
         let mut extern_fns: Vec<FunctionName> = module
             .info()
@@ -1028,6 +1126,22 @@ impl RibosomeT for RealRibosome {
         }
     }
 
+    fn list_callbacks(&self, zome: &Zome) -> RibosomeResult<Vec<FunctionName>> {
+        Ok(self
+            .exported_fns(zome)?
+            .into_iter()
+            .filter(is_callback)
+            .collect())
+    }
+
+    fn list_zome_fns(&self, zome: &Zome) -> RibosomeResult<Vec<FunctionName>> {
+        Ok(self
+            .exported_fns(zome)?
+            .into_iter()
+            .filter(|fn_name| !is_callback(fn_name))
+            .collect())
+    }
+
     fn call_iterator<I: crate::core::ribosome::Invocation>(
         &self,
         host_context: HostContext,
@@ -1071,6 +1185,19 @@ impl RibosomeT for RealRibosome {
         }
     }
 
+    /// Like post commit, on integrated is dispatched from a spawned task so
+    /// errors can only be logged.
+    fn run_on_integrated(
+        &self,
+        host_access: OnIntegratedHostAccess,
+        invocation: OnIntegratedInvocation,
+    ) -> RibosomeResult<()> {
+        match self.call_iterator(host_access.into(), invocation).next() {
+            Ok(_) => Ok(()),
+            Err((_zome, ribosome_error)) => Err(ribosome_error),
+        }
+    }
+
     fn run_genesis_self_check(
         &self,
         host_access: GenesisSelfCheckHostAccess,
@@ -1149,9 +1276,11 @@ impl RibosomeT for RealRibosome {
 #[cfg(test)]
 #[cfg(feature = "slow_tests")]
 pub mod wasm_test {
+    use crate::conductor::api::error::ConductorApiResult;
     use crate::core::ribosome::wasm_test::RibosomeTestFixture;
     use crate::core::ribosome::ZomeCall;
     use crate::sweettest::SweetConductor;
+    use crate::sweettest::SweetConductorConfig;
     use crate::sweettest::SweetDnaFile;
     use ::fixt::prelude::*;
     use hdk::prelude::*;
@@ -1222,6 +1351,36 @@ pub mod wasm_test {
         }
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn list_zome_functions_and_callbacks() {
+        holochain_trace::test_run().ok();
+
+        let (dna_file, _, _) =
+            SweetDnaFile::unique_from_test_wasms(vec![TestWasm::HdkExtern, TestWasm::InitPass])
+                .await;
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor.setup_app("app", [&dna_file]).await.unwrap();
+        let (cell,) = app.into_tuple();
+
+        let zomes = conductor
+            .raw_handle()
+            .list_zome_functions(cell.cell_id())
+            .await
+            .unwrap();
+        let zome = |name: ZomeName| zomes.iter().find(|z| z.zome_name == name).unwrap();
+
+        let hdk_extern = zome(TestWasm::HdkExtern.into());
+        assert_eq!(
+            hdk_extern.functions,
+            vec![FunctionName::from("bar"), "foo".into(), "infallible".into()]
+        );
+        assert!(hdk_extern.callbacks.is_empty());
+
+        let init_pass = zome(TestWasm::InitPass.into());
+        assert!(init_pass.functions.is_empty());
+        assert_eq!(init_pass.callbacks, vec![FunctionName::from("init")]);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn wasm_tooling_test() {
         holochain_trace::test_run().ok();
@@ -1233,6 +1392,8 @@ pub mod wasm_test {
                 "__hc__block_agent_1",
                 "__hc__call_1",
                 "__hc__call_info_1",
+                "__hc__call_remote_streamed_1",
+                "__hc__cancel_schedule_1",
                 "__hc__capability_claims_1",
                 "__hc__capability_grants_1",
                 "__hc__capability_info_1",
@@ -1251,14 +1412,23 @@ pub mod wasm_test {
                 "__hc__get_link_details_1",
                 "__hc__get_links_1",
                 "__hc__hash_1",
+                "__hc__list_schedules_1",
+                "__hc__local_delete_1",
+                "__hc__local_get_1",
+                "__hc__local_put_1",
+                "__hc__local_scan_prefix_1",
                 "__hc__must_get_action_1",
                 "__hc__must_get_agent_activity_1",
                 "__hc__must_get_entry_1",
                 "__hc__must_get_valid_record_1",
+                "__hc__open_private_entry_1",
                 "__hc__query_1",
                 "__hc__random_bytes_1",
                 "__hc__schedule_1",
+                "__hc__schedule_with_payload_1",
                 "__hc__send_remote_signal_1",
+                "__hc__send_remote_signal_acknowledged_1",
+                "__hc__share_private_entry_1",
                 "__hc__sign_1",
                 "__hc__sign_ephemeral_1",
                 "__hc__sleep_1",
@@ -1310,4 +1480,60 @@ pub mod wasm_test {
         .await;
         assert!(create_result.unwrap().is_err());
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn zome_call_over_metering_budget_traps() {
+        holochain_trace::test_run().ok();
+        let (dna_file, _, _) =
+            SweetDnaFile::unique_from_test_wasms(vec![TestWasm::TheIncredibleHalt]).await;
+        let mut conductor = SweetConductor::from_config(
+            SweetConductorConfig::standard().tune_conductor(|params| {
+                params.zome_call_metering_limit = Some(10_000_000);
+            }),
+        )
+        .await;
+        let (cell,) = conductor
+            .setup_app("app", [&dna_file])
+            .await
+            .unwrap()
+            .into_tuple();
+        let zome = cell.zome(TestWasm::TheIncredibleHalt);
+
+        // With a small budget the infinite loop is trapped almost immediately,
+        // rather than after the default 100 billion points.
+        let result: Result<ConductorApiResult<()>, _> = tokio::time::timeout(
+            std::time::Duration::from_secs(30),
+            conductor.call_fallible(&zome, "smash", ()),
+        )
+        .await;
+        assert!(result.expect("the call was not trapped").is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn call_zome_metered_reports_points_used() {
+        holochain_trace::test_run().ok();
+        let RibosomeTestFixture {
+            conductor,
+            alice_cell,
+            ..
+        } = RibosomeTestFixture::new(TestWasm::Foo).await;
+
+        let call = crate::test_utils::new_zome_call(
+            &conductor.keystore(),
+            alice_cell.cell_id(),
+            "foo",
+            (),
+            TestWasm::Foo,
+        )
+        .await
+        .unwrap();
+        let (result, points) = conductor
+            .raw_handle()
+            .call_zome_metered(call)
+            .await
+            .unwrap();
+
+        assert!(matches!(result, Ok(ZomeCallResponse::Ok(_))));
+        assert!(points > 0);
+    }
 }
@@ -268,6 +268,20 @@ pub fn check_agent_validation_pkg_predecessor(
     }
 }
 
+/// Check that nothing is written to a chain after it has been closed
+/// by migrating the agent to another DNA.
+pub fn check_chain_not_closed(action: &Action, prev_action: &Action) -> SysValidationResult<()> {
+    if let Action::CloseChain(_) = prev_action {
+        Err(PrevActionErrorKind::InvalidSuccessor(
+            "No action may follow a CloseChain".to_string(),
+            Box::new((prev_action.clone(), action.clone())),
+        ))
+        .map_err(|e| ValidationOutcome::PrevActionError((e, action.clone()).into()).into())
+    } else {
+        Ok(())
+    }
+}
+
 /// Check that the author didn't change between actions
 pub fn check_prev_author(action: &Action, prev_action: &Action) -> SysValidationResult<()> {
     // Agent updates will be valid when DPKI support lands
@@ -487,6 +487,27 @@ async fn check_previous_seq() {
     );
 }
 
+/// Nothing can be written to a chain after it has been closed
+#[test]
+fn check_chain_not_closed_test() {
+    let mut g = random_generator();
+
+    let action: Action = CreateLink::arbitrary(&mut g).unwrap().into();
+    let prev_action: Action = Create::arbitrary(&mut g).unwrap().into();
+    let close_chain: Action = CloseChain::arbitrary(&mut g).unwrap().into();
+
+    check_chain_not_closed(&action, &prev_action).unwrap();
+    assert_matches!(
+        check_chain_not_closed(&action, &close_chain),
+        Err(SysValidationError::ValidationOutcome(
+            ValidationOutcome::PrevActionError(PrevActionError {
+                source: PrevActionErrorKind::InvalidSuccessor(_, _),
+                ..
+            })
+        ))
+    );
+}
+
 /// Entry type in the action matches the entry variant
 #[test]
 fn check_entry_type_test() {
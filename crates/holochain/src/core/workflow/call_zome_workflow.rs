@@ -12,6 +12,7 @@ use crate::conductor::ConductorHandle;
 use crate::core::queue_consumer::TriggerSender;
 use crate::core::ribosome::error::RibosomeResult;
 use crate::core::ribosome::guest_callback::post_commit::send_post_commit;
+use crate::core::ribosome::metering;
 use crate::core::ribosome::RibosomeT;
 use crate::core::ribosome::ZomeCallHostAccess;
 use crate::core::ribosome::ZomeCallInvocation;
@@ -210,11 +211,14 @@ where
 {
     match invocation.is_authorized(&host_access).await? {
         ZomeCallAuthorization::Authorized => {
-            tokio::task::spawn_blocking(|| {
+            let (ribosome, r, points_used) = tokio::task::spawn_blocking(|| {
+                metering::take_points_used();
                 let r = ribosome.call_zome_function(host_access, invocation);
-                Ok((ribosome, r))
+                (ribosome, r, metering::take_points_used())
             })
-            .await?
+            .await?;
+            metering::add_to_zome_call_meter(points_used);
+            Ok((ribosome, r))
         }
         not_authorized_reason => Ok((
             ribosome,
@@ -14,13 +14,22 @@ mod query_tests;
 #[cfg(feature = "test_utils")]
 mod tests;
 
-#[instrument(skip(vault, trigger_receipt, network, dht_query_cache))]
-pub async fn integrate_dht_ops_workflow(
+/// Integrate all ops that are ready to be integrated, then pass the time they
+/// were integrated at to `on_integrated` if any were.
+/// Once this workflow's write has committed, every op integrated at or before
+/// that time is visible, whichever workflow integrated it, so ops integrated
+/// elsewhere during passes that integrated nothing are passed on next time.
+#[instrument(skip(vault, trigger_receipt, network, dht_query_cache, on_integrated))]
+pub async fn integrate_dht_ops_workflow<F>(
     vault: DbWrite<DbKindDht>,
     dht_query_cache: DhtDbQueryCache,
     trigger_receipt: TriggerSender,
     network: HolochainP2pDna,
-) -> WorkflowResult<WorkComplete> {
+    on_integrated: F,
+) -> WorkflowResult<WorkComplete>
+where
+    F: FnOnce(holochain_zome_types::prelude::Timestamp),
+{
     let start = std::time::Instant::now();
     let time = holochain_zome_types::prelude::Timestamp::now();
     // Get any activity from the cache that is ready to be integrated.
@@ -93,6 +102,7 @@ pub async fn integrate_dht_ops_workflow(
     let ops_ps = changed as f64 / start.elapsed().as_micros() as f64 * 1_000_000.0;
     tracing::debug!(?changed, %ops_ps);
     if changed > 0 {
+        on_integrated(time);
         trigger_receipt.trigger(&"integrate_dht_ops_workflow");
         network.new_integrated_data().await?;
         Ok(WorkComplete::Incomplete(None))
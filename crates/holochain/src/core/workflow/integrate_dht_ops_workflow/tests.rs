@@ -357,9 +357,15 @@ async fn call_workflow<'env>(env: DbWrite<DbKindDht>) {
     let (qt, _rx) = TriggerSender::new();
     let test_network = test_network(None, None).await;
     let holochain_p2p_cell = test_network.dna_network();
-    integrate_dht_ops_workflow(env.clone(), env.clone().into(), qt, holochain_p2p_cell)
-        .await
-        .unwrap();
+    integrate_dht_ops_workflow(
+        env.clone(),
+        env.clone().into(),
+        qt,
+        holochain_p2p_cell,
+        |_| (),
+    )
+    .await
+    .unwrap();
 }
 
 // Need to clear the data from the previous test
@@ -537,3 +543,36 @@ async fn test_ops_state() {
         Db::check(expect, env.clone(), format!("{}: {}", name, here!(""))).await;
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn on_integrated_only_called_when_ops_integrated() {
+    holochain_trace::test_run().ok();
+    let test_db = test_dht_db();
+    let env = test_db.to_db();
+    let test_network = test_network(None, None).await;
+
+    let call_workflow = |env: DbWrite<DbKindDht>| {
+        let holochain_p2p_cell = test_network.dna_network();
+        async move {
+            let (qt, _rx) = TriggerSender::new();
+            let mut called = false;
+            integrate_dht_ops_workflow(
+                env.clone(),
+                env.clone().into(),
+                qt,
+                holochain_p2p_cell,
+                |_| called = true,
+            )
+            .await
+            .unwrap();
+            called
+        }
+    };
+
+    // Nothing is ready to integrate.
+    assert!(!call_workflow(env.clone()).await);
+
+    let (pre_state, _, _) = register_agent_activity(TestData::new().await);
+    Db::set(pre_state, env.clone()).await;
+    assert!(call_workflow(env.clone()).await);
+}
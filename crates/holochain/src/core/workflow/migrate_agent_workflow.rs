@@ -0,0 +1,120 @@
+//! Migrates an agent between two versions of a DNA.
+//!
+//! The agent's chain in the old DNA is ended with a [`CloseChain`] action
+//! pointing at the new DNA, and their chain in the new DNA records where it
+//! came from with an [`OpenChain`] action. The `migrate_agent` callbacks of
+//! each DNA are run first, and may veto the migration.
+
+use super::error::WorkflowResult;
+use crate::conductor::ConductorHandle;
+use crate::core::queue_consumer::TriggerSender;
+use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentHostAccess;
+use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentInvocation;
+use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentResult;
+use crate::core::ribosome::RibosomeT;
+use derive_more::Constructor;
+use holochain_p2p::HolochainP2pDna;
+use holochain_state::host_fn_workspace::HostFnWorkspace;
+use holochain_state::host_fn_workspace::SourceChainWorkspace;
+use holochain_types::prelude::*;
+use holochain_zome_types::action::builder;
+
+#[derive(Constructor)]
+pub struct MigrateAgentWorkflowArgs<Ribosome>
+where
+    Ribosome: RibosomeT + 'static,
+{
+    pub ribosome: Ribosome,
+    pub conductor_handle: ConductorHandle,
+    /// Whether the agent is migrating away from or into this DNA.
+    pub migrate_agent: MigrateAgent,
+    /// The DNA on the other side of the migration.
+    pub other_dna_hash: DnaHash,
+    pub trigger_publish_dht_ops: TriggerSender,
+    pub trigger_integrate_dht_ops: TriggerSender,
+}
+
+pub async fn migrate_agent_workflow<Ribosome>(
+    workspace: SourceChainWorkspace,
+    network: HolochainP2pDna,
+    args: MigrateAgentWorkflowArgs<Ribosome>,
+) -> WorkflowResult<MigrateAgentResult>
+where
+    Ribosome: RibosomeT + Clone + 'static,
+{
+    let trigger_publish_dht_ops = args.trigger_publish_dht_ops.clone();
+    let trigger_integrate_dht_ops = args.trigger_integrate_dht_ops.clone();
+    let result = migrate_agent_workflow_inner(workspace.clone(), network.clone(), args).await?;
+
+    // --- END OF WORKFLOW, BEGIN FINISHER BOILERPLATE ---
+
+    // only commit if the result was successful
+    if result == MigrateAgentResult::Pass {
+        HostFnWorkspace::from(workspace).flush(&network).await?;
+        trigger_publish_dht_ops.trigger(&"migrate_agent_workflow");
+        trigger_integrate_dht_ops.trigger(&"migrate_agent_workflow");
+    }
+    Ok(result)
+}
+
+async fn migrate_agent_workflow_inner<Ribosome>(
+    workspace: SourceChainWorkspace,
+    network: HolochainP2pDna,
+    args: MigrateAgentWorkflowArgs<Ribosome>,
+) -> WorkflowResult<MigrateAgentResult>
+where
+    Ribosome: RibosomeT + Clone + 'static,
+{
+    let MigrateAgentWorkflowArgs {
+        ribosome,
+        conductor_handle,
+        migrate_agent,
+        other_dna_hash,
+        ..
+    } = args;
+
+    // Call the migrate_agent callbacks
+    let result = {
+        let host_access = MigrateAgentHostAccess::new(workspace.clone().into());
+        let invocation = MigrateAgentInvocation::new(
+            ribosome.dna_def().as_content().clone(),
+            migrate_agent.clone(),
+        );
+        ribosome.run_migrate_agent(host_access, invocation)?
+    };
+    if result != MigrateAgentResult::Pass {
+        return Ok(result);
+    }
+
+    // Mark the end or the beginning of the chain.
+    match migrate_agent {
+        MigrateAgent::Close => {
+            workspace
+                .source_chain()
+                .put(
+                    builder::CloseChain {
+                        new_dna_hash: other_dna_hash,
+                    },
+                    None,
+                    ChainTopOrdering::Strict,
+                )
+                .await?;
+        }
+        MigrateAgent::Open => {
+            workspace
+                .source_chain()
+                .put(
+                    builder::OpenChain {
+                        prev_dna_hash: other_dna_hash,
+                    },
+                    None,
+                    ChainTopOrdering::Strict,
+                )
+                .await?;
+        }
+    }
+
+    super::inline_validation(workspace, network, conductor_handle, ribosome).await?;
+
+    Ok(result)
+}
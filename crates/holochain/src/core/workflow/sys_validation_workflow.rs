@@ -720,10 +720,11 @@ fn register_agent_activity(
     if let Some(prev_action_hash) = prev_action_hash {
         // Just make sure we have the dependency and if not then don't mark this action as valid yet
         let mut validation_dependencies = validation_dependencies.lock();
-        validation_dependencies
+        let prev_action = validation_dependencies
             .get(prev_action_hash)
             .and_then(|s| s.as_action())
             .ok_or_else(|| ValidationOutcome::DepMissingFromDht(prev_action_hash.clone().into()))?;
+        check_chain_not_closed(action, prev_action)?;
     }
 
     Ok(())
@@ -748,6 +749,7 @@ fn store_record(
         check_prev_timestamp(action, prev_action)?;
         check_prev_seq(action, prev_action)?;
         check_agent_validation_pkg_predecessor(action, prev_action)?;
+        check_chain_not_closed(action, prev_action)?;
     }
 
     Ok(())
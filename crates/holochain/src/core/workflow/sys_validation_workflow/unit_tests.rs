@@ -146,6 +146,7 @@ async fn validate_op_with_dependency_not_held() {
     network
         .expect_get()
         .return_once(move |_, _| Ok(vec![response]));
+    network.expect_prioritize_fetch().returning(|_| Ok(()));
 
     test_case.with_network_behaviour(network).run().await;
 
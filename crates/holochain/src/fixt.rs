@@ -9,6 +9,8 @@ use crate::core::ribosome::guest_callback::init::InitHostAccess;
 use crate::core::ribosome::guest_callback::init::InitInvocation;
 use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentHostAccess;
 use crate::core::ribosome::guest_callback::migrate_agent::MigrateAgentInvocation;
+use crate::core::ribosome::guest_callback::on_integrated::OnIntegratedHostAccess;
+use crate::core::ribosome::guest_callback::on_integrated::OnIntegratedInvocation;
 use crate::core::ribosome::guest_callback::post_commit::PostCommitHostAccess;
 use crate::core::ribosome::guest_callback::post_commit::PostCommitInvocation;
 use crate::core::ribosome::guest_callback::validate::ValidateHostAccess;
@@ -398,6 +400,26 @@ fixturator!(
     constructor fn new(HostFnWorkspace, MetaLairClient, HolochainP2pDna, SignalBroadcaster);
 );
 
+/// There is no fixturator for `Op`, so these are always empty.
+pub type IntegratedOpVec = Vec<IntegratedOp>;
+
+fixturator!(
+    IntegratedOpVec;
+    curve Empty Vec::new();
+    curve Unpredictable Vec::new();
+    curve Predictable Vec::new();
+);
+
+fixturator!(
+    OnIntegratedInvocation;
+    constructor fn new(CoordinatorZome, IntegratedOpVec);
+);
+
+fixturator!(
+    OnIntegratedHostAccess;
+    constructor fn new(HostFnWorkspace, MetaLairClient, HolochainP2pDna, SignalBroadcaster);
+);
+
 fixturator!(
     ZomesToInvoke;
     constructor fn one(Zome);
@@ -417,6 +439,7 @@ fixturator!(
         EntryDefs(EntryDefsHostAccess)
         MigrateAgent(MigrateAgentHostAccess)
         PostCommit(PostCommitHostAccess)
+        OnIntegrated(OnIntegratedHostAccess)
     ];
 );
 
@@ -20,6 +20,7 @@ pub async fn app_bundle_from_dnas(dnas: impl IntoIterator<Item = &DnaFile>) -> A
                     clone_limit: 255,
                 },
                 provisioning: Some(CellProvisioning::Create { deferred: false }),
+                arc_policy: None,
             };
             let bundle = DnaBundle::from_dna_file(dna.clone()).unwrap();
             (manifest, (path, bundle))
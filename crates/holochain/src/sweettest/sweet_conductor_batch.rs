@@ -1,16 +1,31 @@
 use super::{SweetAgents, SweetAppBatch, SweetConductor, SweetConductorConfig};
 use crate::conductor::api::error::ConductorApiResult;
+use crate::sweettest::sweet_topos::network::NetworkTopology;
 use crate::sweettest::{SweetCell, SweetLocalRendezvous};
 use ::fixt::prelude::StdRng;
 use futures::future;
 use hdk::prelude::*;
 use holochain_types::prelude::*;
+use kitsune_p2p_types::config::TransportConfig;
+use kitsune_p2p_types::tx2::tx2_fault_adapter::{tx2_fault_adapter, FaultInjector};
+use kitsune_p2p_types::tx2::{tx2_mem_adapter, MemConfig};
 use std::path::PathBuf;
 use std::time::Duration;
 
 /// A collection of SweetConductors, with methods for operating on the entire collection
-#[derive(derive_more::Into, derive_more::IntoIterator, derive_more::Deref)]
-pub struct SweetConductorBatch(Vec<SweetConductor>);
+#[derive(derive_more::IntoIterator, derive_more::Deref)]
+pub struct SweetConductorBatch(
+    #[deref]
+    #[into_iterator(owned)]
+    Vec<SweetConductor>,
+    Option<FaultInjector>,
+);
+
+impl From<SweetConductorBatch> for Vec<SweetConductor> {
+    fn from(batch: SweetConductorBatch) -> Self {
+        batch.0
+    }
+}
 
 impl SweetConductorBatch {
     /// Constructor with validation
@@ -29,7 +44,7 @@ impl SweetConductorBatch {
             paths.len(),
             "Some conductors in a SweetConductorBatch share the same data path (or don't have a path)!"
         );
-        Self(conductors)
+        Self(conductors, None)
     }
 
     /// Map the given ConductorConfigs into SweetConductors, each with its own new TestEnvironments
@@ -91,6 +106,45 @@ impl SweetConductorBatch {
         Self::from_configs(std::iter::repeat_with(SweetConductorConfig::standard).take(num)).await
     }
 
+    /// Create the given number of new SweetConductors, networked in memory
+    /// through a [`FaultInjector`] so that tests can inject latency, loss,
+    /// bandwidth limits and partitions at runtime via [`SweetConductorBatch::faults`].
+    /// Each conductor's node index is its index in this batch.
+    ///
+    /// There is no bootstrap service on this network, so use
+    /// [`SweetConductorBatch::exchange_peer_info`] to introduce the conductors.
+    pub async fn from_config_with_faults<C>(num: usize, config: C) -> SweetConductorBatch
+    where
+        C: Into<SweetConductorConfig>,
+    {
+        let faults = FaultInjector::new();
+        let config: SweetConductorConfig = config.into();
+        let mut configs = Vec::with_capacity(num);
+        for node in 0..num {
+            let mem = tx2_mem_adapter(MemConfig::default())
+                .await
+                .expect("Couldn't create mem adapter");
+            let mut config = config.clone();
+            config.network.bootstrap_service = None;
+            config.network.transport_pool = vec![TransportConfig::Mock {
+                mock_network: tx2_fault_adapter(mem, faults.clone(), node).into(),
+            }];
+            configs.push(config);
+        }
+        let mut batch = Self::from_configs(configs).await;
+        batch.1 = Some(faults);
+        batch
+    }
+
+    /// Get the [`FaultInjector`] controlling the network between these conductors.
+    ///
+    /// Panics if this batch was not created by [`SweetConductorBatch::from_config_with_faults`].
+    pub fn faults(&self) -> &FaultInjector {
+        self.1
+            .as_ref()
+            .expect("This SweetConductorBatch was not created with fault injection")
+    }
+
     /// Iterate over the SweetConductors
     pub fn iter(&self) -> impl Iterator<Item = &SweetConductor> {
         self.0.iter()
@@ -232,6 +286,84 @@ impl SweetConductorBatch {
         crate::conductor::p2p_agent_store::reveal_peer_info(observer_envs, seen_envs).await;
     }
 
+    /// Wire the conductors in this batch together according to a network
+    /// topology, where the conductor at each index plays the part of the node
+    /// at the same index.
+    ///
+    /// Conductors can only connect to each other across the topology's edges,
+    /// in either direction, which is enforced by the batch's [`FaultInjector`],
+    /// so the batch must have been created by
+    /// [`SweetConductorBatch::from_config_with_faults`]. For every edge, the
+    /// source conductor is also told about all agents on the target conductor,
+    /// and every conductor forgets the agents of the conductors it has no edge to.
+    ///
+    /// This can be called again after mutating the topology to rewire the
+    /// batch. It replaces any link restriction set on the [`FaultInjector`],
+    /// but leaves partitions in place.
+    pub async fn apply_topology(&self, topology: &NetworkTopology) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            topology.node_count() == self.0.len(),
+            "The topology has {} nodes but the batch has {} conductors",
+            topology.node_count(),
+            self.0.len()
+        );
+        let faults = self.1.as_ref().ok_or_else(|| {
+            anyhow::anyhow!(
+                "A topology can only be applied to a batch created with fault injection"
+            )
+        })?;
+
+        let envs = self
+            .0
+            .iter()
+            .map(|c| c.spaces.get_from_spaces(|s| s.p2p_agents_db.clone()))
+            .collect::<Vec<_>>();
+        let agents = self
+            .0
+            .iter()
+            .map(|c| {
+                c.live_cell_ids()
+                    .into_iter()
+                    .map(|cell_id| cell_id.agent_pubkey().clone())
+                    .collect::<HashSet<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut links = Vec::new();
+        for source in 0..self.0.len() {
+            for target in 0..self.0.len() {
+                if source != target && topology.contains_edge(source.into(), target.into()) {
+                    links.push((source, target));
+                }
+            }
+        }
+        faults.restrict_links(links.iter().copied());
+
+        for source in 0..self.0.len() {
+            for target in 0..self.0.len() {
+                if source == target {
+                    continue;
+                }
+                if links.contains(&(source, target)) {
+                    crate::conductor::p2p_agent_store::reveal_agents_peer_info(
+                        envs[source].clone(),
+                        envs[target].clone(),
+                        agents[target].iter(),
+                    )
+                    .await;
+                } else {
+                    crate::conductor::p2p_agent_store::forget_peer_info(
+                        envs[source].clone(),
+                        agents[target].iter(),
+                    )
+                    .await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Force trigger all dht ops that haven't received
     /// enough validation receipts yet.
     pub async fn force_all_publish_dht_ops(&self) {
@@ -30,6 +30,7 @@ impl From<KitsuneP2pConfig> for SweetConductorConfig {
             }]),
             tuning_params: Some(ConductorTuningParams {
                 sys_validation_retry_delay: Some(std::time::Duration::from_secs(1)),
+                ..ConductorTuningParams::new()
             }),
             ..Default::default()
         }
@@ -2,8 +2,10 @@ use super::network::NetworkTopologyConductor;
 use super::node::NetworkTopologyNode;
 use crate::sweettest::SweetConductor;
 use arbitrary::Arbitrary;
+use holochain_types::prelude::AgentPubKey;
 use holochain_zome_types::prelude::CellId;
 use rand::Rng;
+use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
 
 /// A network edge in a network topology. Represents a network connection.
@@ -52,35 +54,44 @@ impl NetworkTopologyEdge {
         &self.target_conductor
     }
 
-    /// Apply the edge state to its associated conductor.
+    /// Apply the edge state to its associated conductor, revealing the agents
+    /// of the edge's cells on the target conductor to the source conductor.
     pub async fn apply(&mut self) -> anyhow::Result<()> {
-        async fn envs_from_conductor(
-            conductor: &tokio::sync::RwLock<SweetConductor>,
-        ) -> Vec<holochain_types::db::DbWrite<holochain_types::db::DbKindP2pAgents>> {
-            let mut envs = Vec::new();
-
-            for env in conductor
-                .read()
-                .await
-                .raw_handle()
-                .spaces
-                .get_from_spaces(|s| s.p2p_agents_db.clone())
-            {
-                envs.push(env.clone());
-            }
-            envs
-        }
-
         let source_envs = envs_from_conductor(self.source_conductor().lock().await).await;
         let target_envs = envs_from_conductor(self.target_conductor().lock().await).await;
 
-        // @todo This reveals all peers to the source, but in reality we'd only
-        // want to reveal the list of agents specified by the edge.
-        crate::conductor::p2p_agent_store::reveal_peer_info(source_envs, target_envs).await;
+        crate::conductor::p2p_agent_store::reveal_agents_peer_info(
+            source_envs,
+            target_envs,
+            self.agents().iter(),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// Remove the edge state from its associated conductor, so that the source
+    /// conductor forgets the agents of the edge's cells. Use this after an
+    /// edge has been removed from the network during a test.
+    pub async fn unapply(&self) -> anyhow::Result<()> {
+        let source_envs = envs_from_conductor(self.source_conductor().lock().await).await;
+
+        crate::conductor::p2p_agent_store::forget_peer_info(source_envs, self.agents().iter())
+            .await;
 
         Ok(())
     }
 
+    /// The agents of the cells in this edge, without duplicates.
+    fn agents(&self) -> Vec<AgentPubKey> {
+        self.cells
+            .iter()
+            .map(|cell_id| cell_id.agent_pubkey().clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     /// Create a new edge with a full view on the given target node.
     pub fn new_full_view_on_node(
         source: &NetworkTopologyNode,
@@ -95,3 +106,20 @@ impl NetworkTopologyEdge {
         }
     }
 }
+
+async fn envs_from_conductor(
+    conductor: &tokio::sync::RwLock<SweetConductor>,
+) -> Vec<holochain_types::db::DbWrite<holochain_types::db::DbKindP2pAgents>> {
+    let mut envs = Vec::new();
+
+    for env in conductor
+        .read()
+        .await
+        .raw_handle()
+        .spaces
+        .get_from_spaces(|s| s.p2p_agents_db.clone())
+    {
+        envs.push(env.clone());
+    }
+    envs
+}
@@ -15,12 +15,17 @@ pub struct DenseNetworkFact {
     /// The density of the network. Number of edges divided by the maximum
     /// number of edges. Only makes sense for simple graphs.
     pub density: f64,
+    /// A label set with [`Fact::labeled`], replacing the default label.
+    pub label: Option<String>,
 }
 
 impl DenseNetworkFact {
     /// Create a new fact with the given density.
     pub fn new(density: f64) -> Self {
-        Self { density }
+        Self {
+            density,
+            label: None,
+        }
     }
 
     /// This is the maximum number of edges that a graph with the given number
@@ -51,6 +56,14 @@ impl<'a> Fact<'a, NetworkTopology> for DenseNetworkFact {
         let target_edge_count = self.target_edge_count(&graph);
         let mut rng = super::rng_from_generator(g);
 
+        if graph.edge_count() != target_edge_count {
+            g.fail(format!(
+                "Network has {} edges, expected {}.",
+                graph.edge_count(),
+                target_edge_count
+            ))?;
+        }
+
         // Add edges until we reach the desired density.
         while graph.edge_count() < target_edge_count {
             graph.add_random_simple_edge(&mut rng)?;
@@ -65,11 +78,16 @@ impl<'a> Fact<'a, NetworkTopology> for DenseNetworkFact {
     }
 
     fn label(&self) -> String {
-        format!("DenseNetworkFact {{ density: {} }}", self.density)
+        self.label
+            .clone()
+            .unwrap_or_else(|| format!("DenseNetworkFact {{ density: {} }}", self.density))
     }
 
-    fn labeled(self, _: impl ToString) -> Self {
-        todo!()
+    fn labeled(self, label: impl ToString) -> Self {
+        Self {
+            label: Some(label.to_string()),
+            ..self
+        }
     }
 }
 
@@ -87,10 +105,21 @@ pub mod test {
     #[test]
     fn test_dense_network_fact_new() {
         let a = DenseNetworkFact::new(0.5);
-        let b = DenseNetworkFact { density: 0.5 };
+        let b = DenseNetworkFact {
+            density: 0.5,
+            label: None,
+        };
         assert_eq!(a, b);
     }
 
+    /// Test that `labeled` replaces the default label of a fact.
+    #[test]
+    fn test_dense_network_fact_labeled() {
+        let fact = DenseNetworkFact::new(0.5);
+        assert_eq!(fact.label(), "DenseNetworkFact { density: 0.5 }");
+        assert_eq!(fact.labeled("half dense").label(), "half dense");
+    }
+
     #[test]
     fn test_sweet_topos_dense_network() {
         crate::big_stack_test!(
@@ -99,8 +128,9 @@ pub mod test {
                 let mut size_fact = SizedNetworkFact {
                     nodes: 12,
                     agents: 1..=2,
+                    label: None,
                 };
-                let mut density_fact = DenseNetworkFact { density: 0.3 };
+                let mut density_fact = DenseNetworkFact::new(0.3);
                 let mut graph = NetworkTopology::default();
                 graph = size_fact.mutate(&mut g, graph).unwrap();
                 tracing::info!(
@@ -115,6 +145,7 @@ pub mod test {
                 let mut partition_fact = StrictlyPartitionedNetworkFact {
                     partitions: 1,
                     efficiency: 1.0,
+                    label: None,
                 };
                 graph = partition_fact.mutate(&mut g, graph).unwrap();
                 tracing::info!(
@@ -3,6 +3,7 @@ use crate::sweettest::sweet_topos::node::NetworkTopologyNode;
 use contrafact::Fact;
 use contrafact::Generator;
 use contrafact::Mutation;
+use std::collections::HashSet;
 
 /// Fact: The origin node can see every agent on the target node.
 #[derive(Clone, Debug)]
@@ -11,23 +12,38 @@ pub struct FullAgentViewFact {
     source: NetworkTopologyNode,
     /// The target node. This node is fully visible to the origin node.
     target: NetworkTopologyNode,
+    /// A label set with [`Fact::labeled`], replacing the default label.
+    label: Option<String>,
 }
 
 impl<'a> Fact<'a, NetworkTopologyEdge> for FullAgentViewFact {
     fn mutate(
         &mut self,
-        _g: &mut Generator<'a>,
-        mut _edge: NetworkTopologyEdge,
+        g: &mut Generator<'a>,
+        edge: NetworkTopologyEdge,
     ) -> Mutation<NetworkTopologyEdge> {
+        if edge.source_conductor() == self.source.conductor()
+            && edge.target_conductor() == self.target.conductor()
+            && edge.cells().into_iter().collect::<HashSet<_>>()
+                == self.target.cells().into_iter().collect::<HashSet<_>>()
+        {
+            return Ok(edge);
+        }
+        g.fail("Edge does not have a full view of the target node.")?;
         let new_edge = NetworkTopologyEdge::new_full_view_on_node(&self.source, &self.target);
         Ok(new_edge)
     }
 
     fn label(&self) -> String {
-        todo!()
+        self.label
+            .clone()
+            .unwrap_or_else(|| "FullAgentViewFact".to_string())
     }
 
-    fn labeled(self, _: impl ToString) -> Self {
-        todo!()
+    fn labeled(self, label: impl ToString) -> Self {
+        Self {
+            label: Some(label.to_string()),
+            ..self
+        }
     }
 }
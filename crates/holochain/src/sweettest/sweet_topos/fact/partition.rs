@@ -21,6 +21,8 @@ pub struct StrictlyPartitionedNetworkFact {
     /// and 1. The higher the number, the more efficient the partitioning
     /// process.
     pub efficiency: f64,
+    /// A label set with [`Fact::labeled`], replacing the default label.
+    pub label: Option<String>,
 }
 
 impl<'a> Fact<'a, NetworkTopology> for StrictlyPartitionedNetworkFact {
@@ -32,6 +34,14 @@ impl<'a> Fact<'a, NetworkTopology> for StrictlyPartitionedNetworkFact {
         let mut rng = super::rng_from_generator(g);
         let efficiency_cutoff = (self.efficiency * u64::MAX as f64) as u64;
 
+        if graph.strict_partitions() != self.partitions {
+            g.fail(format!(
+                "Network has {} strict partitions, expected {}.",
+                graph.strict_partitions(),
+                self.partitions
+            ))?;
+        }
+
         // Remove edges until the graph is partitioned into the desired number of
         // partitions. The edges are removed randomly, so this is not the most
         // efficient way to do this, but it's simple and it works.
@@ -59,11 +69,19 @@ impl<'a> Fact<'a, NetworkTopology> for StrictlyPartitionedNetworkFact {
     }
 
     fn label(&self) -> String {
-        todo!()
+        self.label.clone().unwrap_or_else(|| {
+            format!(
+                "StrictlyPartitionedNetworkFact {{ partitions: {}, efficiency: {} }}",
+                self.partitions, self.efficiency
+            )
+        })
     }
 
-    fn labeled(self, _: impl ToString) -> Self {
-        todo!()
+    fn labeled(self, label: impl ToString) -> Self {
+        Self {
+            label: Some(label.to_string()),
+            ..self
+        }
     }
 }
 
@@ -87,10 +105,12 @@ pub mod test {
                 let size_fact = SizedNetworkFact {
                     nodes: 3,
                     agents: 3..=5,
+                    label: None,
                 };
                 let partition_fact = StrictlyPartitionedNetworkFact {
                     partitions: 1,
                     efficiency: 1.0,
+                    label: None,
                 };
                 let mut facts = facts![size_fact, partition_fact];
                 let mut graph = NetworkTopology::default();
@@ -110,10 +130,12 @@ pub mod test {
                 let mut size_fact = SizedNetworkFact {
                     nodes: 12,
                     agents: 1..=2,
+                    label: None,
                 };
                 let mut partition_fact = StrictlyPartitionedNetworkFact {
                     partitions: 3,
                     efficiency: 0.2,
+                    label: None,
                 };
                 // let facts = facts![size_fact, partition_fact];
                 let mut graph = NetworkTopology::default();
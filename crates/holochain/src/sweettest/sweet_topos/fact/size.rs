@@ -16,6 +16,8 @@ pub struct SizedNetworkFact {
     pub nodes: usize,
     /// The number of agents in each node.
     pub agents: RangeInclusive<usize>,
+    /// A label set with [`Fact::labeled`], replacing the default label.
+    pub label: Option<String>,
 }
 
 impl SizedNetworkFact {
@@ -28,6 +30,7 @@ impl SizedNetworkFact {
         Ok(Self {
             nodes: g.int_in_range(nodes, || "Couldn't build a fact in the range.")?,
             agents,
+            label: None,
         })
     }
 }
@@ -39,6 +42,12 @@ impl<'a> Fact<'a, NetworkTopology> for SizedNetworkFact {
         mut network_topology: NetworkTopology,
     ) -> Mutation<NetworkTopology> {
         let mut node_count = network_topology.node_count();
+        if node_count != self.nodes {
+            g.fail(format!(
+                "Network has {} nodes, expected {}.",
+                node_count, self.nodes
+            ))?;
+        }
         while node_count < self.nodes {
             let mut node = NetworkTopologyNode::new();
             node.ensure_dnas(network_topology.dnas().to_vec());
@@ -56,11 +65,19 @@ impl<'a> Fact<'a, NetworkTopology> for SizedNetworkFact {
     }
 
     fn label(&self) -> String {
-        todo!()
+        self.label.clone().unwrap_or_else(|| {
+            format!(
+                "SizedNetworkFact {{ nodes: {}, agents: {:?} }}",
+                self.nodes, self.agents
+            )
+        })
     }
 
-    fn labeled(self, _label: impl ToString) -> Self {
-        todo!()
+    fn labeled(self, label: impl ToString) -> Self {
+        Self {
+            label: Some(label.to_string()),
+            ..self
+        }
     }
 }
 
@@ -76,6 +93,7 @@ pub mod test {
         let fact = SizedNetworkFact {
             nodes: 0,
             agents: 1..=1,
+            label: None,
         };
         let graph = fact.build_fallible(&mut g).unwrap();
         assert_eq!(graph.node_count(), 0);
@@ -92,6 +110,7 @@ pub mod test {
                 let fact = SizedNetworkFact {
                     nodes: 1,
                     agents: 1..=1,
+                    label: None,
                 };
                 let graph = fact.build_fallible(&mut g).unwrap();
                 assert_eq!(graph.node_count(), 1);
@@ -111,6 +130,7 @@ pub mod test {
                 let fact = SizedNetworkFact {
                     nodes: 12,
                     agents: 1..=2,
+                    label: None,
                 };
                 let graph = fact.build_fallible(&mut g).unwrap();
                 assert_eq!(graph.node_count(), 12);
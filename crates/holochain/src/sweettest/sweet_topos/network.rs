@@ -439,6 +439,33 @@ impl NetworkTopology {
         }
     }
 
+    /// Add an edge from the origin node to the target node with a full view on
+    /// the target node. Returns true if the edge was added, false if it
+    /// already exists or would be a self edge.
+    pub fn add_full_view_edge(
+        &mut self,
+        origin: usize,
+        target: usize,
+    ) -> Result<bool, NetworkTopologyError> {
+        let edge = NetworkTopologyEdge::new_full_view_on_node(
+            self.node_or_err(origin)?,
+            self.node_or_err(target)?,
+        );
+        Ok(self.add_simple_edge(origin, target, edge))
+    }
+
+    /// Remove the edge from the origin node to the target node, if there is
+    /// one. The removed edge is returned so that it can be unapplied from its
+    /// conductors.
+    pub fn remove_edge_between(
+        &mut self,
+        origin: usize,
+        target: usize,
+    ) -> Option<NetworkTopologyEdge> {
+        let edge_index = self.find_edge(origin.into(), target.into())?;
+        self.graph.remove_edge(edge_index)
+    }
+
     /// Add a random simple edge to the graph. A simple edge is an edge that does
     /// not already exist in the graph and does not create a self edge. If the
     /// edge already exists or would create a self edge, then do nothing.
@@ -692,4 +719,99 @@ pub mod test {
             3_000_000
         );
     }
+
+    /// Test that applying a topology to a conductor batch only lets the
+    /// conductors reach each other along the edges of the topology, and that
+    /// the wiring follows edge mutations.
+    #[cfg(feature = "slow_tests")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_network_topology_apply_to_batch() {
+        use crate::conductor::p2p_agent_store::all_agent_infos;
+        use crate::sweettest::consistency_10s;
+        use crate::sweettest::SweetConductorBatch;
+        use crate::sweettest::SweetConductorConfig;
+        use crate::sweettest::SweetDnaFile;
+        use crate::sweettest::SweetInlineZomes;
+        use crate::test_utils::inline_zomes::simple_crud_zome;
+        use holochain_p2p::AgentPubKeyExt;
+        use holochain_types::prelude::*;
+
+        let mut conductors =
+            SweetConductorBatch::from_config_with_faults(3, SweetConductorConfig::standard()).await;
+        let (dna, _, _) = SweetDnaFile::unique_from_inline_zomes(simple_crud_zome()).await;
+        let apps = conductors.setup_app("app", &[dna.clone()]).await.unwrap();
+        let ((cell_0,), (cell_1,), (cell_2,)) = apps.into_tuples();
+        let agents = [&cell_0, &cell_1, &cell_2]
+            .iter()
+            .map(|cell| cell.agent_pubkey().to_kitsune())
+            .collect::<Vec<_>>();
+
+        let mut topology = NetworkTopology::default();
+        for _ in 0..3 {
+            assert!(topology.add_node(NetworkTopologyNode::new()));
+        }
+        assert!(topology.add_full_view_edge(0, 1).unwrap());
+
+        let knows = |observer: usize, seen: usize| {
+            let env = conductors[observer]
+                .spaces
+                .p2p_agents_db(dna.dna_hash())
+                .unwrap();
+            let agent = agents[seen].clone();
+            async move {
+                all_agent_infos(env.into())
+                    .await
+                    .unwrap()
+                    .iter()
+                    .any(|info| info.agent == agent)
+            }
+        };
+
+        conductors.apply_topology(&topology).await.unwrap();
+        assert!(knows(0, 1).await);
+        assert!(!knows(0, 2).await);
+        assert!(!knows(1, 0).await);
+        assert!(!conductors.faults().is_partitioned(0, 1));
+        assert!(conductors.faults().is_partitioned(0, 2));
+        assert!(conductors.faults().is_partitioned(1, 2));
+
+        let hash: ActionHash = conductors[0]
+            .call(
+                &cell_0.zome(SweetInlineZomes::COORDINATOR),
+                "create_string",
+                "hi".to_string(),
+            )
+            .await;
+        consistency_10s([&cell_0, &cell_1]).await;
+
+        // Conductor 2 has no edge, so it can't get the record from anybody.
+        let record: Option<Record> = conductors[2]
+            .call(
+                &cell_2.zome(SweetInlineZomes::COORDINATOR),
+                "read",
+                hash.clone(),
+            )
+            .await;
+        assert!(record.is_none());
+
+        assert!(topology.remove_edge_between(0, 1).is_some());
+        assert!(topology.add_full_view_edge(2, 1).unwrap());
+
+        conductors.apply_topology(&topology).await.unwrap();
+        assert!(!knows(0, 1).await);
+        assert!(knows(2, 1).await);
+        assert!(conductors.faults().is_partitioned(0, 1));
+        assert!(!conductors.faults().is_partitioned(1, 2));
+
+        // Conductor 2 now gets the record through conductor 1.
+        consistency_10s([&cell_1, &cell_2]).await;
+        let record: Option<Record> = conductors[2]
+            .call(
+                &cell_2.zome(SweetInlineZomes::COORDINATOR),
+                "read",
+                hash.clone(),
+            )
+            .await;
+        assert_eq!(record.unwrap().action_address(), &hash);
+    }
 }
@@ -202,6 +202,7 @@ impl ConductorTestData {
                     .map(TestZomes::from)
                     .map(|z| z.coordinator.into_inner())
                     .collect(),
+                lineage: Default::default(),
             },
             zomes.into_iter().flat_map(Vec::<DnaWasm>::from),
         )
@@ -7,6 +7,7 @@ mod integrity_zome;
 mod multi_conductor;
 mod network_tests;
 mod new_lair;
+mod op_archive;
 mod publish;
 mod regression;
 mod ser_regression;
@@ -4,6 +4,7 @@ use holo_hash::ActionHash;
 use holo_hash::WasmHash;
 use holochain::conductor::api::AdminInterfaceApi;
 use holochain::conductor::api::RealAdminInterfaceApi;
+use holochain::conductor::error::ConductorError;
 use holochain::sweettest::*;
 use holochain_conductor_api::AdminRequest;
 use holochain_conductor_api::AdminResponse;
@@ -303,3 +304,107 @@ async fn test_wasm_memory() {
             .await;
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_update_app() {
+    let mut conductor = SweetConductor::from_standard_config().await;
+    let (dna, _, _) = SweetDnaFile::unique_from_zomes(
+        vec![TestIntegrityWasm::IntegrityZome],
+        vec![TestCoordinatorWasm::CoordinatorZome],
+        vec![
+            DnaWasm::from(TestIntegrityWasm::IntegrityZome),
+            DnaWasm::from(TestCoordinatorWasm::CoordinatorZome),
+        ],
+    )
+    .await;
+
+    let app = conductor.setup_app("app", &[dna.clone()]).await.unwrap();
+    let cells = app.into_cells();
+
+    let hash: ActionHash = conductor
+        .call(
+            &cells[0].zome(TestCoordinatorWasm::CoordinatorZome),
+            "create_entry",
+            (),
+        )
+        .await;
+
+    // A bundle whose integrity zomes differ is rejected.
+    let (other_dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Create]).await;
+    let mut bundle_with_other_integrity = app_bundle_from_dnas([&other_dna]).await;
+    let mut manifest = bundle_with_other_integrity.manifest().clone();
+    let AppManifest::V1(manifest_v1) = &mut manifest;
+    manifest_v1.roles[0].name = dna.dna_hash().to_string();
+    bundle_with_other_integrity = AppBundle::from(
+        bundle_with_other_integrity
+            .into_inner()
+            .update_manifest(manifest)
+            .unwrap(),
+    );
+    let err = conductor
+        .update_app(
+            &"app".to_string(),
+            AppBundleSource::Bundle(bundle_with_other_integrity),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ConductorError::AppUpdateRejected(_, ref diff) if diff.len() == 1),
+        "{:?}",
+        err
+    );
+
+    // A bundle which changes the lineage of a DNA is rejected, even though the
+    // lineage doesn't affect the DNA hash.
+    let dna_with_lineage = {
+        let mut dna_def = dna.dna_def().clone();
+        dna_def.lineage = [other_dna.dna_hash().clone()].into_iter().collect();
+        DnaFile::new(dna_def, dna.code().values().cloned()).await
+    };
+    assert_eq!(dna_with_lineage.dna_hash(), dna.dna_hash());
+    let err = conductor
+        .update_app(
+            &"app".to_string(),
+            AppBundleSource::Bundle(app_bundle_from_dnas([&dna_with_lineage]).await),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ConductorError::AppUpdateRejected(_, ref diff)
+            if diff.len() == 1 && diff[0].contains("lineage")),
+        "{:?}",
+        err
+    );
+
+    // A bundle which only changes the coordinator zomes is applied.
+    let new_dna = {
+        let mut dna_def = dna.dna_def().clone();
+        dna_def.coordinator_zomes =
+            vec![CoordinatorZome::from(TestCoordinatorWasm::CoordinatorZomeUpdate).into_inner()];
+        DnaFile::new(
+            dna_def,
+            vec![
+                DnaWasm::from(TestIntegrityWasm::IntegrityZome),
+                DnaWasm::from(TestCoordinatorWasm::CoordinatorZomeUpdate),
+            ],
+        )
+        .await
+    };
+    assert_eq!(new_dna.dna_hash(), dna.dna_hash());
+    conductor
+        .update_app(
+            &"app".to_string(),
+            AppBundleSource::Bundle(app_bundle_from_dnas([&new_dna]).await),
+        )
+        .await
+        .unwrap();
+
+    let record: Option<Record> = conductor
+        .call(
+            &cells[0].zome(TestCoordinatorWasm::CoordinatorZomeUpdate),
+            "get_entry",
+            hash,
+        )
+        .await;
+    assert!(record.is_some());
+}
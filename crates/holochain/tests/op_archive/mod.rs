@@ -0,0 +1,113 @@
+use hdk::prelude::*;
+use holochain::conductor::api::error::ConductorApiError;
+use holochain::sweettest::*;
+use holochain::test_utils::inline_zomes::simple_crud_zome;
+use holochain::test_utils::WaitFor;
+use holochain_types::prelude::*;
+use matches::assert_matches;
+
+/// Ops exported from one conductor can be imported into another, unconnected
+/// conductor, after which the data is available there.
+#[tokio::test(flavor = "multi_thread")]
+async fn export_and_import_ops_between_unconnected_conductors() {
+    holochain_trace::test_run().ok();
+
+    let mut conductor_0 = SweetConductor::from_standard_config().await;
+    let mut conductor_1 = SweetConductor::from_standard_config().await;
+
+    let (dna_file, _, _) = SweetDnaFile::unique_from_inline_zomes(simple_crud_zome()).await;
+    let dna_hash = dna_file.dna_hash().clone();
+    let (cell_0,) = conductor_0
+        .setup_app("app", [&dna_file])
+        .await
+        .unwrap()
+        .into_tuple();
+    let (cell_1,) = conductor_1
+        .setup_app("app", [&dna_file])
+        .await
+        .unwrap()
+        .into_tuple();
+
+    let zome_0 = cell_0.zome(SweetInlineZomes::COORDINATOR);
+    let zome_1 = cell_1.zome(SweetInlineZomes::COORDINATOR);
+    let hash: ActionHash = conductor_0
+        .call(&zome_0, "create_string", "hi".to_string())
+        .await;
+
+    // The conductors never exchange peer info, so they can't see each other's data.
+    let record: Option<Record> = conductor_1.call(&zome_1, "read", hash.clone()).await;
+    assert!(record.is_none());
+
+    // Only integrated ops are exported, so wait for the create to be integrated.
+    let mut wait = WaitFor::new(std::time::Duration::from_secs(10), 100);
+    let archive = loop {
+        let archive = conductor_0
+            .export_ops(
+                dna_hash.clone(),
+                OpExportFilter::All,
+                cell_0.agent_pubkey().clone(),
+            )
+            .await
+            .unwrap();
+        if archive
+            .archive
+            .ops
+            .iter()
+            .any(|op| ActionHash::with_data_sync(&op.action()) == hash)
+        {
+            break archive;
+        }
+        assert!(
+            wait.wait_any().await,
+            "The created action was never exported"
+        );
+    };
+    assert_eq!(archive.signer, *cell_0.agent_pubkey());
+
+    let num_imported = conductor_1.import_ops(archive.clone()).await.unwrap();
+    assert_eq!(num_imported, archive.archive.ops.len());
+
+    holochain::wait_for!(
+        WaitFor::new(std::time::Duration::from_secs(10), 100),
+        conductor_1
+            .call::<_, Option<Record>>(&zome_1, "read", hash.clone())
+            .await
+            .is_some(),
+        |x: &bool| *x,
+        |x: bool| assert!(x, "The imported record never became available")
+    );
+}
+
+/// An archive can only be imported into a conductor which has its DNA,
+/// even if the archive itself is valid.
+#[tokio::test(flavor = "multi_thread")]
+async fn import_rejects_archive_for_other_dna() {
+    holochain_trace::test_run().ok();
+
+    let mut conductor_0 = SweetConductor::from_standard_config().await;
+    let mut conductor_1 = SweetConductor::from_standard_config().await;
+
+    let (dna_file_0, _, _) = SweetDnaFile::unique_from_inline_zomes(simple_crud_zome()).await;
+    let (dna_file_1, _, _) = SweetDnaFile::unique_from_inline_zomes(simple_crud_zome()).await;
+    let (cell_0,) = conductor_0
+        .setup_app("app", [&dna_file_0])
+        .await
+        .unwrap()
+        .into_tuple();
+    conductor_1.setup_app("app", [&dna_file_1]).await.unwrap();
+
+    let archive = conductor_0
+        .export_ops(
+            dna_file_0.dna_hash().clone(),
+            OpExportFilter::All,
+            cell_0.agent_pubkey().clone(),
+        )
+        .await
+        .unwrap();
+    archive.verify().await.unwrap();
+
+    assert_matches!(
+        conductor_1.import_ops(archive).await,
+        Err(ConductorApiError::DnaError(DnaError::DnaMissing(hash))) if hash == *dna_file_0.dna_hash()
+    );
+}
@@ -56,6 +56,7 @@ async fn ser_regression_test() {
             coordinator_zomes: vec![TestZomes::from(TestWasm::SerRegression)
                 .coordinator
                 .into_inner()],
+            lineage: Default::default(),
         },
         <Vec<DnaWasm>>::from(TestWasm::SerRegression),
     )
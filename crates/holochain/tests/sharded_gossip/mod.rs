@@ -218,6 +218,58 @@ async fn fullsync_sharded_gossip_high_data() -> anyhow::Result<()> {
 }
 
 /// Test that conductors with arcs clamped to zero do not gossip.
+/// Two nodes running only the full-sync gossip strategy, with publish disabled,
+/// should converge on each other's data.
+#[cfg(feature = "test_utils")]
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(target_os = "macos", ignore = "flaky")]
+async fn full_sync_gossip_converges() {
+    holochain_trace::test_run().ok();
+
+    let mut tuning = make_tuning(false, true, true, None);
+    tuning.gossip_strategy = "full-sync".to_string();
+    let config = SweetConductorConfig::rendezvous(true).set_tuning_params(tuning);
+
+    let mut conductors = SweetConductorBatch::from_config_rendezvous(2, config).await;
+
+    let (dna_file, _, _) =
+        SweetDnaFile::unique_from_inline_zomes(("simple", simple_create_read_zome())).await;
+
+    let apps = conductors.setup_app("app", &[dna_file]).await.unwrap();
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_hash: ActionHash = conductors[0]
+        .call(&alice.zome("simple"), "create", ())
+        .await;
+    let bobbo_hash: ActionHash = conductors[1]
+        .call(&bobbo.zome("simple"), "create", ())
+        .await;
+
+    consistency_60s([&alice, &bobbo]).await;
+
+    let record: Option<Record> = conductors[1]
+        .call(&bobbo.zome("simple"), "read", alice_hash)
+        .await;
+    assert_eq!(
+        record
+            .expect("bobbo didn't get alice's record")
+            .action()
+            .author(),
+        alice.agent_pubkey()
+    );
+
+    let record: Option<Record> = conductors[0]
+        .call(&alice.zome("simple"), "read", bobbo_hash)
+        .await;
+    assert_eq!(
+        record
+            .expect("alice didn't get bobbo's record")
+            .action()
+            .author(),
+        bobbo.agent_pubkey()
+    );
+}
+
 #[cfg(feature = "slow_tests")]
 #[tokio::test(flavor = "multi_thread")]
 async fn test_zero_arc_get_links() {
@@ -498,6 +550,49 @@ async fn test_gossip_shutdown() {
     assert_eq!(record.unwrap().action_address(), &hash);
 }
 
+/// Test that gossip resumes once a network partition between two conductors heals.
+#[cfg(feature = "slow_tests")]
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(target_os = "macos", ignore = "flaky")]
+async fn test_gossip_recovers_after_partition() {
+    holochain_trace::test_run().ok();
+    let mut conductors = SweetConductorBatch::from_config_with_faults(
+        2,
+        TestConfig {
+            publish: false,
+            recent: true,
+            historical: true,
+            bootstrap: false,
+            recent_threshold: None,
+        },
+    )
+    .await;
+    conductors.faults().partition([[0], [1]]);
+
+    let (dna_file, _, _) = SweetDnaFile::unique_from_inline_zomes(simple_crud_zome()).await;
+
+    let apps = conductors.setup_app("app", &[dna_file]).await.unwrap();
+    let ((cell_0,), (cell_1,)) = apps.into_tuples();
+    let zome_0 = cell_0.zome(SweetInlineZomes::COORDINATOR);
+    let zome_1 = cell_1.zome(SweetInlineZomes::COORDINATOR);
+    conductors.exchange_peer_info().await;
+
+    let hash: ActionHash = conductors[0]
+        .call(&zome_0, "create_string", "hi".to_string())
+        .await;
+
+    // While partitioned, conductor 1 can neither gossip with nor get from conductor 0
+    tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+    let record: Option<Record> = conductors[1].call(&zome_1, "read", hash.clone()).await;
+    assert!(record.is_none());
+
+    conductors.faults().heal();
+
+    consistency_60s([&cell_0, &cell_1]).await;
+    let record: Option<Record> = conductors[1].call(&zome_1, "read", hash.clone()).await;
+    assert_eq!(record.unwrap().action_address(), &hash);
+}
+
 /// Test that when a new conductor joins, gossip picks up existing data without needing a publish.
 #[cfg(feature = "slow_tests")]
 #[tokio::test(flavor = "multi_thread")]
@@ -912,6 +1007,12 @@ async fn mock_network_sharded_gossip() {
                             debug!("CallRemoteMulti")
                         }
                         holochain_p2p::WireMessage::CallRemote { .. } => debug!("CallRemote"),
+                        holochain_p2p::WireMessage::CallRemoteStreamChunk { .. }
+                        | holochain_p2p::WireMessage::CallRemoteStreamed { .. }
+                        | holochain_p2p::WireMessage::CallRemoteStreamResponse { .. }
+                        | holochain_p2p::WireMessage::CallRemoteStreamDone { .. } => {
+                            debug!("CallRemoteStreamed")
+                        }
                         holochain_p2p::WireMessage::PublishCountersign { .. } => {
                             debug!("PublishCountersign")
                         }
@@ -1432,6 +1533,12 @@ async fn mock_network_sharding() {
                             debug!("CallRemoteMulti")
                         }
                         holochain_p2p::WireMessage::CallRemote { .. } => debug!("CallRemote"),
+                        holochain_p2p::WireMessage::CallRemoteStreamChunk { .. }
+                        | holochain_p2p::WireMessage::CallRemoteStreamed { .. }
+                        | holochain_p2p::WireMessage::CallRemoteStreamResponse { .. }
+                        | holochain_p2p::WireMessage::CallRemoteStreamDone { .. } => {
+                            debug!("CallRemoteStreamed")
+                        }
                         holochain_p2p::WireMessage::ValidationReceipts { receipts: _ } => {
                             debug!("Validation Receipt")
                         }
@@ -132,6 +132,7 @@ async fn speed_test(n: Option<usize>) -> Arc<TempDir> {
             },
             integrity_zomes: vec![TestZomes::from(TestWasm::Anchor).integrity.into_inner()],
             coordinator_zomes: vec![TestZomes::from(TestWasm::Anchor).coordinator.into_inner()],
+            lineage: Default::default(),
         },
         vec![TestWasm::Anchor.into()],
     )
@@ -253,6 +253,7 @@ pub async fn register_and_install_dna_named(
             clone_limit: 0,
         },
         provisioning: Some(CellProvisioning::Create { deferred: false }),
+        arc_policy: None,
     }];
 
     let manifest = AppManifestCurrentBuilder::default()
@@ -6,30 +6,33 @@ use holochain_p2p::actor::GetActivityOptions;
 #[allow(clippy::result_large_err)] // TODO - investigate this lint
 pub(crate) fn merge_activities(
     agent: AgentPubKey,
+    query: &ChainQueryFilter,
     options: &GetActivityOptions,
     results: Vec<AgentActivityResponse<ActionHash>>,
 ) -> CascadeResult<AgentActivityResponse<ActionHash>> {
     if !options.include_rejected_activity && !options.include_valid_activity {
         return Ok(merge_status_only(agent, results));
     }
-    Ok(merge_hashes(agent, options, results))
+    Ok(merge_hashes(agent, query, options, results))
 }
 
 fn merge_hashes(
     agent: AgentPubKey,
+    query: &ChainQueryFilter,
     options: &GetActivityOptions,
     results: Vec<AgentActivityResponse<ActionHash>>,
 ) -> AgentActivityResponse<ActionHash> {
     let mut valid = HashSet::new();
     let mut rejected = HashSet::new();
+    let mut merged_status = None;
     let mut merged_highest_observed = None;
     for result in results {
         let AgentActivityResponse {
+            status,
             agent: the_agent,
             highest_observed,
             valid_activity,
             rejected_activity,
-            ..
         } = result;
         if the_agent != agent {
             continue;
@@ -46,6 +49,11 @@ fn merge_hashes(
             }
         }
 
+        merged_status = Some(match merged_status.take() {
+            Some(last) => merge_status(status, last),
+            None => status,
+        });
+
         match valid_activity {
             ChainItems::Full(_) => {
                 // TODO: BACKLOG: Currently not handling full actions from
@@ -68,7 +76,17 @@ fn merge_hashes(
         }
     }
 
-    let (status, valid, rejected) = compute_chain_status(valid, rejected);
+    let (status, mut valid, mut rejected) = compute_chain_status(valid, rejected);
+    // The hashes may only be a page of the activity, so the status they imply
+    // is merged with the status each authority reported for the whole chain.
+    let status = match merged_status {
+        Some(merged_status) => merge_status(status, merged_status),
+        None => status,
+    };
+    if query.order_descending {
+        valid.reverse();
+        rejected.reverse();
+    }
     let valid_activity = if options.include_valid_activity {
         ChainItems::Hashes(valid)
     } else {
@@ -168,66 +186,10 @@ fn merge_status_only(
                 merged_highest_observed = Some(c);
             }
         }
-        match merged_status.take() {
-            Some(last) => match (status, last) {
-                (ChainStatus::Empty, ChainStatus::Empty) => {
-                    merged_status = Some(ChainStatus::Empty);
-                }
-                (ChainStatus::Empty, ChainStatus::Valid(c))
-                | (ChainStatus::Valid(c), ChainStatus::Empty) => {
-                    merged_status = Some(ChainStatus::Valid(c));
-                }
-                (ChainStatus::Empty, ChainStatus::Forked(c))
-                | (ChainStatus::Forked(c), ChainStatus::Empty) => {
-                    merged_status = Some(ChainStatus::Forked(c));
-                }
-                (ChainStatus::Empty, ChainStatus::Invalid(c))
-                | (ChainStatus::Invalid(c), ChainStatus::Empty) => {
-                    merged_status = Some(ChainStatus::Invalid(c));
-                }
-                (ChainStatus::Valid(a), ChainStatus::Valid(b)) => {
-                    let c = if a.action_seq > b.action_seq { a } else { b };
-                    merged_status = Some(ChainStatus::Valid(c));
-                }
-                (ChainStatus::Valid(_), ChainStatus::Forked(c))
-                | (ChainStatus::Forked(c), ChainStatus::Valid(_)) => {
-                    // If the valid and forked chain heads are the same then they are in conflict here.
-                    // TODO: BACKLOG: When we handle conflicts this should count as a conflict.
-                    merged_status = Some(ChainStatus::Forked(c));
-                }
-                (ChainStatus::Invalid(c), ChainStatus::Valid(_))
-                | (ChainStatus::Valid(_), ChainStatus::Invalid(c)) => {
-                    // If the valid and invalid chain heads are the same then they are in conflict here.
-                    // TODO: BACKLOG: When we handle conflicts this should count as a conflict.
-                    merged_status = Some(ChainStatus::Invalid(c));
-                }
-                (ChainStatus::Forked(a), ChainStatus::Forked(b)) => {
-                    let c = if a.fork_seq < b.fork_seq { a } else { b };
-                    merged_status = Some(ChainStatus::Forked(c));
-                }
-                (ChainStatus::Invalid(a), ChainStatus::Invalid(b)) => {
-                    let c = if a.action_seq < b.action_seq { a } else { b };
-                    merged_status = Some(ChainStatus::Invalid(c));
-                }
-                (ChainStatus::Forked(a), ChainStatus::Invalid(b)) => {
-                    if a.fork_seq < b.action_seq {
-                        merged_status = Some(ChainStatus::Forked(a));
-                    } else {
-                        merged_status = Some(ChainStatus::Invalid(b));
-                    };
-                }
-                (ChainStatus::Invalid(a), ChainStatus::Forked(b)) => {
-                    if a.action_seq < b.fork_seq {
-                        merged_status = Some(ChainStatus::Invalid(a));
-                    } else {
-                        merged_status = Some(ChainStatus::Forked(b));
-                    };
-                }
-            },
-            None => {
-                merged_status = Some(status);
-            }
-        }
+        merged_status = Some(match merged_status.take() {
+            Some(last) => merge_status(status, last),
+            None => status,
+        });
     }
     AgentActivityResponse {
         status: merged_status.unwrap_or(ChainStatus::Empty),
@@ -237,3 +199,54 @@ fn merge_status_only(
         highest_observed: merged_highest_observed,
     }
 }
+
+/// Merge the chain status reported by one authority into the status merged so far.
+fn merge_status(status: ChainStatus, last: ChainStatus) -> ChainStatus {
+    match (status, last) {
+        (ChainStatus::Empty, ChainStatus::Empty) => ChainStatus::Empty,
+        (ChainStatus::Empty, ChainStatus::Valid(c))
+        | (ChainStatus::Valid(c), ChainStatus::Empty) => ChainStatus::Valid(c),
+        (ChainStatus::Empty, ChainStatus::Forked(c))
+        | (ChainStatus::Forked(c), ChainStatus::Empty) => ChainStatus::Forked(c),
+        (ChainStatus::Empty, ChainStatus::Invalid(c))
+        | (ChainStatus::Invalid(c), ChainStatus::Empty) => ChainStatus::Invalid(c),
+        (ChainStatus::Valid(a), ChainStatus::Valid(b)) => {
+            let c = if a.action_seq > b.action_seq { a } else { b };
+            ChainStatus::Valid(c)
+        }
+        (ChainStatus::Valid(_), ChainStatus::Forked(c))
+        | (ChainStatus::Forked(c), ChainStatus::Valid(_)) => {
+            // If the valid and forked chain heads are the same then they are in conflict here.
+            // TODO: BACKLOG: When we handle conflicts this should count as a conflict.
+            ChainStatus::Forked(c)
+        }
+        (ChainStatus::Invalid(c), ChainStatus::Valid(_))
+        | (ChainStatus::Valid(_), ChainStatus::Invalid(c)) => {
+            // If the valid and invalid chain heads are the same then they are in conflict here.
+            // TODO: BACKLOG: When we handle conflicts this should count as a conflict.
+            ChainStatus::Invalid(c)
+        }
+        (ChainStatus::Forked(a), ChainStatus::Forked(b)) => {
+            let c = if a.fork_seq < b.fork_seq { a } else { b };
+            ChainStatus::Forked(c)
+        }
+        (ChainStatus::Invalid(a), ChainStatus::Invalid(b)) => {
+            let c = if a.action_seq < b.action_seq { a } else { b };
+            ChainStatus::Invalid(c)
+        }
+        (ChainStatus::Forked(a), ChainStatus::Invalid(b)) => {
+            if a.fork_seq < b.action_seq {
+                ChainStatus::Forked(a)
+            } else {
+                ChainStatus::Invalid(b)
+            }
+        }
+        (ChainStatus::Invalid(a), ChainStatus::Forked(b)) => {
+            if a.action_seq < b.fork_seq {
+                ChainStatus::Invalid(a)
+            } else {
+                ChainStatus::Forked(b)
+            }
+        }
+    }
+}
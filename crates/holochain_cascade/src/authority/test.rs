@@ -242,4 +242,23 @@ async fn get_agent_activity() {
     .unwrap();
 
     assert_eq!(result, expected);
+
+    // A page of the activity still reports the status of the whole chain.
+    let all_hashes = match td.valid_hashes.clone() {
+        ChainItems::Hashes(v) => v,
+        _ => unreachable!(),
+    };
+    expected.valid_activity = ChainItems::Hashes(all_hashes[10..15].to_vec());
+
+    let filter = QueryFilter::new().offset(10).limit(5);
+    let result = handle_get_agent_activity(
+        db.to_db().into(),
+        td.agent.clone(),
+        filter,
+        (&options).into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, expected);
 }
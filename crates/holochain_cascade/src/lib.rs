@@ -440,33 +440,53 @@ where
 
     async fn merge_ops_into_cache(&self, responses: Vec<WireOps>) -> CascadeResult<()> {
         let cache = some_or_return!(self.cache.as_ref());
-        cache
+        let op_hashes = cache
             .write_async(|txn| {
+                let mut op_hashes = Vec::new();
                 for response in responses {
                     let ops = response.render()?;
                     Self::insert_rendered_ops(txn, &ops)?;
+                    op_hashes.extend(ops.ops.into_iter().map(|op| op.op_hash));
                 }
-                CascadeResult::Ok(())
+                CascadeResult::Ok(op_hashes)
             })
             .await?;
+        self.prioritize_fetch(op_hashes).await;
         Ok(())
     }
 
+    /// A zome call is waiting on these ops. If we are also an authority for them
+    /// and they are still queued to be fetched, then fetch them ahead of any
+    /// gossip backlog so that they are held and validated sooner.
+    async fn prioritize_fetch(&self, op_hashes: Vec<DhtOpHash>) {
+        if op_hashes.is_empty() {
+            return;
+        }
+        if let Some(network) = self.network.as_ref() {
+            if let Err(e) = network.prioritize_fetch(op_hashes).await {
+                tracing::warn!(?e, "Failed to prioritize fetching ops");
+            }
+        }
+    }
+
     async fn merge_link_ops_into_cache(
         &self,
         responses: Vec<WireLinkOps>,
         key: WireLinkKey,
     ) -> CascadeResult<()> {
         let cache = some_or_return!(self.cache.as_ref());
-        cache
+        let op_hashes = cache
             .write_async(move |txn| {
+                let mut op_hashes = Vec::new();
                 for response in responses {
                     let ops = response.render(&key)?;
                     Self::insert_rendered_ops(txn, &ops)?;
+                    op_hashes.extend(ops.ops.into_iter().map(|op| op.op_hash));
                 }
-                CascadeResult::Ok(())
+                CascadeResult::Ok(op_hashes)
             })
             .await?;
+        self.prioritize_fetch(op_hashes).await;
         Ok(())
     }
 
@@ -1054,6 +1074,9 @@ where
     /// - include_entries will also fetch the entries in parallel (requires include_full_actions)
     /// - sequence_range will get all the activity in the exclusive range
     /// - action_type and entry_type will filter the activity (requires include_full_actions)
+    /// - offset and limit will return a page of the filtered activity, counted from the
+    ///   end of the chain and returned in descending order if order_descending is set.
+    ///   The status is still that of the whole chain.
     pub async fn get_agent_activity(
         &self,
         agent: AgentPubKey,
@@ -1068,7 +1091,7 @@ where
                 .fetch_agent_activity(agent.clone(), query.clone(), options.clone())
                 .await?;
             let merged_response: AgentActivityResponse<ActionHash> =
-                agent_activity::merge_activities(agent.clone(), &options, results)?;
+                agent_activity::merge_activities(agent.clone(), &query, &options, results)?;
             merged_response
         } else {
             match self.dht.clone() {
@@ -1083,6 +1106,7 @@ where
                 }
                 None => agent_activity::merge_activities(
                     agent.clone(),
+                    &query,
                     &options,
                     Vec::with_capacity(0),
                 )?,
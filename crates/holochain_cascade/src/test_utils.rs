@@ -189,6 +189,11 @@ impl HolochainP2pDnaT for PassThroughNetwork {
         Ok(out)
     }
 
+    async fn prioritize_fetch(&self, _op_hashes: Vec<DhtOpHash>) -> actor::HolochainP2pResult<()> {
+        // There is no fetch pool to prioritize.
+        Ok(())
+    }
+
     async fn authority_for_hash(
         &self,
         _dht_hash: holo_hash::OpBasis,
@@ -284,6 +289,23 @@ impl HolochainP2pDnaT for PassThroughNetwork {
         todo!()
     }
 
+    async fn call_remote_streamed(
+        &self,
+        _from_agent: AgentPubKey,
+        _from_signature: Signature,
+        _to_agent: AgentPubKey,
+        _zome_name: ZomeName,
+        _fn_name: FunctionName,
+        _cap: Option<CapSecret>,
+        _payload: ExternIO,
+        _nonce: Nonce256Bits,
+        _expires_at: Timestamp,
+    ) -> actor::HolochainP2pResult<holochain_serialized_bytes::SerializedBytes> {
+        Err(HolochainP2pError::other(
+            "Streamed remote calls are not supported by the PassThroughNetwork",
+        ))
+    }
+
     fn chc(&self) -> Option<ChcImpl> {
         None
     }
@@ -395,6 +417,10 @@ impl HolochainP2pDnaT for MockNetwork {
             .await
     }
 
+    async fn prioritize_fetch(&self, op_hashes: Vec<DhtOpHash>) -> actor::HolochainP2pResult<()> {
+        self.0.lock().await.prioritize_fetch(op_hashes).await
+    }
+
     async fn authority_for_hash(
         &self,
         dht_hash: holo_hash::OpBasis,
@@ -490,6 +516,23 @@ impl HolochainP2pDnaT for MockNetwork {
         todo!()
     }
 
+    async fn call_remote_streamed(
+        &self,
+        _from_agent: AgentPubKey,
+        _from_signature: Signature,
+        _to_agent: AgentPubKey,
+        _zome_name: ZomeName,
+        _fn_name: FunctionName,
+        _cap: Option<CapSecret>,
+        _payload: ExternIO,
+        _nonce: Nonce256Bits,
+        _expires_at: Timestamp,
+    ) -> actor::HolochainP2pResult<holochain_serialized_bytes::SerializedBytes> {
+        Err(HolochainP2pError::other(
+            "Streamed remote calls are not supported by the MockNetwork",
+        ))
+    }
+
     fn chc(&self) -> Option<ChcImpl> {
         None
     }
@@ -60,6 +60,73 @@ async fn get_activity() {
     assert_eq!(r, expected);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn get_activity_page_reports_whole_chain_status() {
+    holochain_trace::test_run().ok();
+
+    // Environments
+    let cache = test_cache_db();
+    let authority = test_dht_db();
+
+    // Data
+    let td = ActivityTestData::valid_chain_scenario();
+
+    for hash_op in td.hash_ops.iter().cloned() {
+        fill_db(&authority.to_db(), hash_op).await;
+    }
+
+    let options = holochain_p2p::actor::GetActivityOptions {
+        include_valid_activity: true,
+        include_rejected_activity: false,
+        ..Default::default()
+    };
+    let all_hashes = match td.valid_hashes.clone() {
+        ChainItems::Hashes(hashes) => hashes,
+        _ => unreachable!(),
+    };
+
+    // Network
+    let network = PassThroughNetwork::authority_for_nothing(vec![authority.to_db().clone().into()]);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(network, cache.to_db());
+
+    // Neither page includes the chain head, but the status is of the whole chain.
+    let r = cascade
+        .get_agent_activity(
+            td.agent.clone(),
+            ChainQueryFilter::new().offset(1).limit(2),
+            options.clone(),
+        )
+        .await
+        .unwrap();
+    let expected = AgentActivityResponse {
+        agent: td.agent.clone(),
+        valid_activity: ChainItems::Hashes(all_hashes[1..3].to_vec()),
+        rejected_activity: ChainItems::NotRequested,
+        status: ChainStatus::Valid(td.chain_head.clone()),
+        highest_observed: Some(td.highest_observed.clone()),
+    };
+    assert_eq!(r, expected);
+
+    // Descending pages are counted from the end of the chain and come back descending.
+    let r = cascade
+        .get_agent_activity(
+            td.agent.clone(),
+            ChainQueryFilter::new().descending().offset(1).limit(2),
+            options,
+        )
+        .await
+        .unwrap();
+    let mut page = all_hashes[all_hashes.len() - 3..all_hashes.len() - 1].to_vec();
+    page.reverse();
+    let expected = AgentActivityResponse {
+        valid_activity: ChainItems::Hashes(page),
+        ..expected
+    };
+    assert_eq!(r, expected);
+}
+
 #[derive(Default)]
 struct Data {
     scratch: Option<Vec<(AgentPubKey, Vec<TestChainItem>)>>,
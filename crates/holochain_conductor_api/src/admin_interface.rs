@@ -54,6 +54,19 @@ pub enum AdminRequest {
     /// [`AdminResponse::CoordinatorsUpdated`]
     UpdateCoordinators(Box<UpdateCoordinatorsPayload>),
 
+    /// Update the coordinator zomes of an installed app from a new version of
+    /// its app bundle.
+    ///
+    /// Each role of the new bundle must have the same DNA hash as the installed
+    /// role, i.e. only coordinator zomes may change. If so, the coordinators of
+    /// all cells of the app, including clone cells, are updated. Otherwise the
+    /// update is rejected with an error listing the differences.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::AppUpdated`]
+    UpdateApp(Box<UpdateAppPayload>),
+
     /// Install an app using an [`AppBundle`].
     ///
     /// Triggers genesis to be run on all Cells and to be stored.
@@ -228,6 +241,20 @@ pub enum AdminRequest {
     /// Dump raw json network statistics from the backend networking lib.
     DumpNetworkStats,
 
+    /// Dump the validation receipts which the cell specified by argument `cell_id`
+    /// has received from validators for the ops it has authored.
+    ///
+    /// **Warning**: this API call is subject to change, and will not be available to hApps.
+    /// This is meant to be used by introspection tooling.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::ValidationReceiptsDumped`]
+    DumpValidationReceipts {
+        /// The cell ID for which to dump the validation receipts
+        cell_id: Box<CellId>,
+    },
+
     /// Add a list of agents to this conductor's peer store.
     ///
     /// This is a way of shortcutting peer discovery and is useful for testing.
@@ -329,6 +356,148 @@ pub enum AdminRequest {
 
     /// Info about storage used by apps
     StorageInfo,
+
+    /// Export the integrated DHT ops held for a DNA into a signed,
+    /// self-describing archive.
+    ///
+    /// The archive can be carried to another conductor by any means and
+    /// imported there with [`AdminRequest::ImportOps`], which makes it possible
+    /// to seed nodes which have little or no network connectivity.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::OpsExported`]
+    ExportOps {
+        /// The DNA whose ops are to be exported.
+        dna_hash: DnaHash,
+        /// Which of the DNA's ops to export.
+        filter: OpExportFilter,
+        /// The agent whose key is used to sign the archive.
+        /// This key must be in the conductor's keystore.
+        signer: AgentPubKey,
+    },
+
+    /// Import the ops in an archive produced by [`AdminRequest::ExportOps`].
+    ///
+    /// The archive signature is checked, and then every op is validated
+    /// exactly as if it had been received over the network.
+    /// A cell for the archive's DNA must be running on this conductor.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::OpsImported`]
+    ImportOps(Box<SignedOpArchive>),
+
+    /// Set the storage arc policy for all cells of an app role, overriding
+    /// any policy given for the role in the app manifest.
+    ///
+    /// This controls how much of the DHT the role's cells commit to holding,
+    /// so that apps running the same DNA on one conductor can make different
+    /// storage commitments. The current policy is reported in [`NetworkInfo`].
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::ArcPolicySet`]
+    ///
+    /// [`NetworkInfo`]: crate::NetworkInfo
+    SetArcPolicy {
+        /// The app containing the role.
+        installed_app_id: InstalledAppId,
+        /// The role whose cells the policy applies to.
+        role_name: RoleName,
+        /// The new policy. If `None`, the conductor-wide arc settings are used.
+        arc_policy: Option<ArcPolicy>,
+    },
+
+    /// Migrate an agent from a cell of one DNA to a cell of another DNA,
+    /// such as a new version of the same DNA with breaking integrity changes.
+    ///
+    /// Both cells must belong to the same agent and be enabled.
+    /// The `migrate_agent` callbacks of both DNAs are run, then the old
+    /// chain is closed with a `CloseChain` action and the new chain is
+    /// opened with an `OpenChain` action. Nothing can be committed to the
+    /// old chain after this.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::AgentMigrated`]
+    MigrateAgent {
+        /// The cell the agent is migrating away from.
+        from_cell_id: CellId,
+        /// The cell the agent is migrating into.
+        to_cell_id: CellId,
+    },
+
+    /// Block a cell, a node on a DNA, a whole node or an IP address
+    /// for some time span.
+    ///
+    /// Nothing is sent to or accepted from a blocked target during the span.
+    /// Blocks of the same target for the same reason which overlap are merged.
+    ///
+    /// IP addresses are only blocked on direct tx2 connections, as the remote
+    /// address of a tx5 (WebRTC) connection isn't known.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::Blocked`]
+    Block {
+        /// The target to block, and the reason for the block.
+        target: BlockTarget,
+        /// The time span of the block, inclusive of both ends.
+        interval: InclusiveTimestampInterval,
+    },
+
+    /// Lift a block from a target for some time span.
+    ///
+    /// Only blocks for the same reason as the given target are lifted, and
+    /// any parts of them outside of the given span remain in place.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::Unblocked`]
+    Unblock {
+        /// The target to unblock, and the reason it was blocked for.
+        target: BlockTarget,
+        /// The time span to lift the block for, inclusive of both ends.
+        interval: InclusiveTimestampInterval,
+    },
+
+    /// List the blocks stored by the conductor.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::BlocksListed`]
+    ListBlocks {
+        /// If set, only list the blocks in effect at this time.
+        at: Option<Timestamp>,
+    },
+
+    /// List the pending scheduled functions of a cell.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::SchedulesListed`]
+    ListSchedules {
+        /// The cell to list the schedules of.
+        cell_id: CellId,
+    },
+
+    /// Cancel a pending schedule of a function for a cell.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::ScheduleCancelled`]
+    CancelSchedule {
+        /// The cell the function is scheduled for.
+        cell_id: CellId,
+        /// The zome of the scheduled function.
+        zome_name: ZomeName,
+        /// The scheduled function.
+        fn_name: FunctionName,
+        /// The name the function was scheduled under.
+        /// Functions scheduled without a name have the empty name.
+        name: String,
+    },
 }
 
 /// Represents the possible responses to an [`AdminRequest`]
@@ -356,6 +525,9 @@ pub enum AdminResponse {
     /// The successful response to an [`AdminRequest::UpdateCoordinators`]
     CoordinatorsUpdated,
 
+    /// The successful response to an [`AdminRequest::UpdateApp`]
+    AppUpdated,
+
     /// The successful response to an [`AdminRequest::InstallApp`].
     ///
     /// The resulting [`AppInfo`] contains the app ID,
@@ -444,6 +616,9 @@ pub enum AdminResponse {
     /// networking library.
     NetworkStatsDumped(String),
 
+    /// The successful result of a call to [`AdminRequest::DumpValidationReceipts`].
+    ValidationReceiptsDumped(Vec<SignedValidationReceipt>),
+
     /// The successful response to an [`AdminRequest::AddAgentInfo`].
     ///
     /// This means the agent info was successfully added to the peer store.
@@ -465,6 +640,45 @@ pub enum AdminResponse {
 
     /// The successful response to an [`AdminRequest::StorageInfo`].
     StorageInfo(StorageInfo),
+
+    /// The successful response to an [`AdminRequest::ExportOps`].
+    ///
+    /// Contains the signed archive of all matching ops.
+    OpsExported(Box<SignedOpArchive>),
+
+    /// The successful response to an [`AdminRequest::ImportOps`].
+    ///
+    /// Contains the number of ops which were submitted for validation.
+    OpsImported(usize),
+
+    /// The successful response to an [`AdminRequest::SetArcPolicy`].
+    ///
+    /// The new policy will be applied the next time each agent's info is refreshed.
+    ArcPolicySet,
+
+    /// The successful response to an [`AdminRequest::MigrateAgent`].
+    AgentMigrated,
+
+    /// The successful response to an [`AdminRequest::Block`].
+    Blocked,
+
+    /// The successful response to an [`AdminRequest::Unblock`].
+    Unblocked,
+
+    /// The successful response to an [`AdminRequest::ListBlocks`].
+    ///
+    /// Contains the blocks, ordered by the start of their time span.
+    BlocksListed(Vec<BlockSpan>),
+
+    /// The successful response to an [`AdminRequest::ListSchedules`].
+    ///
+    /// Contains the pending schedules, soonest first.
+    SchedulesListed(Vec<ScheduledFnInfo>),
+
+    /// The successful response to an [`AdminRequest::CancelSchedule`].
+    ///
+    /// Contains whether the function was scheduled under the given name.
+    ScheduleCancelled(bool),
 }
 
 /// Error type that goes over the websocket wire.
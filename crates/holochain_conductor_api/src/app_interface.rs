@@ -38,6 +38,14 @@ pub enum AppRequest {
     /// [`AppResponse::ZomeCalled`]
     CallZome(Box<ZomeCall>),
 
+    /// Call a zome function, like [`AppRequest::CallZome`], and also report
+    /// the wasm metering points the zome function used.
+    ///
+    /// # Returns
+    ///
+    /// [`AppResponse::ZomeCalledMetered`]
+    CallZomeMetered(Box<ZomeCall>),
+
     /// Clone a DNA (in the biological sense), thus creating a new `Cell`.
     ///
     /// Using the provided, already-registered DNA, create a new DNA with a unique
@@ -81,6 +89,17 @@ pub enum AppRequest {
     ///
     /// [`AppResponse::ListWasmHostFunctions`]
     ListWasmHostFunctions,
+
+    /// List the functions which can be called, and the callbacks which are
+    /// implemented, for each zome of a cell.
+    ///
+    /// # Returns
+    ///
+    /// [`AppResponse::ZomeFunctionsListed`]
+    ListZomeFunctions {
+        /// The cell to list the zome functions of
+        cell_id: CellId,
+    },
 }
 
 /// Represents the possible responses to an [`AppRequest`].
@@ -105,6 +124,14 @@ pub enum AppResponse {
     /// [msgpack]: https://msgpack.org/
     ZomeCalled(Box<ExternIO>),
 
+    /// The successful response to an [`AppRequest::CallZomeMetered`].
+    ZomeCalledMetered {
+        /// The output of the zome function, as in [`AppResponse::ZomeCalled`].
+        output: Box<ExternIO>,
+        /// The wasm metering points used by the zome function.
+        metering_points: u64,
+    },
+
     /// The successful response to an [`AppRequest::CreateCloneCell`].
     ///
     /// The response contains the created clone [`ClonedCell`].
@@ -126,6 +153,20 @@ pub enum AppResponse {
 
     /// All the wasm host functions supported by this conductor.
     ListWasmHostFunctions(Vec<String>),
+
+    /// The successful response to an [`AppRequest::ListZomeFunctions`].
+    ZomeFunctionsListed(Vec<ZomeFunctions>),
+}
+
+/// The functions of a zome, as listed by [`AppRequest::ListZomeFunctions`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ZomeFunctions {
+    /// The name of the zome
+    pub zome_name: ZomeName,
+    /// The functions of the zome which can be called, sorted by name
+    pub functions: Vec<FunctionName>,
+    /// The callbacks the zome implements, sorted by name
+    pub callbacks: Vec<FunctionName>,
 }
 
 /// The data provided over an app interface in order to make a zome call
@@ -313,12 +354,19 @@ pub struct AppInfo {
     /// The original AppManifest used to install the app, which can also be used to
     /// install the app again under a new agent.
     pub manifest: AppManifest,
+    /// For each role, the cells of the app's agent, in any installed app,
+    /// whose DNA is one of the previous versions listed in the lineage of
+    /// the role's DNA. These hold the data the agent created under the
+    /// versions which the role's DNA supersedes.
+    #[serde(default)]
+    pub lineage_cells: HashMap<RoleName, Vec<CellId>>,
 }
 
 impl AppInfo {
     pub fn from_installed_app(
         app: &InstalledApp,
         dna_definitions: &HashMap<CellId, DnaDefHashed>,
+        lineage_cells: HashMap<RoleName, Vec<CellId>>,
     ) -> Self {
         let installed_app_id = app.id().clone();
         let status = app.status().clone().into();
@@ -407,6 +455,7 @@ impl AppInfo {
             status,
             agent_pub_key,
             manifest,
+            lineage_cells,
         }
     }
 }
@@ -448,6 +497,10 @@ pub struct NetworkInfo {
     pub total_network_peers: u32,
     pub bytes_since_last_time_queried: u64,
     pub completed_rounds_since_last_time_queried: u32,
+    /// The storage arc policy of the app role which the cell belongs to,
+    /// if one has been set.
+    #[serde(default)]
+    pub arc_policy: Option<ArcPolicy>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, SerializedBytes)]
@@ -2,7 +2,9 @@
 //! This module is used to configure the conductor
 
 use crate::conductor::process::ERROR_CODE;
+use holo_hash::{AgentPubKeyB64, DnaHashB64};
 use holochain_types::prelude::DbSyncStrategy;
+use holochain_types::prelude::WasmMeteringLimits;
 use kitsune_p2p_types::config::{KitsuneP2pConfig, KitsuneP2pTuningParams};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
@@ -25,6 +27,7 @@ pub use dpki_config::DpkiConfig;
 pub use error::*;
 pub use keystore_config::KeystoreConfig;
 //pub use signal_config::SignalConfig;
+use std::collections::HashMap;
 use std::path::Path;
 
 use crate::config::conductor::paths::DataRootPath;
@@ -58,6 +61,24 @@ pub struct ConductorConfig {
     #[serde(default)]
     pub network: KitsuneP2pConfig,
 
+    /// Override the network `gossip_strategy` tuning param for specific DNAs.
+    /// This can be used, for instance, to run "full-sync" gossip for a DNA
+    /// which is only ever used on a small private network.
+    #[serde(default)]
+    pub gossip_strategy_overrides: HashMap<DnaHashB64, String>,
+
+    /// If set, only bundles with a valid signature by one of these keys can be
+    /// used to install or update an app, register a DNA, or update coordinator
+    /// zomes. The signature may be embedded into the bundle or, for a bundle
+    /// read from a path, be in a detached `.sig` file next to it.
+    ///
+    /// A bundle sent as data rather than as a path has no stored bytes, so its
+    /// signature must be over its manifest and resources as this conductor
+    /// encodes them. DNAs registered from the hash of an already registered
+    /// DNA need no signature.
+    #[serde(default)]
+    pub trusted_app_signers: Option<Vec<AgentPubKeyB64>>,
+
     /// Optional specification of Chain Head Coordination service URL.
     /// If set, each cell's commit workflow will include synchronizing with the specified CHC service.
     /// If you don't know what this means, leave this setting alone (as `None`)
@@ -145,6 +166,19 @@ pub struct ConductorTuningParams {
     /// The delay between retries of sys validation when there are missing dependencies waiting to be found on the DHT.
     /// Default: 10 seconds
     pub sys_validation_retry_delay: Option<std::time::Duration>,
+    /// The number of wasm metering points which a zome function call may use
+    /// before it is trapped. This also applies to callbacks other than
+    /// `validate` and `init`.
+    /// Default: 100 billion
+    pub zome_call_metering_limit: Option<u64>,
+    /// The number of wasm metering points which a `validate` callback may use
+    /// before it is trapped.
+    /// Default: 100 billion
+    pub validation_metering_limit: Option<u64>,
+    /// The number of wasm metering points which an `init` callback may use
+    /// before it is trapped.
+    /// Default: 100 billion
+    pub init_metering_limit: Option<u64>,
 }
 
 impl ConductorTuningParams {
@@ -152,6 +186,9 @@ impl ConductorTuningParams {
     pub fn new() -> Self {
         Self {
             sys_validation_retry_delay: None,
+            zome_call_metering_limit: None,
+            validation_metering_limit: None,
+            init_metering_limit: None,
         }
     }
 
@@ -160,13 +197,28 @@ impl ConductorTuningParams {
         self.sys_validation_retry_delay
             .unwrap_or_else(|| std::time::Duration::from_secs(10))
     }
+
+    /// Get the wasm metering limits for each kind of call, using the default
+    /// for any which are not set.
+    pub fn wasm_metering_limits(&self) -> WasmMeteringLimits {
+        let default = WasmMeteringLimits::default();
+        WasmMeteringLimits {
+            zome_call: self.zome_call_metering_limit.unwrap_or(default.zome_call),
+            validation: self.validation_metering_limit.unwrap_or(default.validation),
+            init: self.init_metering_limit.unwrap_or(default.init),
+        }
+    }
 }
 
 impl Default for ConductorTuningParams {
     fn default() -> Self {
         let empty = Self::new();
+        let wasm_metering_limits = empty.wasm_metering_limits();
         Self {
             sys_validation_retry_delay: Some(empty.sys_validation_retry_delay()),
+            zome_call_metering_limit: Some(wasm_metering_limits.zome_call),
+            validation_metering_limit: Some(wasm_metering_limits.validation),
+            init_metering_limit: Some(wasm_metering_limits.init),
         }
     }
 }
@@ -215,10 +267,12 @@ mod tests {
                 dpki: None,
                 keystore: KeystoreConfig::DangerTestKeystore,
                 admin_interfaces: None,
+                gossip_strategy_overrides: HashMap::new(),
                 db_sync_strategy: DbSyncStrategy::default(),
                 #[cfg(feature = "chc")]
                 chc_url: None,
                 tuning_params: None,
+                trusted_app_signers: None,
             }
         );
     }
@@ -263,6 +317,9 @@ mod tests {
         tx5_max_ephemeral_udp_port: 40255
       network_type: quic_bootstrap
 
+    gossip_strategy_overrides:
+      uhC0k29vb29vb29vb29vb29vb29vb29vb29vb29vb29vb29uTp5Iv: full-sync
+
     db_sync_strategy: Fast
     "#;
         let result: ConductorConfigResult<ConductorConfig> = config_from_yaml(yaml);
@@ -298,10 +355,16 @@ mod tests {
                     driver: InterfaceDriver::Websocket { port: 1234 }
                 }]),
                 network: network_config,
+                gossip_strategy_overrides: [(
+                    holo_hash::DnaHash::from_raw_32(vec![0xdb; 32]).into(),
+                    "full-sync".to_string()
+                )]
+                .into(),
                 db_sync_strategy: DbSyncStrategy::Fast,
                 #[cfg(feature = "chc")]
                 chc_url: None,
                 tuning_params: None,
+                trusted_app_signers: None,
             }
         );
     }
@@ -328,11 +391,26 @@ mod tests {
                     connection_url: url2::url2!("unix:///var/run/lair-keystore/socket?k=EcRDnP3xDIZ9Rk_1E-egPE0mGZi5CcszeRxVkb2QXXQ"),
                 },
                 admin_interfaces: None,
+                gossip_strategy_overrides: HashMap::new(),
                 db_sync_strategy: DbSyncStrategy::Fast,
                 #[cfg(feature = "chc")]
                 chc_url: None,
                 tuning_params: None,
+                trusted_app_signers: None,
             }
         );
     }
+
+    #[test]
+    fn test_config_wasm_metering_limits() {
+        let yaml = r#"---
+    tuning_params:
+      validation_metering_limit: 1000
+    "#;
+        let config: ConductorConfig = config_from_yaml(yaml).unwrap();
+        let limits = config.conductor_tuning_params().wasm_metering_limits();
+        assert_eq!(limits.validation, 1000);
+        assert_eq!(limits.zome_call, WasmMeteringLimits::default().zome_call);
+        assert_eq!(limits.init, WasmMeteringLimits::default().init);
+    }
 }
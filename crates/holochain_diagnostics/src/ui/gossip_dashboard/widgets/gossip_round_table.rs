@@ -135,6 +135,10 @@ fn render_gossip_metric_row<Id: Display>(
             Cell::from("H".to_string()),
             Style::default().fg(Color::Blue),
         ),
+        GossipModuleType::FullSync => (
+            Cell::from("F".to_string()),
+            Style::default().fg(Color::Magenta),
+        ),
     };
     let err = Cell::from(if error { "E" } else { " " });
     let mut cells = vec![
@@ -8,6 +8,7 @@ use crate::LinkType;
 use crate::Timestamp;
 use holo_hash::DnaHash;
 use holochain_serialized_bytes::prelude::*;
+use std::collections::HashSet;
 use std::time::Duration;
 
 #[cfg(test)]
@@ -223,6 +224,10 @@ pub struct DnaInfoV2 {
     // In ZomeIndex order as to match corresponding `ZomeInfo` for each.
     /// The zomes in this DNA.
     pub zome_names: Vec<ZomeName>,
+    /// The hashes of previous versions of this DNA which this DNA supersedes,
+    /// as declared in the `lineage` of its manifest.
+    #[serde(default)]
+    pub lineage: HashSet<DnaHash>,
 }
 
 /// Convenience alias to the latest `DnaInfoN`.
@@ -60,6 +60,22 @@ pub trait HolochainP2pDnaT: Send + Sync {
         expires_at: Timestamp,
     ) -> actor::HolochainP2pResult<SerializedBytes>;
 
+    /// Invoke a zome function on a remote node like `call_remote`, sending the
+    /// payload and receiving the response in chunks so they can be large.
+    #[allow(clippy::too_many_arguments)]
+    async fn call_remote_streamed(
+        &self,
+        from_agent: AgentPubKey,
+        from_signature: Signature,
+        to_agent: AgentPubKey,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        cap_secret: Option<CapSecret>,
+        payload: ExternIO,
+        nonce: Nonce256Bits,
+        expires_at: Timestamp,
+    ) -> actor::HolochainP2pResult<SerializedBytes>;
+
     /// Invoke a zome function on a remote node (if you have been granted the capability).
     /// This is a fire-and-forget operation, a best effort will be made
     /// to forward the signal, but if the conductor network is overworked
@@ -146,6 +162,10 @@ pub trait HolochainP2pDnaT: Send + Sync {
         receipts: ValidationReceiptBundle,
     ) -> actor::HolochainP2pResult<()>;
 
+    /// A local zome call is waiting on these ops, so fetch any of them which
+    /// are already in the fetch pool ahead of the rest of the backlog.
+    async fn prioritize_fetch(&self, op_hashes: Vec<DhtOpHash>) -> actor::HolochainP2pResult<()>;
+
     /// Check if an agent is an authority for a hash.
     async fn authority_for_hash(
         &self,
@@ -195,6 +215,19 @@ mockall::mock! {
             expires_at: Timestamp,
         ) -> actor::HolochainP2pResult<SerializedBytes>;
         #[allow(clippy::too_many_arguments)]
+        async fn call_remote_streamed(
+            &self,
+            from_agent: AgentPubKey,
+            from_signature: Signature,
+            to_agent: AgentPubKey,
+            zome_name: ZomeName,
+            fn_name: FunctionName,
+            cap_secret: Option<CapSecret>,
+            payload: ExternIO,
+            nonce: Nonce256Bits,
+            expires_at: Timestamp,
+        ) -> actor::HolochainP2pResult<SerializedBytes>;
+        #[allow(clippy::too_many_arguments)]
         async fn send_remote_signal(
             &self,
             from_agent: AgentPubKey,
@@ -258,6 +291,7 @@ mockall::mock! {
             to_agent: AgentPubKey,
             receipts: ValidationReceiptBundle,
         ) -> actor::HolochainP2pResult<()>;
+        async fn prioritize_fetch(&self, op_hashes: Vec<DhtOpHash>) -> actor::HolochainP2pResult<()>;
         async fn authority_for_hash(
             &self,
             basis: holo_hash::OpBasis,
@@ -345,6 +379,36 @@ impl HolochainP2pDnaT for HolochainP2pDna {
             .await
     }
 
+    /// Invoke a zome function on a remote node like `call_remote`, sending the
+    /// payload and receiving the response in chunks so they can be large.
+    async fn call_remote_streamed(
+        &self,
+        from_agent: AgentPubKey,
+        from_signature: Signature,
+        to_agent: AgentPubKey,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        cap_secret: Option<CapSecret>,
+        payload: ExternIO,
+        nonce: Nonce256Bits,
+        expires_at: Timestamp,
+    ) -> actor::HolochainP2pResult<SerializedBytes> {
+        self.sender
+            .call_remote_streamed(
+                (*self.dna_hash).clone(),
+                from_agent,
+                from_signature,
+                to_agent,
+                zome_name,
+                fn_name,
+                cap_secret,
+                payload,
+                nonce,
+                expires_at,
+            )
+            .await
+    }
+
     /// Invoke a zome function on a remote node (if you have been granted the capability).
     /// This is a fire-and-forget operation, a best effort will be made
     /// to forward the signal, but if the conductor network is overworked
@@ -489,6 +553,12 @@ impl HolochainP2pDnaT for HolochainP2pDna {
             .await
     }
 
+    async fn prioritize_fetch(&self, op_hashes: Vec<DhtOpHash>) -> actor::HolochainP2pResult<()> {
+        self.sender
+            .prioritize_fetch((*self.dna_hash).clone(), op_hashes)
+            .await
+    }
+
     /// Check if an agent is an authority for a hash.
     async fn authority_for_hash(
         &self,
@@ -4,6 +4,8 @@ use crate::event::*;
 mod actor;
 use actor::*;
 
+mod stream;
+
 /// Spawn a new HolochainP2p actor.
 /// Conductor will call this on initialization.
 pub async fn spawn_holochain_p2p(
@@ -14,6 +14,8 @@ use kitsune_p2p_fetch::FetchContext;
 
 use crate::types::AgentPubKeyExt;
 
+use super::stream::*;
+
 use ghost_actor::dependencies::tracing;
 use ghost_actor::dependencies::tracing_futures::Instrument;
 
@@ -333,6 +335,7 @@ pub(crate) struct HolochainP2pActor {
     evt_sender: WrapEvtSender,
     kitsune_p2p: ghost_actor::GhostSender<kitsune_p2p::actor::KitsuneP2p>,
     host: kitsune_p2p::HostApi,
+    streams: StreamBuffers,
 }
 
 impl ghost_actor::GhostControlHandler for HolochainP2pActor {
@@ -367,6 +370,7 @@ impl HolochainP2pActor {
             evt_sender: WrapEvtSender(evt_sender),
             kitsune_p2p,
             host,
+            streams: StreamBuffers::default(),
         })
     }
 
@@ -742,6 +746,71 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
                     None => Err(HolochainP2pError::RoutingAgentError(to_agent).into()),
                 }
             }
+            crate::wire::WireMessage::CallRemoteStreamChunk {
+                stream_id,
+                from_agent,
+                offset,
+                total_len,
+                data,
+            } => {
+                let received = self.streams.receive_chunk(
+                    &from_agent,
+                    &to_agent,
+                    &stream_id,
+                    offset,
+                    total_len,
+                    data,
+                )?;
+                let res = encode_reply(crate::wire::WireStreamAck { received })?;
+                Ok(async move { Ok(res) }.boxed().into())
+            }
+            crate::wire::WireMessage::CallRemoteStreamed {
+                stream_id,
+                zome_name,
+                fn_name,
+                from_agent,
+                signature,
+                to_agent: callee,
+                cap_secret,
+                nonce,
+                expires_at,
+            } => {
+                let data = self
+                    .streams
+                    .take_payload(&from_agent, &to_agent, &stream_id)?;
+                let fut = self.handle_incoming_call_remote(
+                    space,
+                    from_agent.clone(),
+                    signature,
+                    callee,
+                    zome_name,
+                    fn_name,
+                    cap_secret,
+                    data,
+                    *nonce,
+                    expires_at,
+                )?;
+                let streams = self.streams.clone();
+                Ok(async move {
+                    let response = fut.await?;
+                    let chunk =
+                        streams.put_response(&from_agent, &to_agent, &stream_id, response)?;
+                    Ok(encode_reply(chunk)?)
+                }
+                .boxed()
+                .into())
+            }
+            crate::wire::WireMessage::CallRemoteStreamResponse {
+                stream_id,
+                from_agent,
+                offset,
+            } => {
+                let chunk =
+                    self.streams
+                        .response_chunk(&from_agent, &to_agent, &stream_id, offset)?;
+                let res = encode_reply(chunk)?;
+                Ok(async move { Ok(res) }.boxed().into())
+            }
             crate::wire::WireMessage::Get { dht_hash, options } => {
                 self.handle_incoming_get(space, to_agent, dht_hash, options)
             }
@@ -768,6 +837,13 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
                 )
                     .into())
             }
+            crate::wire::WireMessage::CallRemoteStreamDone { .. } => {
+                Err(HolochainP2pError::invalid_p2p_message(
+                    "invalid: the end of a stream is a notification rather than a request"
+                        .to_string(),
+                )
+                .into())
+            }
             // holochain_p2p only broadcasts this message.
             crate::wire::WireMessage::CountersigningSessionNegotiation { .. }
             | crate::wire::WireMessage::PublishCountersign { .. } => {
@@ -800,7 +876,10 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
             | crate::wire::WireMessage::GetLinks { .. }
             | crate::wire::WireMessage::CountLinks { .. }
             | crate::wire::WireMessage::GetAgentActivity { .. }
-            | crate::wire::WireMessage::MustGetAgentActivity { .. } => {
+            | crate::wire::WireMessage::MustGetAgentActivity { .. }
+            | crate::wire::WireMessage::CallRemoteStreamChunk { .. }
+            | crate::wire::WireMessage::CallRemoteStreamed { .. }
+            | crate::wire::WireMessage::CallRemoteStreamResponse { .. } => {
                 Err(HolochainP2pError::invalid_p2p_message(
                     "invalid call type message in a notify".to_string(),
                 )
@@ -860,6 +939,13 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
             WireMessage::ValidationReceipts { receipts } => {
                 self.handle_incoming_validation_receipt(space, to_agent, receipts)
             }
+            crate::wire::WireMessage::CallRemoteStreamDone {
+                stream_id,
+                from_agent,
+            } => {
+                self.streams.release(&from_agent, &to_agent, &stream_id);
+                Ok(async move { Ok(()) }.boxed().into())
+            }
             crate::wire::WireMessage::CountersigningSessionNegotiation { message } => {
                 self.handle_incoming_countersigning_session_negotiation(space, to_agent, message)
             }
@@ -1060,6 +1146,152 @@ impl HolochainP2pHandler for HolochainP2pActor {
         .into())
     }
 
+    /// Dispatch an outgoing streamed remote call.
+    #[tracing::instrument(skip(self, payload), level = "trace")]
+    fn handle_call_remote_streamed(
+        &mut self,
+        dna_hash: DnaHash,
+        from_agent: AgentPubKey,
+        signature: Signature,
+        to_agent: AgentPubKey,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        cap_secret: Option<CapSecret>,
+        payload: ExternIO,
+        nonce: Nonce256Bits,
+        expires_at: Timestamp,
+    ) -> HolochainP2pHandlerResult<SerializedBytes> {
+        let payload = payload.into_vec();
+        let total_len = payload.len() as u64;
+        if total_len > MAX_STREAM_BYTES {
+            return Err(HolochainP2pError::other(format!(
+                "Streamed payload of {} bytes is larger than the limit of {} bytes",
+                total_len, MAX_STREAM_BYTES
+            )));
+        }
+
+        let space = dna_hash.into_kitsune();
+        let to_agent_kitsune = to_agent.clone().into_kitsune();
+        let stream_id = Nonce256Bits::from(rand::random::<[u8; 32]>());
+
+        let call = crate::wire::WireMessage::call_remote_streamed(
+            stream_id,
+            zome_name,
+            fn_name,
+            from_agent.clone(),
+            signature,
+            to_agent,
+            cap_secret,
+            nonce,
+            expires_at,
+        )
+        .encode()?;
+
+        let timeout = self.config.tuning_params.implicit_timeout();
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        let notify = kitsune_p2p.clone();
+        let (done_space, done_agent) = (space.clone(), to_agent_kitsune.clone());
+        let rpc = move |req: Vec<u8>| {
+            let kitsune_p2p = kitsune_p2p.clone();
+            let space = space.clone();
+            let to_agent_kitsune = to_agent_kitsune.clone();
+            async move {
+                Ok::<_, HolochainP2pError>(
+                    kitsune_p2p
+                        .rpc_single(space, to_agent_kitsune, req, None)
+                        .await?,
+                )
+            }
+        };
+        Ok(async move {
+            // Send the payload, always at least one chunk so the receiver
+            // knows about the stream even if the payload is empty.
+            let mut received = 0;
+            let mut stalled = 0;
+            loop {
+                let end = total_len.min(received + STREAM_CHUNK_BYTES as u64);
+                let req = crate::wire::WireMessage::call_remote_stream_chunk(
+                    stream_id,
+                    from_agent.clone(),
+                    received,
+                    total_len,
+                    payload[received as usize..end as usize].to_vec(),
+                )
+                .encode()?;
+                let ack: crate::wire::WireStreamAck =
+                    decode_reply(with_retries(|| rpc(req.clone())).await?)?;
+                if ack.received > total_len {
+                    return Err(HolochainP2pError::invalid_p2p_message(
+                        "Streamed payload acknowledged beyond its end".to_string(),
+                    ));
+                }
+                // The receiver may have dropped the stream and asked to start
+                // again, but it must make progress eventually.
+                if ack.received > received {
+                    stalled = 0;
+                } else {
+                    stalled += 1;
+                    if stalled > STREAM_RETRIES {
+                        return Err(HolochainP2pError::other(
+                            "Streamed payload is not being received",
+                        ));
+                    }
+                }
+                received = ack.received;
+                if received == total_len {
+                    break;
+                }
+            }
+
+            // Making the call isn't retried, the zome function may not be
+            // safe to run twice.
+            let first: crate::wire::WireStreamChunk = decode_reply(rpc(call).await?)?;
+            let response_len = first.total_len;
+            if response_len > MAX_STREAM_BYTES || first.offset != 0 {
+                return Err(HolochainP2pError::invalid_p2p_message(
+                    "Invalid first chunk of a streamed response".to_string(),
+                ));
+            }
+            let mut response = first.data;
+            while (response.len() as u64) < response_len {
+                let offset = response.len() as u64;
+                let req = crate::wire::WireMessage::call_remote_stream_response(
+                    stream_id,
+                    from_agent.clone(),
+                    offset,
+                )
+                .encode()?;
+                let chunk: crate::wire::WireStreamChunk =
+                    decode_reply(with_retries(|| rpc(req.clone())).await?)?;
+                if chunk.offset != offset
+                    || chunk.total_len != response_len
+                    || chunk.data.is_empty()
+                    || offset + chunk.data.len() as u64 > response_len
+                {
+                    return Err(HolochainP2pError::invalid_p2p_message(
+                        "Invalid chunk of a streamed response".to_string(),
+                    ));
+                }
+                response.extend(chunk.data);
+            }
+            // Only responses longer than a chunk are kept by the receiver.
+            // If telling it we're done fails the response still expires.
+            if response_len > STREAM_CHUNK_BYTES as u64 {
+                let done = crate::wire::WireMessage::call_remote_stream_done(stream_id, from_agent)
+                    .encode()?;
+                if let Err(e) = notify
+                    .targeted_broadcast(done_space, vec![done_agent], timeout, done, false)
+                    .await
+                {
+                    tracing::debug!(?e, "Failed to release a streamed response");
+                }
+            }
+            Ok(UnsafeBytes::from(response).into())
+        }
+        .boxed()
+        .into())
+    }
+
     /// Dispatch an outgoing signal.
     #[tracing::instrument(skip(self), level = "trace")]
     fn handle_send_remote_signal(
@@ -1129,7 +1361,10 @@ impl HolochainP2pHandler for HolochainP2pActor {
 
         let fetch_context = FetchContext::default()
             .with_request_validation_receipt(request_validation_receipt)
-            .with_countersigning_session(countersigning_session);
+            .with_countersigning_session(countersigning_session)
+            // A countersigning session is waiting on these ops, so have
+            // receivers fetch them ahead of any gossip backlog.
+            .with_priority(countersigning_session);
 
         let kitsune_p2p = self.kitsune_p2p.clone();
         let host = self.host.clone();
@@ -1450,6 +1685,23 @@ impl HolochainP2pHandler for HolochainP2pActor {
         )
     }
 
+    #[tracing::instrument(skip(self), level = "trace")]
+    fn handle_prioritize_fetch(
+        &mut self,
+        dna_hash: DnaHash,
+        op_hashes: Vec<DhtOpHash>,
+    ) -> HolochainP2pHandlerResult<()> {
+        let space = dna_hash.into_kitsune();
+        let op_hashes = op_hashes.into_iter().map(|h| h.into_kitsune()).collect();
+
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        Ok(
+            async move { Ok(kitsune_p2p.prioritize_fetch(space, op_hashes).await?) }
+                .boxed()
+                .into(),
+        )
+    }
+
     #[tracing::instrument(skip(self), level = "trace")]
     fn handle_authority_for_hash(
         &mut self,
@@ -0,0 +1,499 @@
+//! Streamed remote calls send their payload and fetch their response in
+//! chunks, each a separate request within the usual limits of a message.
+//!
+//! The caller waits for each chunk of the payload to be acknowledged before
+//! sending the next, so a busy receiver slows the transfer down, and a chunk
+//! that failed is retried from wherever the receiver got to. Once the whole
+//! response has been fetched the caller tells the receiver it is done, so the
+//! response doesn't have to wait to expire before its bytes are freed.
+
+use crate::actor::*;
+use crate::wire::WireStreamChunk;
+use crate::*;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Decode the reply to a message of a streamed remote call.
+pub(crate) fn decode_reply<T>(reply: Vec<u8>) -> HolochainP2pResult<T>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    Ok(SerializedBytes::from(UnsafeBytes::from(reply)).try_into()?)
+}
+
+/// Encode the reply to a message of a streamed remote call.
+pub(crate) fn encode_reply<T>(reply: T) -> HolochainP2pResult<Vec<u8>>
+where
+    T: TryInto<SerializedBytes, Error = SerializedBytesError>,
+{
+    Ok(UnsafeBytes::from(reply.try_into()?).into())
+}
+
+/// Send a message of a streamed remote call, retrying with a backoff
+/// if it fails.
+/// Only messages that can safely be handled twice can be retried.
+pub(crate) async fn with_retries<F, Fut, T>(mut send: F) -> HolochainP2pResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = HolochainP2pResult<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match send().await {
+            Err(e) if attempt < STREAM_RETRIES => {
+                attempt += 1;
+                tracing::debug!(?e, attempt, "Retrying a streamed remote call message");
+                tokio::time::sleep(Duration::from_millis(100 << attempt)).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// A stream is identified by the calling agent, the agent being called
+/// and the random id chosen by the caller.
+type StreamKey = (AgentPubKey, AgentPubKey, [u8; 32]);
+
+enum StreamBuffer {
+    /// The payload is still being received.
+    Receiving { total_len: u64, data: Vec<u8> },
+    /// The call has been made and the response is waiting to be fetched.
+    Responding { data: Vec<u8> },
+}
+
+impl StreamBuffer {
+    /// The bytes this buffer holds.
+    /// Only bytes that have actually been received are counted, as the
+    /// declared length of a payload can't be trusted.
+    fn buffered_bytes(&self) -> u64 {
+        match self {
+            Self::Receiving { data, .. } | Self::Responding { data } => data.len() as u64,
+        }
+    }
+}
+
+/// The streamed remote calls being received by the agents of this conductor.
+#[derive(Clone)]
+pub(crate) struct StreamBuffers {
+    buffers: Arc<Mutex<HashMap<StreamKey, (Instant, StreamBuffer)>>>,
+    max_buffered_bytes: u64,
+}
+
+impl Default for StreamBuffers {
+    fn default() -> Self {
+        Self::new(MAX_STREAM_BUFFER_BYTES)
+    }
+}
+
+impl StreamBuffers {
+    /// Buffer at most `max_buffered_bytes` of all streams.
+    fn new(max_buffered_bytes: u64) -> Self {
+        Self {
+            buffers: Default::default(),
+            max_buffered_bytes,
+        }
+    }
+
+    fn key(
+        from_agent: &AgentPubKey,
+        to_agent: &AgentPubKey,
+        stream_id: &Nonce256Bits,
+    ) -> StreamKey {
+        (from_agent.clone(), to_agent.clone(), stream_id.into_inner())
+    }
+
+    /// Add a chunk of a payload to its stream, returning how many bytes
+    /// of the payload have been received so far.
+    ///
+    /// A chunk which doesn't start where the received bytes end is ignored,
+    /// so the sender can resume from the returned count after a failure.
+    pub fn receive_chunk(
+        &self,
+        from_agent: &AgentPubKey,
+        to_agent: &AgentPubKey,
+        stream_id: &Nonce256Bits,
+        offset: u64,
+        total_len: u64,
+        chunk: Vec<u8>,
+    ) -> HolochainP2pResult<u64> {
+        if total_len > MAX_STREAM_BYTES {
+            return Err(HolochainP2pError::other(format!(
+                "Streamed payload of {} bytes is larger than the limit of {} bytes",
+                total_len, MAX_STREAM_BYTES
+            )));
+        }
+        let key = Self::key(from_agent, to_agent, stream_id);
+        let mut buffers = self.buffers.lock().unwrap();
+        prune_expired(&mut buffers);
+        if !buffers.contains_key(&key) {
+            if offset != 0 {
+                // The stream has expired, the sender must start again.
+                return Ok(0);
+            }
+            buffers.insert(
+                key.clone(),
+                (
+                    Instant::now(),
+                    StreamBuffer::Receiving {
+                        total_len,
+                        data: Vec::new(),
+                    },
+                ),
+            );
+        }
+        let buffered: u64 = buffers.values().map(|(_, b)| b.buffered_bytes()).sum();
+        match buffers.get_mut(&key) {
+            Some((
+                touched,
+                StreamBuffer::Receiving {
+                    total_len: len,
+                    data,
+                },
+            )) => {
+                if *len != total_len {
+                    return Err(HolochainP2pError::other(
+                        "Streamed payload length changed during the transfer",
+                    ));
+                }
+                if offset == data.len() as u64 {
+                    if offset + chunk.len() as u64 > total_len {
+                        return Err(HolochainP2pError::other(
+                            "Streamed payload is longer than its declared length",
+                        ));
+                    }
+                    if buffered + chunk.len() as u64 > self.max_buffered_bytes {
+                        return Err(HolochainP2pError::other(
+                            "Too many streamed bytes are being buffered, try again later",
+                        ));
+                    }
+                    data.extend(chunk);
+                }
+                *touched = Instant::now();
+                Ok(data.len() as u64)
+            }
+            _ => Err(HolochainP2pError::other(
+                "Streamed payload chunk received after the call was made",
+            )),
+        }
+    }
+
+    /// Take the complete payload of a stream to make the call with.
+    pub fn take_payload(
+        &self,
+        from_agent: &AgentPubKey,
+        to_agent: &AgentPubKey,
+        stream_id: &Nonce256Bits,
+    ) -> HolochainP2pResult<Vec<u8>> {
+        let key = Self::key(from_agent, to_agent, stream_id);
+        let mut buffers = self.buffers.lock().unwrap();
+        prune_expired(&mut buffers);
+        match buffers.remove(&key) {
+            Some((_, StreamBuffer::Receiving { total_len, data }))
+                if data.len() as u64 == total_len =>
+            {
+                Ok(data)
+            }
+            Some(buffer) => {
+                buffers.insert(key, buffer);
+                Err(HolochainP2pError::other(
+                    "Streamed call made before its payload was complete",
+                ))
+            }
+            None => Err(HolochainP2pError::other("Unknown or expired stream")),
+        }
+    }
+
+    /// Keep the response to a streamed call and return its first chunk.
+    ///
+    /// A response that fits in the first chunk isn't kept, as the call
+    /// itself is never retried.
+    pub fn put_response(
+        &self,
+        from_agent: &AgentPubKey,
+        to_agent: &AgentPubKey,
+        stream_id: &Nonce256Bits,
+        response: Vec<u8>,
+    ) -> HolochainP2pResult<WireStreamChunk> {
+        let response_len = response.len() as u64;
+        if response_len > MAX_STREAM_BYTES {
+            return Err(HolochainP2pError::other(format!(
+                "Streamed response of {} bytes is larger than the limit of {} bytes",
+                response_len, MAX_STREAM_BYTES
+            )));
+        }
+        if response.len() <= STREAM_CHUNK_BYTES {
+            return Ok(response_chunk_at(&response, 0));
+        }
+        let mut buffers = self.buffers.lock().unwrap();
+        prune_expired(&mut buffers);
+        let buffered: u64 = buffers.values().map(|(_, b)| b.buffered_bytes()).sum();
+        if buffered + response_len > self.max_buffered_bytes {
+            return Err(HolochainP2pError::other(
+                "Too many streamed bytes are being buffered to keep the response",
+            ));
+        }
+        let first = response_chunk_at(&response, 0);
+        buffers.insert(
+            Self::key(from_agent, to_agent, stream_id),
+            (Instant::now(), StreamBuffer::Responding { data: response }),
+        );
+        Ok(first)
+    }
+
+    /// Get the chunk of a response starting at the offset.
+    /// The response is kept until the caller releases the stream or it has
+    /// been idle for [`STREAM_IDLE_TIMEOUT`], so the caller can fetch any
+    /// chunk again if a reply was lost.
+    pub fn response_chunk(
+        &self,
+        from_agent: &AgentPubKey,
+        to_agent: &AgentPubKey,
+        stream_id: &Nonce256Bits,
+        offset: u64,
+    ) -> HolochainP2pResult<WireStreamChunk> {
+        let key = Self::key(from_agent, to_agent, stream_id);
+        let mut buffers = self.buffers.lock().unwrap();
+        prune_expired(&mut buffers);
+        match buffers.get_mut(&key) {
+            Some((touched, StreamBuffer::Responding { data })) => {
+                if offset > data.len() as u64 {
+                    return Err(HolochainP2pError::other(
+                        "Streamed response offset is past its end",
+                    ));
+                }
+                *touched = Instant::now();
+                Ok(response_chunk_at(data, offset))
+            }
+            _ => Err(HolochainP2pError::other("Unknown or expired stream")),
+        }
+    }
+
+    /// Drop a stream once the caller has fetched all of its response.
+    pub fn release(
+        &self,
+        from_agent: &AgentPubKey,
+        to_agent: &AgentPubKey,
+        stream_id: &Nonce256Bits,
+    ) {
+        let key = Self::key(from_agent, to_agent, stream_id);
+        let mut buffers = self.buffers.lock().unwrap();
+        if let Some((_, StreamBuffer::Responding { .. })) = buffers.get(&key) {
+            buffers.remove(&key);
+        }
+        prune_expired(&mut buffers);
+    }
+}
+
+/// The chunk of a response starting at an offset within it.
+fn response_chunk_at(data: &[u8], offset: u64) -> WireStreamChunk {
+    let total_len = data.len() as u64;
+    let end = (offset + STREAM_CHUNK_BYTES as u64).min(total_len);
+    WireStreamChunk {
+        total_len,
+        offset,
+        data: data[offset as usize..end as usize].to_vec(),
+    }
+}
+
+fn prune_expired(buffers: &mut HashMap<StreamKey, (Instant, StreamBuffer)>) {
+    buffers.retain(|_, (touched, _)| touched.elapsed() < STREAM_IDLE_TIMEOUT);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::fixt::prelude::*;
+    use holo_hash::fixt::AgentPubKeyFixturator;
+
+    #[test]
+    fn stream_payload_and_response_round_trip() {
+        let buffers = StreamBuffers::default();
+        let (alice, bob) = (fixt!(AgentPubKey), fixt!(AgentPubKey));
+        let id = Nonce256Bits::from([1; 32]);
+        let payload: Vec<u8> = (0..10).collect();
+
+        // Chunks that don't follow on from the received bytes are ignored.
+        assert_eq!(
+            buffers
+                .receive_chunk(&alice, &bob, &id, 0, 10, payload[..4].to_vec())
+                .unwrap(),
+            4
+        );
+        assert_eq!(
+            buffers
+                .receive_chunk(&alice, &bob, &id, 0, 10, payload[..4].to_vec())
+                .unwrap(),
+            4
+        );
+        assert_eq!(
+            buffers
+                .receive_chunk(&alice, &bob, &id, 8, 10, payload[8..].to_vec())
+                .unwrap(),
+            4
+        );
+        // The call can't be made until the payload is complete.
+        assert!(buffers.take_payload(&alice, &bob, &id).is_err());
+        assert_eq!(
+            buffers
+                .receive_chunk(&alice, &bob, &id, 4, 10, payload[4..].to_vec())
+                .unwrap(),
+            10
+        );
+        // Streams from another agent are kept apart.
+        assert!(buffers.take_payload(&bob, &alice, &id).is_err());
+        assert_eq!(buffers.take_payload(&alice, &bob, &id).unwrap(), payload);
+
+        let response = vec![7; STREAM_CHUNK_BYTES + 1];
+        let first = buffers
+            .put_response(&alice, &bob, &id, response.clone())
+            .unwrap();
+        assert_eq!(first.total_len, response.len() as u64);
+        assert_eq!(first.data.len(), STREAM_CHUNK_BYTES);
+        // Any chunk can be fetched again, even after the last one was fetched,
+        // in case a reply was lost.
+        let again = buffers.response_chunk(&alice, &bob, &id, 0).unwrap();
+        assert_eq!(again.data, first.data);
+        let last = buffers
+            .response_chunk(&alice, &bob, &id, STREAM_CHUNK_BYTES as u64)
+            .unwrap();
+        assert_eq!(last.data, vec![7]);
+        let last_again = buffers
+            .response_chunk(&alice, &bob, &id, STREAM_CHUNK_BYTES as u64)
+            .unwrap();
+        assert_eq!(last_again.data, last.data);
+        assert!(buffers
+            .response_chunk(&bob, &alice, &id, STREAM_CHUNK_BYTES as u64)
+            .is_err());
+
+        // Only the caller releasing its own stream drops the response.
+        buffers.release(&bob, &alice, &id);
+        assert!(buffers.response_chunk(&alice, &bob, &id, 0).is_ok());
+        buffers.release(&alice, &bob, &id);
+        assert!(buffers.response_chunk(&alice, &bob, &id, 0).is_err());
+
+        // A response that fits in the first chunk isn't kept.
+        let id = Nonce256Bits::from([2; 32]);
+        let first = buffers.put_response(&alice, &bob, &id, vec![7; 3]).unwrap();
+        assert_eq!(first.total_len, 3);
+        assert_eq!(first.data, vec![7; 3]);
+        assert!(buffers.response_chunk(&alice, &bob, &id, 0).is_err());
+    }
+
+    #[test]
+    fn stream_limits() {
+        let buffers = StreamBuffers::default();
+        let (alice, bob) = (fixt!(AgentPubKey), fixt!(AgentPubKey));
+
+        // An unknown stream can only be started from the beginning.
+        assert_eq!(
+            buffers
+                .receive_chunk(
+                    &alice,
+                    &bob,
+                    &Nonce256Bits::from([1; 32]),
+                    4,
+                    10,
+                    vec![0; 4]
+                )
+                .unwrap(),
+            0
+        );
+        assert!(buffers
+            .receive_chunk(
+                &alice,
+                &bob,
+                &Nonce256Bits::from([2; 32]),
+                0,
+                MAX_STREAM_BYTES + 1,
+                vec![]
+            )
+            .is_err());
+        // Payloads can't grow past their declared length.
+        assert!(buffers
+            .receive_chunk(&alice, &bob, &Nonce256Bits::from([3; 32]), 0, 2, vec![0; 3])
+            .is_err());
+
+        // Declaring large payloads doesn't use up the buffer, only the bytes
+        // actually received count towards it.
+        let buffers = StreamBuffers::new(10);
+        for stream in 0..3 {
+            assert_eq!(
+                buffers
+                    .receive_chunk(
+                        &alice,
+                        &bob,
+                        &Nonce256Bits::from([10 + stream; 32]),
+                        0,
+                        MAX_STREAM_BYTES,
+                        vec![0; 4],
+                    )
+                    .is_ok(),
+                stream < 2
+            );
+        }
+        // Chunks that would take the buffered bytes over the limit are
+        // refused, while those that fit are still accepted.
+        assert!(buffers
+            .receive_chunk(
+                &alice,
+                &bob,
+                &Nonce256Bits::from([10; 32]),
+                4,
+                MAX_STREAM_BYTES,
+                vec![0; 3]
+            )
+            .is_err());
+        assert_eq!(
+            buffers
+                .receive_chunk(
+                    &alice,
+                    &bob,
+                    &Nonce256Bits::from([10; 32]),
+                    4,
+                    MAX_STREAM_BYTES,
+                    vec![0; 2]
+                )
+                .unwrap(),
+            6
+        );
+
+        // Kept responses count towards the buffered bytes too.
+        let buffers = StreamBuffers::new(2 * STREAM_CHUNK_BYTES as u64);
+        assert!(buffers
+            .put_response(
+                &alice,
+                &bob,
+                &Nonce256Bits::from([20; 32]),
+                vec![0; STREAM_CHUNK_BYTES + 1]
+            )
+            .is_ok());
+        assert!(buffers
+            .put_response(
+                &alice,
+                &bob,
+                &Nonce256Bits::from([21; 32]),
+                vec![0; STREAM_CHUNK_BYTES + 1]
+            )
+            .is_err());
+        buffers.release(&alice, &bob, &Nonce256Bits::from([20; 32]));
+        assert!(buffers
+            .put_response(
+                &alice,
+                &bob,
+                &Nonce256Bits::from([21; 32]),
+                vec![0; STREAM_CHUNK_BYTES + 1]
+            )
+            .is_ok());
+        assert!(StreamBuffers::default()
+            .put_response(
+                &alice,
+                &bob,
+                &Nonce256Bits::from([22; 32]),
+                vec![0; MAX_STREAM_BYTES as usize + 1]
+            )
+            .is_err());
+    }
+}
@@ -48,6 +48,22 @@ impl HolochainP2pHandler for StubNetwork {
         Err("stub".into())
     }
 
+    fn handle_call_remote_streamed(
+        &mut self,
+        dna_hash: DnaHash,
+        from_agent: AgentPubKey,
+        signature: Signature,
+        to_agent: AgentPubKey,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        cap_secret: Option<CapSecret>,
+        payload: ExternIO,
+        nonce: Nonce256Bits,
+        expires_at: Timestamp,
+    ) -> HolochainP2pHandlerResult<SerializedBytes> {
+        Err("stub".into())
+    }
+
     fn handle_send_remote_signal(
         &mut self,
         dna_hash: DnaHash,
@@ -154,6 +170,14 @@ impl HolochainP2pHandler for StubNetwork {
         Err("stub".into())
     }
 
+    fn handle_prioritize_fetch(
+        &mut self,
+        dna_hash: DnaHash,
+        op_hashes: Vec<DhtOpHash>,
+    ) -> HolochainP2pHandlerResult<()> {
+        Err("stub".into())
+    }
+
     fn handle_authority_for_hash(
         &mut self,
         dna_hash: DnaHash,
@@ -346,6 +370,94 @@ mod tests {
         r_task.await.unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_call_remote_streamed_workflow() {
+        let (dna, a1, a2, _) = test_setup();
+        let keystore = test_keystore();
+
+        let (p2p, mut evt) = spawn_holochain_p2p(
+            KitsuneP2pConfig::default(),
+            TlsConfig::new_ephemeral().await.unwrap(),
+            kitsune_p2p::HostStub::new(),
+        )
+        .await
+        .unwrap();
+
+        let r_task = tokio::task::spawn(async move {
+            use tokio_stream::StreamExt;
+            while let Some(evt) = evt.next().await {
+                use crate::types::event::HolochainP2pEvent::*;
+                match evt {
+                    CallRemote {
+                        payload, respond, ..
+                    } => {
+                        // Echo the payload back so the response is streamed too.
+                        let res = payload.into_vec();
+                        respond.r(Ok(async move { Ok(UnsafeBytes::from(res).into()) }
+                            .boxed()
+                            .into()));
+                    }
+                    SignNetworkData { respond, .. } => {
+                        respond.r(Ok(async move { Ok([0; 64].into()) }.boxed().into()));
+                    }
+                    PutAgentInfoSigned { respond, .. } => {
+                        respond.r(Ok(async move { Ok(()) }.boxed().into()));
+                    }
+                    QueryPeerDensity { respond, .. } => {
+                        let view = test_peer_view();
+                        respond.r(Ok(async move { Ok(view) }.boxed().into()));
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        p2p.join(dna.clone(), a1.clone(), None, None).await.unwrap();
+        p2p.join(dna.clone(), a2.clone(), None, None).await.unwrap();
+
+        let zome_name: ZomeName = "".into();
+        let fn_name: FunctionName = "".into();
+        let nonce = Nonce256Bits::from([0; 32]);
+        let cap_secret = None;
+        let blob: Vec<u8> = (0..3 * crate::actor::STREAM_CHUNK_BYTES + 17)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let payload = ExternIO::from(blob.clone());
+        let expires_at = (Timestamp::now() + std::time::Duration::from_secs(10)).unwrap();
+
+        let signature = a1
+            .sign_raw(
+                &keystore,
+                ZomeCallUnsigned {
+                    provenance: a1.clone(),
+                    cell_id: CellId::new(dna.clone(), a2.clone()),
+                    zome_name: zome_name.clone(),
+                    fn_name: fn_name.clone(),
+                    cap_secret,
+                    payload: payload.clone(),
+                    nonce,
+                    expires_at,
+                }
+                .data_to_sign()
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let res = p2p
+            .call_remote_streamed(
+                dna, a1, signature, a2, zome_name, fn_name, None, payload, nonce, expires_at,
+            )
+            .await
+            .unwrap();
+        let res: Vec<u8> = UnsafeBytes::from(res).into();
+
+        assert_eq!(blob, res);
+
+        p2p.ghost_actor_shutdown().await.unwrap();
+        r_task.await.unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_send_validation_receipt_workflow() {
         let (dna, a1, a2, _) = test_setup();
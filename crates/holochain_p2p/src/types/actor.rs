@@ -27,6 +27,8 @@ pub trait FetchContextExt {
     fn has_countersigning_session(&self) -> bool;
 }
 
+// NB: the high bit is reserved by kitsune to mark priority fetches,
+// see [`kitsune_p2p_fetch::FETCH_CONTEXT_PRIORITY`].
 const FLAG_REQ_VAL_RCPT: u32 = 1 << 0;
 const FLAG_CNTR_SSN: u32 = 1 << 1;
 
@@ -245,6 +247,23 @@ impl Default for GetActivityOptions {
 
 type MaybeDnaHash = Option<DnaHash>;
 
+/// The most bytes sent in a single message of a streamed remote call.
+pub const STREAM_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// The largest payload a streamed remote call can send.
+pub const MAX_STREAM_BYTES: u64 = 128 * 1024 * 1024;
+
+/// The most bytes a conductor will buffer for incoming streamed remote calls.
+/// Chunks are refused until other streams have finished or expired, and the
+/// caller retries them.
+pub const MAX_STREAM_BUFFER_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Streams which haven't been sent to or fetched from for this long are dropped.
+pub const STREAM_IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// How many times a failed message of a streamed remote call is retried.
+pub const STREAM_RETRIES: u32 = 3;
+
 ghost_actor::ghost_chan! {
     /// The HolochainP2pSender struct allows controlling the HolochainP2p
     /// actor instance.
@@ -269,6 +288,22 @@ ghost_actor::ghost_chan! {
             expires_at: Timestamp,
         ) -> SerializedBytes;
 
+        /// Invoke a zome function on a remote node like `call_remote`, but send the
+        /// payload and receive the response in chunks of [`STREAM_CHUNK_BYTES`] so
+        /// they aren't bound by the timeout and size limits of a single message.
+        fn call_remote_streamed(
+            dna_hash: DnaHash,
+            from_agent: AgentPubKey,
+            signature: Signature,
+            to_agent: AgentPubKey,
+            zome_name: ZomeName,
+            fn_name: FunctionName,
+            cap_secret: Option<CapSecret>,
+            payload: ExternIO,
+            nonce: Nonce256Bits,
+            expires_at: Timestamp,
+        ) -> SerializedBytes;
+
         /// Invoke a zome function on a remote node (if you have been granted the capability).
         /// This is a fire-and-forget operation, a best effort will be made
         /// to forward the signal, but if the conductor network is overworked
@@ -353,6 +388,10 @@ ghost_actor::ghost_chan! {
         /// New data has been integrated and is ready for gossiping.
         fn new_integrated_data(dna_hash: DnaHash) -> ();
 
+        /// A local zome call is waiting on these ops, so fetch any of them which
+        /// are already in the fetch pool ahead of the rest of the backlog.
+        fn prioritize_fetch(dna_hash: DnaHash, op_hashes: Vec<DhtOpHash>) -> ();
+
         /// Check if any local agent in this space is an authority for a hash.
         fn authority_for_hash(dna_hash: DnaHash, basis: OpBasis) -> bool;
 
@@ -342,6 +342,9 @@ impl HolochainP2pMockMsg {
             HolochainP2pMockMsg::Wire { msg, .. } => match &msg {
                 crate::wire::WireMessage::CallRemote { .. }
                 | crate::wire::WireMessage::CallRemoteMulti { .. }
+                | crate::wire::WireMessage::CallRemoteStreamChunk { .. }
+                | crate::wire::WireMessage::CallRemoteStreamed { .. }
+                | crate::wire::WireMessage::CallRemoteStreamResponse { .. }
                 | crate::wire::WireMessage::ValidationReceipts { .. }
                 | crate::wire::WireMessage::Get { .. }
                 | crate::wire::WireMessage::GetMeta { .. }
@@ -351,7 +354,8 @@ impl HolochainP2pMockMsg {
                 | crate::wire::WireMessage::PublishCountersign { .. }
                 | crate::wire::WireMessage::MustGetAgentActivity { .. } => next_msg_id().as_req(),
 
-                crate::wire::WireMessage::CountersigningSessionNegotiation { .. } => {
+                crate::wire::WireMessage::CallRemoteStreamDone { .. }
+                | crate::wire::WireMessage::CountersigningSessionNegotiation { .. } => {
                     MsgId::new_notify()
                 }
             },
@@ -372,6 +376,9 @@ impl HolochainP2pMockMsg {
                 let call = match &msg {
                     crate::wire::WireMessage::CallRemote { .. }
                     | crate::wire::WireMessage::CallRemoteMulti { .. }
+                    | crate::wire::WireMessage::CallRemoteStreamChunk { .. }
+                    | crate::wire::WireMessage::CallRemoteStreamed { .. }
+                    | crate::wire::WireMessage::CallRemoteStreamResponse { .. }
                     | crate::wire::WireMessage::ValidationReceipts { .. }
                     | crate::wire::WireMessage::Get { .. }
                     | crate::wire::WireMessage::GetMeta { .. }
@@ -379,7 +386,8 @@ impl HolochainP2pMockMsg {
                     | crate::wire::WireMessage::CountLinks { .. }
                     | crate::wire::WireMessage::GetAgentActivity { .. }
                     | crate::wire::WireMessage::MustGetAgentActivity { .. } => true,
-                    crate::wire::WireMessage::PublishCountersign { .. }
+                    crate::wire::WireMessage::CallRemoteStreamDone { .. }
+                    | crate::wire::WireMessage::PublishCountersign { .. }
                     | crate::wire::WireMessage::CountersigningSessionNegotiation { .. } => false,
                 };
                 let to_agent = to_agent.to_kitsune();
@@ -488,11 +496,11 @@ impl HolochainP2pMockMsg {
                 use kitsune_p2p::gossip::sharded_gossip::*;
                 use kitsune_p2p_types::codec::Codec;
                 let gossip = match module {
-                    GossipModuleType::ShardedRecent | GossipModuleType::ShardedHistorical => {
-                        GossipProtocol::Sharded(
-                            ShardedGossipWire::decode_ref(data.as_ref()).unwrap().1,
-                        )
-                    }
+                    GossipModuleType::ShardedRecent
+                    | GossipModuleType::ShardedHistorical
+                    | GossipModuleType::FullSync => GossipProtocol::Sharded(
+                        ShardedGossipWire::decode_ref(data.as_ref()).unwrap().1,
+                    ),
                 };
                 let dna = holo_hash::DnaHash::from_kitsune(&space);
                 HolochainP2pMockMsg::Gossip {
@@ -21,6 +21,26 @@ impl WireDhtOpData {
     }
 }
 
+/// The reply to a chunk of a streamed remote call's payload.
+#[derive(Debug, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct WireStreamAck {
+    /// How many bytes of the payload have been received, which is where
+    /// the next chunk must start.
+    pub received: u64,
+}
+
+/// A chunk of the response to a streamed remote call.
+#[derive(Debug, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct WireStreamChunk {
+    /// The length of the whole response.
+    pub total_len: u64,
+    /// Where this chunk starts in the response.
+    pub offset: u64,
+    /// The bytes of this chunk.
+    #[serde(with = "serde_bytes")]
+    pub data: Vec<u8>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, SerializedBytes)]
 #[serde(tag = "type", content = "content")]
 #[allow(missing_docs)]
@@ -48,6 +68,41 @@ pub enum WireMessage {
         nonce: Box<Nonce256Bits>,
         expires_at: Timestamp,
     },
+    /// A chunk of the payload of a streamed remote call.
+    CallRemoteStreamChunk {
+        stream_id: Box<Nonce256Bits>,
+        from_agent: AgentPubKey,
+        offset: u64,
+        total_len: u64,
+        #[serde(with = "serde_bytes")]
+        data: Vec<u8>,
+    },
+    /// Make a streamed remote call with the payload sent in chunks.
+    /// The signature covers the whole payload, so chunks from anyone else
+    /// will fail the call.
+    CallRemoteStreamed {
+        stream_id: Box<Nonce256Bits>,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        from_agent: AgentPubKey,
+        signature: Signature,
+        to_agent: AgentPubKey,
+        cap_secret: Option<CapSecret>,
+        nonce: Box<Nonce256Bits>,
+        expires_at: Timestamp,
+    },
+    /// Fetch a chunk of the response to a streamed remote call.
+    CallRemoteStreamResponse {
+        stream_id: Box<Nonce256Bits>,
+        from_agent: AgentPubKey,
+        offset: u64,
+    },
+    /// Tell the receiver of a streamed remote call that the whole response
+    /// has been fetched, so it can be dropped.
+    CallRemoteStreamDone {
+        stream_id: Box<Nonce256Bits>,
+        from_agent: AgentPubKey,
+    },
     ValidationReceipts {
         receipts: ValidationReceiptBundle,
     },
@@ -147,6 +202,69 @@ impl WireMessage {
         }
     }
 
+    pub fn call_remote_stream_chunk(
+        stream_id: Nonce256Bits,
+        from_agent: holo_hash::AgentPubKey,
+        offset: u64,
+        total_len: u64,
+        data: Vec<u8>,
+    ) -> WireMessage {
+        Self::CallRemoteStreamChunk {
+            stream_id: Box::new(stream_id),
+            from_agent,
+            offset,
+            total_len,
+            data,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn call_remote_streamed(
+        stream_id: Nonce256Bits,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        from_agent: holo_hash::AgentPubKey,
+        signature: Signature,
+        to_agent: holo_hash::AgentPubKey,
+        cap_secret: Option<CapSecret>,
+        nonce: Nonce256Bits,
+        expires_at: Timestamp,
+    ) -> WireMessage {
+        Self::CallRemoteStreamed {
+            stream_id: Box::new(stream_id),
+            zome_name,
+            fn_name,
+            from_agent,
+            signature,
+            to_agent,
+            cap_secret,
+            nonce: Box::new(nonce),
+            expires_at,
+        }
+    }
+
+    pub fn call_remote_stream_response(
+        stream_id: Nonce256Bits,
+        from_agent: holo_hash::AgentPubKey,
+        offset: u64,
+    ) -> WireMessage {
+        Self::CallRemoteStreamResponse {
+            stream_id: Box::new(stream_id),
+            from_agent,
+            offset,
+        }
+    }
+
+    pub fn call_remote_stream_done(
+        stream_id: Nonce256Bits,
+        from_agent: holo_hash::AgentPubKey,
+    ) -> WireMessage {
+        Self::CallRemoteStreamDone {
+            stream_id: Box::new(stream_id),
+            from_agent,
+        }
+    }
+
     pub fn validation_receipts(receipts: ValidationReceiptBundle) -> WireMessage {
         Self::ValidationReceipts { receipts }
     }
@@ -27,6 +27,8 @@ pub mod sql_cell {
 
     pub const FETCH_PUBLISHABLE_OP: &str = include_str!("sql/cell/fetch_publishable_op.sql");
 
+    pub const SELECT_INTEGRATED_OPS: &str = include_str!("sql/cell/select_integrated_ops.sql");
+
     pub const SUM_OF_RECEIVED_BYTES_SINCE_TIMESTAMP: &str =
         include_str!("sql/cell/sum_of_received_bytes_since_timestamp.sql");
 
@@ -39,13 +41,21 @@ pub mod sql_cell {
 
     pub mod schedule {
         pub const UPDATE: &str = include_str!("sql/cell/schedule/update.sql");
+        pub const UPDATE_PAYLOAD: &str = include_str!("sql/cell/schedule/update_payload.sql");
         pub const DELETE: &str = include_str!("sql/cell/schedule/delete.sql");
         pub const EXPIRED: &str = include_str!("sql/cell/schedule/expired.sql");
+        pub const LIST: &str = include_str!("sql/cell/schedule/list.sql");
         pub const DELETE_ALL_EPHEMERAL: &str =
             include_str!("sql/cell/schedule/delete_all_ephemeral.sql");
         pub const DELETE_LIVE_EPHEMERAL: &str =
             include_str!("sql/cell/schedule/delete_live_ephemeral.sql");
     }
+    pub mod local_store {
+        pub const GET: &str = include_str!("sql/cell/local_store/get.sql");
+        pub const PUT: &str = include_str!("sql/cell/local_store/put.sql");
+        pub const DELETE: &str = include_str!("sql/cell/local_store/delete.sql");
+        pub const SCAN_PREFIX: &str = include_str!("sql/cell/local_store/scan_prefix.sql");
+    }
     pub mod state_dump {
         pub const DHT_OPS_IN_INTEGRATION_LIMBO: &str =
             include_str!("sql/cell/state_dump/dht_ops_in_integration_limbo.sql");
@@ -63,6 +73,7 @@ pub mod sql_conductor {
     pub const FROM_BLOCK_SPAN_WHERE_OVERLAPPING: &str =
         include_str!("sql/conductor/from_block_span_where_overlapping.sql");
     pub const IS_BLOCKED: &str = include_str!("sql/conductor/is_blocked.sql");
+    pub const SELECT_BLOCK_SPANS: &str = include_str!("sql/conductor/select_block_spans.sql");
     pub const SELECT_VALID_CAP_GRANT_FOR_CAP_SECRET: &str =
         include_str!("sql/conductor/select_valid_cap_grant_for_cap_secret.sql");
     pub const SELECT_VALID_UNRESTRICTED_CAP_GRANT: &str =
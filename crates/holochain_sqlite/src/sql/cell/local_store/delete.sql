@@ -0,0 +1,6 @@
+DELETE FROM
+  LocalKv
+WHERE
+  author = :author
+  AND zome_name = :zome_name
+  AND key = :key
@@ -0,0 +1,8 @@
+SELECT
+  value
+FROM
+  LocalKv
+WHERE
+  author = :author
+  AND zome_name = :zome_name
+  AND key = :key
@@ -0,0 +1,4 @@
+INSERT INTO
+  LocalKv (author, zome_name, key, value)
+VALUES
+  (:author, :zome_name, :key, :value)
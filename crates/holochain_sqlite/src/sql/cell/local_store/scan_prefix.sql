@@ -0,0 +1,11 @@
+SELECT
+  key,
+  value
+FROM
+  LocalKv
+WHERE
+  author = :author
+  AND zome_name = :zome_name
+  AND substr(key, 1, length(:prefix)) = :prefix
+ORDER BY
+  key ASC
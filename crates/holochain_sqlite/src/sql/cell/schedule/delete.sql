@@ -3,4 +3,5 @@ DELETE FROM
 WHERE
   zome_name = :zome_name
   AND scheduled_fn = :scheduled_fn
+  AND schedule_name = :schedule_name
   AND author = :author
@@ -1,6 +1,7 @@
 SELECT
   zome_name,
   scheduled_fn,
+  schedule_name,
   maybe_schedule
 FROM
   ScheduledFunctions
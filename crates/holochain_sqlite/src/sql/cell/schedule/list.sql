@@ -0,0 +1,13 @@
+SELECT
+  zome_name,
+  scheduled_fn,
+  schedule_name,
+  maybe_schedule,
+  payload,
+  start
+FROM
+  ScheduledFunctions
+WHERE
+  author = :author
+ORDER BY
+  start ASC
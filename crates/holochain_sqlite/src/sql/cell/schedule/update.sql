@@ -8,4 +8,5 @@ ephemeral = :ephemeral
 WHERE
   zome_name = :zome_name
   AND scheduled_fn = :scheduled_fn
+  AND schedule_name = :schedule_name
   AND author = :author
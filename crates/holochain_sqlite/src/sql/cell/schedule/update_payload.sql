@@ -0,0 +1,9 @@
+UPDATE
+  ScheduledFunctions
+SET
+  payload = :payload
+WHERE
+  zome_name = :zome_name
+  AND scheduled_fn = :scheduled_fn
+  AND schedule_name = :schedule_name
+  AND author = :author
@@ -0,0 +1,39 @@
+ALTER TABLE
+  ScheduledFunctions RENAME TO ScheduledFunctions_2Up;
+CREATE TABLE ScheduledFunctions (
+  author BLOB NOT NULL,
+  zome_name TEXT NOT NULL,
+  scheduled_fn TEXT NOT NULL,
+  schedule_name TEXT NOT NULL,
+  payload BLOB NULL,
+  maybe_schedule BLOB NOT NULL,
+  start INTEGER NOT NULL,
+end INTEGER NOT NULL,
+ephemeral BOOLEAN NOT NULL,
+PRIMARY KEY (zome_name, scheduled_fn, schedule_name, author) ON CONFLICT ROLLBACK
+);
+INSERT INTO
+  ScheduledFunctions (
+    author,
+    zome_name,
+    scheduled_fn,
+    schedule_name,
+    payload,
+    maybe_schedule,
+    start,
+  end,
+  ephemeral
+)
+SELECT
+  author,
+  zome_name,
+  scheduled_fn,
+  '',
+  NULL,
+  maybe_schedule,
+  start,
+end,
+ephemeral
+FROM
+  ScheduledFunctions_2Up;
+DROP TABLE ScheduledFunctions_2Up;
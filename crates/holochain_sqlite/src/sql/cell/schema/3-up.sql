@@ -0,0 +1,7 @@
+CREATE TABLE LocalKv (
+  author BLOB NOT NULL,
+  zome_name TEXT NOT NULL,
+  key BLOB NOT NULL,
+  value BLOB NOT NULL,
+  PRIMARY KEY (author, zome_name, key) ON CONFLICT REPLACE
+);
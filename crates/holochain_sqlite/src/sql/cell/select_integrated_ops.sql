@@ -0,0 +1,27 @@
+SELECT
+  DhtOp.hash,
+  DhtOp.type,
+  DhtOp.validation_status,
+  Action.blob AS action_blob,
+  Entry.blob AS entry_blob
+FROM
+  DhtOp
+  JOIN Action ON DhtOp.action_hash = Action.hash
+  LEFT JOIN Entry ON Action.entry_hash = Entry.hash
+WHERE
+  -- Listing every type lets the type and when_integrated index be used.
+  DhtOp.type IN (
+    'StoreRecord',
+    'StoreEntry',
+    'RegisterAgentActivity',
+    'RegisterUpdatedContent',
+    'RegisterUpdatedRecord',
+    'RegisterDeletedBy',
+    'RegisterDeletedEntryAction',
+    'RegisterAddLink',
+    'RegisterRemoveLink'
+  )
+  AND DhtOp.when_integrated > :integrated_after
+  AND DhtOp.when_integrated <= :integrated_until
+ORDER BY
+  DhtOp.when_integrated
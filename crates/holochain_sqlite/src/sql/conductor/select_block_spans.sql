@@ -0,0 +1,16 @@
+-- all block spans, or only those covering :time_us if it is not null
+SELECT
+  target_id,
+  target_reason,
+  start_us,
+  end_us
+FROM
+  BlockSpan
+WHERE
+  :time_us IS NULL
+  OR (
+    start_us <= :time_us
+    AND :time_us <= end_us
+  )
+ORDER BY
+  start_us
@@ -7,7 +7,10 @@ use holochain_sqlite::sql::sql_conductor;
 use holochain_types::prelude::DbKindConductor;
 use holochain_types::prelude::Timestamp;
 use holochain_zome_types::block::Block;
+use holochain_zome_types::block::BlockSpan;
+use holochain_zome_types::block::BlockTarget;
 use holochain_zome_types::block::BlockTargetId;
+use holochain_zome_types::block::BlockTargetReason;
 
 pub async fn block(db: &DbWrite<DbKindConductor>, input: Block) -> DatabaseResult<()> {
     tracing::warn!(?input, "blocking node!");
@@ -36,6 +39,41 @@ pub fn query_is_blocked(
     )?)
 }
 
+/// List all blocks, or only those in effect at the given time.
+pub fn query_blocks(
+    txn: &Transaction<'_>,
+    timestamp: Option<Timestamp>,
+) -> DatabaseResult<Vec<BlockSpan>> {
+    let mut stmt = txn.prepare(sql_conductor::SELECT_BLOCK_SPANS)?;
+    let rows = stmt.query_map(
+        named_params! {
+            ":time_us": timestamp,
+        },
+        |row| {
+            Ok((
+                row.get::<_, Vec<u8>>("target_id")?,
+                row.get::<_, Vec<u8>>("target_reason")?,
+                row.get::<_, Timestamp>("start_us")?,
+                row.get::<_, Timestamp>("end_us")?,
+            ))
+        },
+    )?;
+    let mut blocks = vec![];
+    for row in rows {
+        let (target_id, target_reason, start, end) = row?;
+        let target_id: BlockTargetId = holochain_serialized_bytes::decode(&target_id)?;
+        let target_reason: BlockTargetReason = holochain_serialized_bytes::decode(&target_reason)?;
+        let target =
+            BlockTarget::from_id_and_reason(target_id, target_reason).ok_or_else(|| {
+                holochain_serialized_bytes::SerializedBytesError::Deserialize(
+                    "Block span has a reason for a different kind of target".to_string(),
+                )
+            })?;
+        blocks.push(BlockSpan { target, start, end });
+    }
+    Ok(blocks)
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -566,4 +604,68 @@ mod test {
             );
         }
     }
+
+    // Blocks can be listed, optionally only those in effect at some time.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn query_blocks() {
+        let db = test_conductor_db();
+
+        let ipv4 = BlockTarget::Ip(
+            std::net::Ipv4Addr::new(192, 168, 0, 1).into(),
+            kitsune_p2p_block::IpBlockReason::DOS.into(),
+        );
+        let ipv6 = BlockTarget::Ip(
+            std::net::Ipv6Addr::LOCALHOST.into(),
+            kitsune_p2p_block::IpBlockReason::DOS.into(),
+        );
+        for (target, start, end) in [(ipv4.clone(), 0, 10), (ipv6.clone(), 5, 20)] {
+            super::block(
+                &db,
+                Block::new(
+                    target,
+                    InclusiveTimestampInterval::try_new(Timestamp(start), Timestamp(end)).unwrap(),
+                ),
+            )
+            .await
+            .unwrap();
+        }
+
+        let all = db
+            .read_async(|txn| super::query_blocks(&txn, None))
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![
+                BlockSpan {
+                    target: ipv4.clone(),
+                    start: Timestamp(0),
+                    end: Timestamp(10),
+                },
+                BlockSpan {
+                    target: ipv6.clone(),
+                    start: Timestamp(5),
+                    end: Timestamp(20),
+                },
+            ],
+            all
+        );
+
+        let later = db
+            .read_async(|txn| super::query_blocks(&txn, Some(Timestamp(15))))
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![BlockSpan {
+                target: ipv6,
+                start: Timestamp(5),
+                end: Timestamp(20),
+            }],
+            later
+        );
+
+        assert!(db
+            .read_async(move |txn| super::query_is_blocked(&txn, ipv4.into(), Timestamp(5)))
+            .await
+            .unwrap());
+    }
 }
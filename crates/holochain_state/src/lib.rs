@@ -35,6 +35,7 @@ pub mod dna_def;
 pub mod entry_def;
 pub mod host_fn_workspace;
 pub mod integrate;
+pub mod local_store;
 pub mod mutations;
 pub mod nonce;
 #[allow(missing_docs)]
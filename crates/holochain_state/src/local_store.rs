@@ -0,0 +1,135 @@
+//! Reads from a zome's local key/value store.
+//!
+//! The store is kept in the authored database next to the source chain but
+//! is not part of it, so nothing in it is gossiped or validated.
+//! Writes are in [`crate::mutations::local_put`] and [`crate::mutations::local_delete`].
+
+use crate::prelude::*;
+use holo_hash::AgentPubKey;
+use holochain_sqlite::rusqlite::OptionalExtension;
+use holochain_sqlite::rusqlite::{named_params, Transaction};
+use holochain_sqlite::sql::sql_cell::local_store;
+
+/// Get the value stored under a key by a zome of this author.
+pub fn local_get(
+    txn: &Transaction,
+    author: &AgentPubKey,
+    zome_name: &ZomeName,
+    key: &[u8],
+) -> StateQueryResult<Option<Vec<u8>>> {
+    Ok(txn
+        .query_row(
+            local_store::GET,
+            named_params! {
+                ":author": author,
+                ":zome_name": zome_name.to_string(),
+                ":key": key,
+            },
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+/// All the key/value pairs stored by a zome of this author whose key starts
+/// with the prefix, ordered by key.
+pub fn local_scan_prefix(
+    txn: &Transaction,
+    author: &AgentPubKey,
+    zome_name: &ZomeName,
+    prefix: &[u8],
+) -> StateQueryResult<Vec<(Vec<u8>, Vec<u8>)>> {
+    let mut stmt = txn.prepare(local_store::SCAN_PREFIX)?;
+    let rows = stmt.query_map(
+        named_params! {
+            ":author": author,
+            ":zome_name": zome_name.to_string(),
+            ":prefix": prefix,
+        },
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_authored_db;
+    use ::fixt::prelude::*;
+    use holo_hash::fixt::AgentPubKeyFixturator;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn local_store_round_trip() {
+        let test_db = test_authored_db();
+        let db = test_db.to_db();
+        let author = fixt!(AgentPubKey);
+        let other_author = fixt!(AgentPubKey);
+        let zome: ZomeName = "zome".into();
+        let other_zome: ZomeName = "other_zome".into();
+
+        db.write_async({
+            let author = author.clone();
+            let zome = zome.clone();
+            move |txn| -> StateMutationResult<()> {
+                local_put(txn, &author, &zome, b"a/1", b"one")?;
+                local_put(txn, &author, &zome, b"a/2", b"two")?;
+                local_put(txn, &author, &zome, b"b/1", b"three")?;
+                // Putting a key again replaces its value.
+                local_put(txn, &author, &zome, b"a/1", b"uno")?;
+                local_put(txn, &other_author, &zome, b"a/3", b"other author")?;
+                local_put(txn, &author, &other_zome, b"a/4", b"other zome")?;
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+
+        db.read_async({
+            let author = author.clone();
+            let zome = zome.clone();
+            move |txn| -> StateQueryResult<()> {
+                assert_eq!(
+                    local_get(&txn, &author, &zome, b"a/1")?,
+                    Some(b"uno".to_vec())
+                );
+                assert_eq!(local_get(&txn, &author, &zome, b"a/3")?, None);
+                assert_eq!(
+                    local_scan_prefix(&txn, &author, &zome, b"a/")?,
+                    vec![
+                        (b"a/1".to_vec(), b"uno".to_vec()),
+                        (b"a/2".to_vec(), b"two".to_vec()),
+                    ]
+                );
+                assert_eq!(local_scan_prefix(&txn, &author, &zome, b"")?.len(), 3);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+
+        let deleted = db
+            .write_async({
+                let author = author.clone();
+                let zome = zome.clone();
+                move |txn| -> StateMutationResult<(bool, bool)> {
+                    Ok((
+                        local_delete(txn, &author, &zome, b"a/1")?,
+                        local_delete(txn, &author, &zome, b"a/1")?,
+                    ))
+                }
+            })
+            .await
+            .unwrap();
+        assert_eq!(deleted, (true, false));
+
+        db.read_async(move |txn| -> StateQueryResult<()> {
+            assert_eq!(local_get(&txn, &author, &zome, b"a/1")?, None);
+            assert_eq!(
+                local_scan_prefix(&txn, &author, &zome, b"a/")?,
+                vec![(b"a/2".to_vec(), b"two".to_vec())]
+            );
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
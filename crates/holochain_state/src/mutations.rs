@@ -724,6 +724,7 @@ pub fn reschedule_expired(
                     ZomeName(row.get::<_, String>(0)?.into()),
                     FunctionName(row.get(1)?),
                     row.get(2)?,
+                    row.get(3)?,
                 ))
             },
         )?;
@@ -733,11 +734,11 @@ pub fn reschedule_expired(
         }
         ret
     };
-    for (zome_name, scheduled_fn, maybe_schedule) in rows {
+    for (zome_name, scheduled_fn, schedule_name, maybe_schedule) in rows {
         schedule_fn(
             txn,
             author,
-            ScheduledFn::new(zome_name, scheduled_fn),
+            ScheduledFn::named(zome_name, scheduled_fn, schedule_name),
             from_blob(maybe_schedule)?,
             now,
         )?;
@@ -773,6 +774,7 @@ pub fn schedule_fn(
                     named_params! {
                         ":zome_name": scheduled_fn.zome_name().to_string(),
                         ":scheduled_fn": scheduled_fn.fn_name().to_string(),
+                        ":schedule_name": scheduled_fn.name(),
                         ":author" : author,
                     },
                 )?;
@@ -797,6 +799,7 @@ pub fn schedule_fn(
                 ":zome_name": scheduled_fn.zome_name().to_string(),
                 ":maybe_schedule": to_blob::<Option<Schedule>>(&maybe_schedule)?,
                 ":scheduled_fn": scheduled_fn.fn_name().to_string(),
+                ":schedule_name": scheduled_fn.name(),
                 ":start": start,
                 ":end": end,
                 ":ephemeral": ephemeral,
@@ -808,6 +811,7 @@ pub fn schedule_fn(
             "zome_name": scheduled_fn.zome_name().to_string(),
             "maybe_schedule": to_blob::<Option<Schedule>>(&maybe_schedule)?,
             "scheduled_fn": scheduled_fn.fn_name().to_string(),
+            "schedule_name": scheduled_fn.name(),
             "start": start,
             "end": end,
             "ephemeral": ephemeral,
@@ -816,3 +820,84 @@ pub fn schedule_fn(
     }
     Ok(())
 }
+
+/// Schedule a function like [`schedule_fn`] and set the payload it will be
+/// called with.
+pub fn schedule_fn_with_payload(
+    txn: &mut Transaction,
+    author: &AgentPubKey,
+    scheduled_fn: ScheduledFn,
+    payload: &ExternIO,
+    maybe_schedule: Option<Schedule>,
+    now: Timestamp,
+) -> StateMutationResult<()> {
+    schedule_fn(txn, author, scheduled_fn.clone(), maybe_schedule, now)?;
+    txn.execute(
+        holochain_sqlite::sql::sql_cell::schedule::UPDATE_PAYLOAD,
+        named_params! {
+            ":zome_name": scheduled_fn.zome_name().to_string(),
+            ":scheduled_fn": scheduled_fn.fn_name().to_string(),
+            ":schedule_name": scheduled_fn.name(),
+            ":payload": to_blob(payload)?,
+            ":author" : author,
+        },
+    )?;
+    Ok(())
+}
+
+/// Remove a pending schedule.
+/// Returns whether the function was scheduled.
+pub fn cancel_scheduled_fn(
+    txn: &mut Transaction,
+    author: &AgentPubKey,
+    scheduled_fn: &ScheduledFn,
+) -> StateMutationResult<bool> {
+    Ok(txn.execute(
+        holochain_sqlite::sql::sql_cell::schedule::DELETE,
+        named_params! {
+            ":zome_name": scheduled_fn.zome_name().to_string(),
+            ":scheduled_fn": scheduled_fn.fn_name().to_string(),
+            ":schedule_name": scheduled_fn.name(),
+            ":author" : author,
+        },
+    )? > 0)
+}
+
+/// Store a value under a key in a zome's local key/value store,
+/// replacing any value already stored under it.
+pub fn local_put(
+    txn: &mut Transaction,
+    author: &AgentPubKey,
+    zome_name: &ZomeName,
+    key: &[u8],
+    value: &[u8],
+) -> StateMutationResult<()> {
+    txn.execute(
+        holochain_sqlite::sql::sql_cell::local_store::PUT,
+        named_params! {
+            ":author": author,
+            ":zome_name": zome_name.to_string(),
+            ":key": key,
+            ":value": value,
+        },
+    )?;
+    Ok(())
+}
+
+/// Remove a key from a zome's local key/value store.
+/// Returns whether the key was stored.
+pub fn local_delete(
+    txn: &mut Transaction,
+    author: &AgentPubKey,
+    zome_name: &ZomeName,
+    key: &[u8],
+) -> StateMutationResult<bool> {
+    Ok(txn.execute(
+        holochain_sqlite::sql::sql_cell::local_store::DELETE,
+        named_params! {
+            ":author": author,
+            ":zome_name": zome_name.to_string(),
+            ":key": key,
+        },
+    )? > 0)
+}
@@ -16,12 +16,14 @@ pub fn fn_is_scheduled(
             WHERE
             zome_name=:zome_name
             AND scheduled_fn=:scheduled_fn
+            AND schedule_name=:schedule_name
             AND author = :author
             LIMIT 1
             ",
             named_params! {
                 ":zome_name": scheduled_fn.zome_name().to_string(),
                 ":scheduled_fn": scheduled_fn.fn_name().to_string(),
+                ":schedule_name": scheduled_fn.name(),
                 ":author": author,
             },
             |row| row.get::<_, String>(0),
@@ -30,17 +32,22 @@ pub fn fn_is_scheduled(
         .is_some())
 }
 
+/// A scheduled function that is due, with its current schedule and payload.
+pub type LiveScheduledFn = (ScheduledFn, Option<Schedule>, Option<ExternIO>);
+
 pub fn live_scheduled_fns(
     txn: &Transaction,
     now: Timestamp,
     author: &AgentPubKey,
-) -> StateMutationResult<Vec<(ScheduledFn, Option<Schedule>)>> {
+) -> StateMutationResult<Vec<LiveScheduledFn>> {
     let mut stmt = txn.prepare(
         "
         SELECT
         zome_name,
         scheduled_fn,
-        maybe_schedule
+        schedule_name,
+        maybe_schedule,
+        payload
         FROM ScheduledFunctions
         WHERE
         start <= :now
@@ -55,18 +62,55 @@ pub fn live_scheduled_fns(
         },
         |row| {
             Ok((
-                ScheduledFn::new(
+                ScheduledFn::named(
                     ZomeName(row.get::<_, String>(0)?.into()),
                     FunctionName(row.get(1)?),
+                    row.get(2)?,
                 ),
-                row.get(2)?,
+                row.get(3)?,
+                row.get::<_, Option<Vec<u8>>>(4)?,
             ))
         },
     )?;
     let mut ret = vec![];
     for row in rows {
-        let (scheduled_fn, maybe_schedule_serialized) = row?;
-        ret.push((scheduled_fn, from_blob(maybe_schedule_serialized)?));
+        let (scheduled_fn, maybe_schedule_serialized, maybe_payload_serialized) = row?;
+        ret.push((
+            scheduled_fn,
+            from_blob(maybe_schedule_serialized)?,
+            maybe_payload_serialized.map(from_blob).transpose()?,
+        ));
+    }
+    Ok(ret)
+}
+
+/// All the pending schedules of an author, soonest first.
+pub fn list_scheduled_fns(
+    txn: &Transaction,
+    author: &AgentPubKey,
+) -> StateMutationResult<Vec<ScheduledFnInfo>> {
+    let mut stmt = txn.prepare(holochain_sqlite::sql::sql_cell::schedule::LIST)?;
+    let rows = stmt.query_map(named_params! { ":author": author }, |row| {
+        Ok((
+            ZomeName(row.get::<_, String>(0)?.into()),
+            FunctionName(row.get(1)?),
+            row.get::<_, String>(2)?,
+            row.get::<_, Vec<u8>>(3)?,
+            row.get::<_, Option<Vec<u8>>>(4)?,
+            row.get::<_, Timestamp>(5)?,
+        ))
+    })?;
+    let mut ret = vec![];
+    for row in rows {
+        let (zome_name, fn_name, name, maybe_schedule, maybe_payload, next_trigger) = row?;
+        ret.push(ScheduledFnInfo {
+            zome_name,
+            fn_name,
+            name,
+            schedule: from_blob(maybe_schedule)?,
+            payload: maybe_payload.map(from_blob).transpose()?,
+            next_trigger,
+        });
     }
     Ok(ret)
 }
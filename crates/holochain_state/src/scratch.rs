@@ -24,10 +24,21 @@ pub struct Scratch {
     actions: Vec<SignedActionHashed>,
     entries: HashMap<EntryHash, Arc<Entry>>,
     chain_top_ordering: ChainTopOrdering,
-    scheduled_fns: Vec<ScheduledFn>,
+    scheduled_fns: Vec<ScheduleChange>,
     chain_head: Option<(u32, usize)>,
 }
 
+/// A change to the scheduled functions of the author, staged in the scratch
+/// and applied in order when the scratch is flushed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleChange {
+    /// Schedule a function, with the payload and first trigger if it was
+    /// scheduled with a payload.
+    Schedule(ScheduledFn, Option<(ExternIO, Option<Schedule>)>),
+    /// Cancel a pending schedule.
+    Cancel(ScheduledFn),
+}
+
 #[derive(Debug, Clone)]
 pub struct SyncScratch(Arc<Mutex<Scratch>>);
 
@@ -44,12 +55,30 @@ impl Scratch {
         }
     }
 
-    pub fn scheduled_fns(&self) -> &[ScheduledFn] {
+    pub fn scheduled_fns(&self) -> &[ScheduleChange] {
         &self.scheduled_fns
     }
 
     pub fn add_scheduled_fn(&mut self, scheduled_fn: ScheduledFn) {
-        self.scheduled_fns.push(scheduled_fn)
+        self.scheduled_fns
+            .push(ScheduleChange::Schedule(scheduled_fn, None))
+    }
+
+    pub fn add_scheduled_fn_with_payload(
+        &mut self,
+        scheduled_fn: ScheduledFn,
+        payload: ExternIO,
+        schedule: Option<Schedule>,
+    ) {
+        self.scheduled_fns.push(ScheduleChange::Schedule(
+            scheduled_fn,
+            Some((payload, schedule)),
+        ))
+    }
+
+    pub fn cancel_scheduled_fn(&mut self, scheduled_fn: ScheduledFn) {
+        self.scheduled_fns
+            .push(ScheduleChange::Cancel(scheduled_fn))
     }
 
     pub fn chain_top_ordering(&self) -> ChainTopOrdering {
@@ -160,7 +189,7 @@ impl Scratch {
         Ok(r)
     }
 
-    pub fn drain_scheduled_fns(&mut self) -> impl Iterator<Item = ScheduledFn> + '_ {
+    pub fn drain_scheduled_fns(&mut self) -> impl Iterator<Item = ScheduleChange> + '_ {
         self.scheduled_fns.drain(..)
     }
 
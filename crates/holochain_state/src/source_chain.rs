@@ -7,6 +7,7 @@ use crate::chain_lock::is_lock_expired;
 use crate::integrate::authored_ops_to_dht_db;
 use crate::integrate::authored_ops_to_dht_db_without_check;
 use crate::query::chain_head::ChainHeadQuery;
+use crate::scratch::ScheduleChange;
 use crate::scratch::ScratchError;
 use crate::scratch::SyncScratchError;
 use async_recursion::async_recursion;
@@ -299,8 +300,25 @@ impl SourceChain {
             .vault
             .write_async(move |txn: &mut Transaction| {
                 let now = Timestamp::now();
-                for scheduled_fn in scheduled_fns {
-                    schedule_fn(txn, author.as_ref(), scheduled_fn, None, now)?;
+                for change in scheduled_fns {
+                    match change {
+                        ScheduleChange::Schedule(scheduled_fn, None) => {
+                            schedule_fn(txn, author.as_ref(), scheduled_fn, None, now)?;
+                        }
+                        ScheduleChange::Schedule(scheduled_fn, Some((payload, schedule))) => {
+                            schedule_fn_with_payload(
+                                txn,
+                                author.as_ref(),
+                                scheduled_fn,
+                                &payload,
+                                schedule,
+                                now,
+                            )?;
+                        }
+                        ScheduleChange::Cancel(scheduled_fn) => {
+                            cancel_scheduled_fn(txn, author.as_ref(), &scheduled_fn)?;
+                        }
+                    }
                 }
 
                 // As at check.
@@ -540,6 +558,40 @@ where
         Ok(zomes_initialized)
     }
 
+    /// The [`CloseChain`] action which ended this source chain, if the agent
+    /// has been migrated away to another DNA.
+    pub async fn chain_closed(&self) -> SourceChainResult<Option<CloseChain>> {
+        let query_filter = ChainQueryFilter {
+            action_type: Some(vec![ActionType::CloseChain]),
+            ..QueryFilter::default()
+        };
+        Ok(self
+            .query(query_filter)
+            .await?
+            .into_iter()
+            .find_map(|record| match record.action() {
+                Action::CloseChain(close_chain) => Some(close_chain.clone()),
+                _ => None,
+            }))
+    }
+
+    /// The [`OpenChain`] action which started this source chain, if the agent
+    /// has been migrated here from another DNA.
+    pub async fn chain_opened(&self) -> SourceChainResult<Option<OpenChain>> {
+        let query_filter = ChainQueryFilter {
+            action_type: Some(vec![ActionType::OpenChain]),
+            ..QueryFilter::default()
+        };
+        Ok(self
+            .query(query_filter)
+            .await?
+            .into_iter()
+            .find_map(|record| match record.action() {
+                Action::OpenChain(open_chain) => Some(open_chain.clone()),
+                _ => None,
+            }))
+    }
+
     pub fn set_zomes_initialized(&self, value: bool) {
         self.zomes_initialized.store(value, Ordering::Relaxed);
     }
@@ -745,6 +797,13 @@ where
                         ", named_param_seq("entry_type", entry_type_filters_count), named_param_seq("action_type", action_type_filters_count)).as_str(),
                     );
                     sql.push_str(if query.order_descending {" DESC"} else {" ASC"});
+                    // The page itself is taken by `filter_records` once any scratch records
+                    // have been added, so here we only avoid loading rows that can't be in it.
+                    // Entry hashes and hash bounded ranges are filtered in memory, so all
+                    // candidate rows are needed for those.
+                    if let (Some(limit), None, ChainQueryFilterRange::Unbounded | ChainQueryFilterRange::ActionSeqRange(_, _)) = (query.limit, &query.entry_hashes, &query.sequence_range) {
+                        sql.push_str(&format!(" LIMIT {}", limit as u64 + query.offset.unwrap_or(0) as u64));
+                    }
                     let mut stmt = txn.prepare(&sql)?;
 
                     // This type is similar to what `named_params!` from rusqlite creates, escept for the use of boxing to allow references to be passed to the query.
@@ -2325,6 +2384,8 @@ mod tests {
                     entry_hashes: entry_hashes.clone(),
                     include_entries,
                     order_descending: false,
+                    offset: None,
+                    limit: None,
                 };
                 if sequence_range != ChainQueryFilterRange::Unbounded
                     && (action_type.is_some()
@@ -2391,6 +2452,78 @@ mod tests {
         assert_eq!(asc, desc_sorted);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn source_chain_query_pagination() {
+        let test_db = test_authored_db();
+        let dht_db = test_dht_db();
+        let dht_db_cache = DhtDbQueryCache::new(dht_db.to_db().into());
+        let keystore = test_keystore();
+        let vault = test_db.to_db();
+        let alice = keystore.new_sign_keypair_random().await.unwrap();
+        let dna_hash = fixt!(DnaHash);
+
+        genesis(
+            vault.clone().into(),
+            dht_db.to_db(),
+            &dht_db_cache,
+            keystore.clone(),
+            dna_hash.clone(),
+            alice.clone(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let chain = SourceChain::new(vault, dht_db.to_db(), dht_db_cache, keystore, alice.clone())
+            .await
+            .unwrap();
+
+        let all = chain.query(ChainQueryFilter::default()).await.unwrap();
+        assert_eq!(all.len(), 3);
+
+        let seqs = |records: Vec<Record>| {
+            records
+                .iter()
+                .map(|r| r.action().action_seq())
+                .collect::<Vec<_>>()
+        };
+
+        let page = chain
+            .query(ChainQueryFilter::default().limit(2))
+            .await
+            .unwrap();
+        assert_eq!(seqs(page), vec![0, 1]);
+
+        let page = chain
+            .query(ChainQueryFilter::default().offset(1).limit(1))
+            .await
+            .unwrap();
+        assert_eq!(seqs(page), vec![1]);
+
+        let page = chain
+            .query(ChainQueryFilter::default().descending().limit(2))
+            .await
+            .unwrap();
+        assert_eq!(seqs(page), vec![2, 1]);
+
+        let page = chain
+            .query(
+                ChainQueryFilter::default()
+                    .sequence_range(ChainQueryFilterRange::ActionSeqRange(1, 2))
+                    .offset(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(seqs(page), vec![2]);
+
+        let page = chain
+            .query(ChainQueryFilter::default().offset(3))
+            .await
+            .unwrap();
+        assert!(page.is_empty());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn init_zomes_complete() {
         let test_db = test_authored_db();
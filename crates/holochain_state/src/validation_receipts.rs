@@ -31,6 +31,32 @@ pub fn list_receipts(
     iter.collect()
 }
 
+/// List the receipts for all ops whose action was authored by the given agent.
+///
+/// Receipts for ops which are no longer held, so whose author can't be
+/// checked, are listed too, after all the others.
+pub fn list_receipts_for_author(
+    txn: &Transaction,
+    author: &AgentPubKey,
+) -> StateQueryResult<Vec<SignedValidationReceipt>> {
+    let mut stmt = txn.prepare(
+        "
+        SELECT ValidationReceipt.blob FROM ValidationReceipt
+        LEFT JOIN DhtOp ON ValidationReceipt.op_hash = DhtOp.hash
+        LEFT JOIN Action ON DhtOp.action_hash = Action.hash
+        WHERE Action.author = :author OR Action.hash IS NULL
+        ORDER BY DhtOp.authored_timestamp IS NULL, DhtOp.authored_timestamp
+        ",
+    )?;
+    let iter = stmt.query_and_then(
+        named_params! {
+            ":author": author
+        },
+        |row| from_blob::<SignedValidationReceipt>(row.get("blob")?),
+    )?;
+    iter.collect()
+}
+
 pub fn count_valid(txn: &Transaction, op_hash: &DhtOpHash) -> DatabaseResult<usize> {
     let count: usize = txn
         .query_row(
@@ -182,6 +208,44 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn list_receipts_by_author() {
+        holochain_trace::test_run().ok();
+
+        let env = crate::test_utils::test_dht_db().to_db();
+        let keystore = test_keystore();
+
+        let mine = DhtOpHashed::from_content_sync(DhtOp::RegisterAgentActivity(
+            fixt!(Signature),
+            fixt!(Action),
+        ));
+        let theirs = DhtOpHashed::from_content_sync(DhtOp::RegisterAgentActivity(
+            fixt!(Signature),
+            fixt!(Action),
+        ));
+        let author = mine.action().author().clone();
+        let vr_mine = fake_vr(mine.as_hash(), &keystore).await;
+        let vr_theirs = fake_vr(theirs.as_hash(), &keystore).await;
+
+        env.write_async({
+            let vr_mine = vr_mine.clone();
+            move |txn| -> StateMutationResult<()> {
+                mutations::insert_op(txn, &mine)?;
+                mutations::insert_op(txn, &theirs)?;
+                add_if_unique(txn, vr_mine)?;
+                add_if_unique(txn, vr_theirs)
+            }
+        })
+        .await
+        .unwrap();
+
+        let list = env
+            .read_async(move |txn| list_receipts_for_author(&txn, &author))
+            .await
+            .unwrap();
+        assert_eq!(vec![vr_mine], list);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn no_pending_receipts() {
         holochain_trace::test_run().ok();
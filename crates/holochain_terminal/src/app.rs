@@ -1,6 +1,7 @@
 use crate::cli::Args;
 use crate::client::{AdminClient, AppClient};
 use crate::event::ScreenEvent;
+use holochain_types::prelude::CellId;
 use kitsune_p2p_types::dependencies::tokio;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -22,11 +23,13 @@ pub struct App {
     args: Args,
 
     /// An admin client if the `admin_url` flag was provided
-    #[allow(dead_code)]
     admin_client: Option<Arc<Mutex<AdminClient>>>,
 
     /// An app client if the `admin_url` flag was provided
     app_client: Option<Arc<Mutex<AppClient>>>,
+
+    /// The cell selected on the apps screen, for inspection on the other screens
+    selected_cell: Option<CellId>,
 }
 
 impl App {
@@ -44,6 +47,7 @@ impl App {
             args,
             admin_client: admin_client.map(|c| Arc::new(Mutex::new(c))),
             app_client: app_client.map(|c| Arc::new(Mutex::new(c))),
+            selected_cell: None,
         }
     }
 
@@ -76,7 +80,6 @@ impl App {
         &self.args
     }
 
-    #[allow(dead_code)]
     pub fn admin_client(&mut self) -> Option<Arc<Mutex<AdminClient>>> {
         self.admin_client.clone()
     }
@@ -84,4 +87,12 @@ impl App {
     pub fn app_client(&mut self) -> Option<Arc<Mutex<AppClient>>> {
         self.app_client.clone()
     }
+
+    pub fn selected_cell(&self) -> Option<&CellId> {
+        self.selected_cell.as_ref()
+    }
+
+    pub fn selected_cell_mut(&mut self) -> &mut Option<CellId> {
+        &mut self.selected_cell
+    }
 }
@@ -1,9 +1,12 @@
 use anyhow::anyhow;
 use holo_hash::{AgentPubKey, DnaHash};
 use holochain_conductor_api::{
-    AdminRequest, AdminResponse, AppInfo, AppRequest, AppResponse, CellInfo, NetworkInfo,
+    AdminRequest, AdminResponse, AppInfo, AppRequest, AppResponse, CellInfo, FullStateDump,
+    NetworkInfo,
+};
+use holochain_types::prelude::{
+    CellId, InstalledAppId, NetworkInfoRequestPayload, SignedValidationReceipt,
 };
-use holochain_types::prelude::{InstalledAppId, NetworkInfoRequestPayload};
 use holochain_websocket::{
     connect, WebsocketConfig, WebsocketError, WebsocketReceiver, WebsocketSender,
 };
@@ -128,6 +131,43 @@ impl AdminClient {
         AppClient::connect(&app_url).await
     }
 
+    pub async fn list_apps(&mut self) -> anyhow::Result<Vec<AppInfo>> {
+        let msg = AdminRequest::ListApps {
+            status_filter: None,
+        };
+        let response = self.send(msg).await?;
+        match response {
+            AdminResponse::AppsListed(apps) => Ok(apps),
+            _ => unreachable!("Unexpected response {:?}", response),
+        }
+    }
+
+    pub async fn dump_full_state(&mut self, cell_id: CellId) -> anyhow::Result<FullStateDump> {
+        let msg = AdminRequest::DumpFullState {
+            cell_id: Box::new(cell_id),
+            dht_ops_cursor: None,
+        };
+        let response = self.send(msg).await?;
+        match response {
+            AdminResponse::FullStateDumped(state) => Ok(state),
+            _ => unreachable!("Unexpected response {:?}", response),
+        }
+    }
+
+    pub async fn dump_validation_receipts(
+        &mut self,
+        cell_id: CellId,
+    ) -> anyhow::Result<Vec<SignedValidationReceipt>> {
+        let msg = AdminRequest::DumpValidationReceipts {
+            cell_id: Box::new(cell_id),
+        };
+        let response = self.send(msg).await?;
+        match response {
+            AdminResponse::ValidationReceiptsDumped(receipts) => Ok(receipts),
+            _ => unreachable!("Unexpected response {:?}", response),
+        }
+    }
+
     async fn list_app_interfaces(&mut self) -> anyhow::Result<Vec<u16>> {
         let msg = AdminRequest::ListAppInterfaces;
         let response = self.send(msg).await?;
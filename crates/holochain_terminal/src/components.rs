@@ -1,3 +1,8 @@
+pub mod apps;
 pub mod bootstrap;
+pub mod cell_state;
 pub mod common;
+pub mod dht_ops;
 pub mod network_info;
+pub mod source_chain;
+pub mod validation_receipts;
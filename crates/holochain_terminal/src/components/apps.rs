@@ -0,0 +1,165 @@
+use crate::client::AdminClient;
+use crate::components::common::{list_and_detail_layout, navigate, render_detail, show_message};
+use crate::event::ScreenEvent;
+use anyhow::anyhow;
+use holochain_conductor_api::{AppInfo, CellInfo};
+use holochain_types::prelude::CellId;
+use holochain_util::tokio_helper::block_on;
+use kitsune_p2p_types::dependencies::tokio;
+use once_cell::sync::Lazy;
+use ratatui::{prelude::*, widgets::*};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+static APPS: Lazy<RwLock<Option<Vec<AppInfo>>>> = Lazy::new(|| RwLock::new(None));
+static SELECTED: Lazy<RwLock<usize>> = Lazy::new(|| RwLock::new(0));
+
+/// A cell of an installed app, which can be selected for inspection on the other screens
+struct CellRow<'a> {
+    app: &'a AppInfo,
+    role_name: &'a str,
+    cell_info: &'a CellInfo,
+    cell_id: &'a CellId,
+}
+
+pub fn render_apps_widget<B: Backend>(
+    admin_client: Option<Arc<Mutex<AdminClient>>>,
+    selected_cell: &mut Option<CellId>,
+    events: Vec<ScreenEvent>,
+    frame: &mut Frame<B>,
+    rect: Rect,
+) {
+    let admin_client = match admin_client {
+        Some(a) => a,
+        None => {
+            show_message("No admin URL configured, to use this screen please re-run the terminal with `--admin-url <my-url>`", frame, rect);
+            return;
+        }
+    };
+
+    let refresh = events.iter().any(|e| matches!(e, ScreenEvent::Refresh));
+    if refresh || APPS.read().unwrap().is_none() {
+        match get_apps(admin_client) {
+            Ok(apps) => *APPS.write().unwrap() = Some(apps),
+            Err(e) => {
+                show_message(format!("{:?}", e).as_str(), frame, rect);
+                return;
+            }
+        }
+    }
+
+    let apps = APPS.read().unwrap();
+    let rows = cell_rows(apps.as_deref().unwrap_or_default());
+
+    let mut selected = SELECTED.write().unwrap();
+    for event in &events {
+        navigate(event, &mut selected, rows.len());
+        if let (ScreenEvent::Select, Some(row)) = (event, rows.get(*selected)) {
+            *selected_cell = Some(row.cell_id.clone());
+        }
+    }
+
+    let content_layout = list_and_detail_layout(rect);
+
+    let list_items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| {
+            let marker = if selected_cell.as_ref() == Some(row.cell_id) {
+                "* "
+            } else {
+                "  "
+            };
+            ListItem::new(format!(
+                "{}{} / {} / {}",
+                marker,
+                row.app.installed_app_id,
+                row.role_name,
+                cell_name(row.cell_info)
+            ))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .block(
+            Block::default()
+                .title(" Cells (press enter to inspect a cell on the other screens) ")
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(Color::White))
+        .highlight_symbol(">> ");
+
+    let selected = if rows.is_empty() {
+        None
+    } else {
+        *selected = (*selected).min(rows.len() - 1);
+        let row = &rows[*selected];
+        let mut detail = vec![
+            format!("app            : {}", row.app.installed_app_id),
+            format!("app status     : {:?}", row.app.status),
+            format!("role           : {}", row.role_name),
+            format!("cell           : {}", cell_name(row.cell_info)),
+            format!("agent          : {}", row.cell_id.agent_pubkey()),
+            format!("dna hash       : {}", row.cell_id.dna_hash()),
+        ];
+        if let CellInfo::Cloned(clone) = row.cell_info {
+            detail.push(format!("clone id       : {}", clone.clone_id));
+            detail.push(format!("enabled        : {}", clone.enabled));
+        }
+        render_detail("Cell", detail.join("\n"), frame, content_layout[1]);
+
+        Some(*selected)
+    };
+
+    frame.render_stateful_widget(
+        list,
+        content_layout[0],
+        &mut ListState::default().with_selected(selected),
+    );
+}
+
+fn cell_rows(apps: &[AppInfo]) -> Vec<CellRow> {
+    let mut rows = vec![];
+    for app in apps {
+        let mut roles: Vec<_> = app.cell_info.iter().collect();
+        roles.sort_by_key(|(role_name, _)| role_name.as_str());
+        for (role_name, cells) in roles {
+            for cell_info in cells {
+                let cell_id = match cell_info {
+                    CellInfo::Provisioned(p) => &p.cell_id,
+                    CellInfo::Cloned(c) => &c.cell_id,
+                    CellInfo::Stem(_) => continue,
+                };
+                rows.push(CellRow {
+                    app,
+                    role_name,
+                    cell_info,
+                    cell_id,
+                });
+            }
+        }
+    }
+    rows
+}
+
+fn cell_name(cell_info: &CellInfo) -> String {
+    match cell_info {
+        CellInfo::Provisioned(p) => p.name.clone(),
+        CellInfo::Cloned(c) => format!("{} (clone {})", c.name, c.clone_id),
+        CellInfo::Stem(s) => s.name.clone().unwrap_or_default(),
+    }
+}
+
+fn get_apps(admin_client: Arc<Mutex<AdminClient>>) -> anyhow::Result<Vec<AppInfo>> {
+    match block_on(
+        async { admin_client.lock().await.list_apps().await },
+        Duration::from_secs(10),
+    ) {
+        Ok(Ok(mut apps)) => {
+            apps.sort_by(|a, b| a.installed_app_id.cmp(&b.installed_app_id));
+            Ok(apps)
+        }
+        Ok(Err(e)) => Err(anyhow!("Failed to list apps - {:?}", e)),
+        Err(_) => Err(anyhow!("Timeout while listing apps")),
+    }
+}
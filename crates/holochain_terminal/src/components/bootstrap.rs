@@ -83,6 +83,9 @@ pub fn render_bootstrap_widget<B: Backend>(
                     *selected -= 1;
                 }
             }
+            ScreenEvent::Select => {
+                // Ignored
+            }
         }
     }
 
@@ -0,0 +1,83 @@
+use crate::client::AdminClient;
+use anyhow::anyhow;
+use holochain_conductor_api::FullStateDump;
+use holochain_types::prelude::{CellId, SignedValidationReceipt};
+use holochain_util::tokio_helper::block_on;
+use kitsune_p2p_types::dependencies::tokio;
+use once_cell::sync::Lazy;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// The most recently fetched data for a cell, shared between screens so
+/// that it is only re-fetched on refresh or when a different cell is selected.
+type CellCache<T> = Lazy<RwLock<Option<(CellId, Arc<T>)>>>;
+
+static FULL_STATE: CellCache<FullStateDump> = Lazy::new(|| RwLock::new(None));
+static VALIDATION_RECEIPTS: CellCache<Vec<SignedValidationReceipt>> =
+    Lazy::new(|| RwLock::new(None));
+
+/// Get the full state dump of a cell, including its source chain and DHT ops
+pub fn get_full_state(
+    admin_client: Arc<Mutex<AdminClient>>,
+    cell_id: &CellId,
+    refresh: bool,
+) -> anyhow::Result<Arc<FullStateDump>> {
+    get_cached(
+        &FULL_STATE,
+        cell_id,
+        refresh,
+        "full state",
+        |cell_id| async move { admin_client.lock().await.dump_full_state(cell_id).await },
+    )
+}
+
+/// Get the validation receipts for the ops authored by a cell
+pub fn get_validation_receipts(
+    admin_client: Arc<Mutex<AdminClient>>,
+    cell_id: &CellId,
+    refresh: bool,
+) -> anyhow::Result<Arc<Vec<SignedValidationReceipt>>> {
+    get_cached(
+        &VALIDATION_RECEIPTS,
+        cell_id,
+        refresh,
+        "validation receipts",
+        |cell_id| async move {
+            admin_client
+                .lock()
+                .await
+                .dump_validation_receipts(cell_id)
+                .await
+        },
+    )
+}
+
+fn get_cached<T, F, Fut>(
+    cache: &CellCache<T>,
+    cell_id: &CellId,
+    refresh: bool,
+    what: &str,
+    fetch: F,
+) -> anyhow::Result<Arc<T>>
+where
+    F: FnOnce(CellId) -> Fut,
+    Fut: Future<Output = anyhow::Result<T>>,
+{
+    if !refresh {
+        if let Some((cached_cell_id, value)) = cache.read().unwrap().as_ref() {
+            if cached_cell_id == cell_id {
+                return Ok(value.clone());
+            }
+        }
+    }
+
+    let value = match block_on(fetch(cell_id.clone()), Duration::from_secs(10)) {
+        Ok(Ok(value)) => Arc::new(value),
+        Ok(Err(e)) => return Err(anyhow!("Failed to fetch {} - {:?}", what, e)),
+        Err(_) => return Err(anyhow!("Timeout while fetching {}", what)),
+    };
+    *cache.write().unwrap() = Some((cell_id.clone(), value.clone()));
+    Ok(value)
+}
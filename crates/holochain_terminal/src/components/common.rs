@@ -1,6 +1,72 @@
+use crate::event::ScreenEvent;
+use holochain_types::prelude::{Entry, RecordEntryRef};
 use ratatui::{prelude::*, widgets::*};
 
 pub fn show_message<B: Backend>(message: &str, frame: &mut Frame<B>, rect: Rect) {
     let p = Paragraph::new(message).block(Block::default());
     frame.render_widget(p, rect);
 }
+
+/// Move the selected row of a list up or down in response to a navigation event
+pub fn navigate(event: &ScreenEvent, selected: &mut usize, len: usize) {
+    match event {
+        ScreenEvent::NavDown => {
+            if *selected + 1 < len {
+                *selected += 1;
+            }
+        }
+        ScreenEvent::NavUp => {
+            if *selected > 0 {
+                *selected -= 1;
+            }
+        }
+        _ => {
+            // Ignored
+        }
+    }
+}
+
+/// Split a screen into a list on the left and details of the selected list item on the right
+pub fn list_and_detail_layout(rect: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(rect)
+}
+
+/// Render text describing the selected list item
+pub fn render_detail<B: Backend>(title: &str, text: String, frame: &mut Frame<B>, rect: Rect) {
+    let p = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title(format!(" {} ", title))
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(p, rect);
+}
+
+/// Describe an entry, decoding the MessagePack content of app entries
+pub fn format_entry(entry: &Entry) -> String {
+    match entry {
+        Entry::App(bytes) => {
+            let bytes = bytes.bytes();
+            match rmpv::decode::read_value(&mut bytes.as_slice()) {
+                Ok(value) => format!("App entry: {}", value),
+                Err(_) => format!("App entry: {:?}", bytes),
+            }
+        }
+        _ => format!("{:#?}", entry),
+    }
+}
+
+/// Describe the entry of a record or DHT op, if it has one
+pub fn format_record_entry(entry: RecordEntryRef) -> String {
+    match entry {
+        RecordEntryRef::Present(entry) => format_entry(entry),
+        RecordEntryRef::Hidden => "Entry is private".to_string(),
+        RecordEntryRef::NA => "No entry".to_string(),
+        RecordEntryRef::NotStored => "Entry is not stored".to_string(),
+    }
+}
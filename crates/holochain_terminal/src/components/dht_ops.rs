@@ -0,0 +1,121 @@
+use crate::client::AdminClient;
+use crate::components::cell_state::get_full_state;
+use crate::components::common::{
+    format_record_entry, list_and_detail_layout, navigate, render_detail, show_message,
+};
+use crate::event::ScreenEvent;
+use holochain_types::prelude::{CellId, DhtOp, DhtOpHash};
+use kitsune_p2p_types::dependencies::tokio;
+use once_cell::sync::Lazy;
+use ratatui::{prelude::*, widgets::*};
+use std::sync::{Arc, RwLock};
+use tokio::sync::Mutex;
+
+static SELECTED: Lazy<RwLock<usize>> = Lazy::new(|| RwLock::new(0));
+
+pub fn render_dht_ops_widget<B: Backend>(
+    admin_client: Option<Arc<Mutex<AdminClient>>>,
+    selected_cell: Option<&CellId>,
+    events: Vec<ScreenEvent>,
+    frame: &mut Frame<B>,
+    rect: Rect,
+) {
+    let admin_client = match admin_client {
+        Some(a) => a,
+        None => {
+            show_message("No admin URL configured, to use this screen please re-run the terminal with `--admin-url <my-url>`", frame, rect);
+            return;
+        }
+    };
+
+    let cell_id = match selected_cell {
+        Some(c) => c,
+        None => {
+            show_message(
+                "No cell selected, to use this screen please select a cell on the Apps screen",
+                frame,
+                rect,
+            );
+            return;
+        }
+    };
+
+    let refresh = events.iter().any(|e| matches!(e, ScreenEvent::Refresh));
+    let state = match get_full_state(admin_client, cell_id, refresh) {
+        Ok(state) => state,
+        Err(e) => {
+            show_message(format!("{:?}", e).as_str(), frame, rect);
+            return;
+        }
+    };
+    let dump = &state.integration_dump;
+    let ops: Vec<(&str, &DhtOp)> = dump
+        .validation_limbo
+        .iter()
+        .map(|op| ("validation limbo", op))
+        .chain(
+            dump.integration_limbo
+                .iter()
+                .map(|op| ("integration limbo", op)),
+        )
+        .chain(dump.integrated.iter().map(|op| ("integrated", op)))
+        .collect();
+
+    let mut selected = SELECTED.write().unwrap();
+    for event in &events {
+        navigate(event, &mut selected, ops.len());
+    }
+
+    let content_layout = list_and_detail_layout(rect);
+
+    let list_items: Vec<ListItem> = ops
+        .iter()
+        .map(|(status, op)| {
+            ListItem::new(format!(
+                "{:<17} {:<26} {}",
+                status,
+                op.get_type().to_string(),
+                op.action().action_type()
+            ))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " DHT ops ({} awaiting validation, {} awaiting integration, {} integrated) ",
+                    dump.validation_limbo.len(),
+                    dump.integration_limbo.len(),
+                    dump.integrated.len()
+                ))
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(Color::White))
+        .highlight_symbol(">> ");
+
+    let selected = if ops.is_empty() {
+        None
+    } else {
+        *selected = (*selected).min(ops.len() - 1);
+        let (status, op) = ops[*selected];
+        let detail = format!(
+            "hash      : {}\ntype      : {}\nstatus    : {}\nbasis     : {}\n\n{:#?}\n\n{}",
+            DhtOpHash::with_data_sync(op),
+            op.get_type(),
+            status,
+            op.dht_basis(),
+            op.action(),
+            format_record_entry(op.entry())
+        );
+        render_detail("DHT op", detail, frame, content_layout[1]);
+
+        Some(*selected)
+    };
+
+    frame.render_stateful_widget(
+        list,
+        content_layout[0],
+        &mut ListState::default().with_selected(selected),
+    );
+}
@@ -0,0 +1,108 @@
+use crate::client::AdminClient;
+use crate::components::cell_state::get_full_state;
+use crate::components::common::{
+    format_entry, list_and_detail_layout, navigate, render_detail, show_message,
+};
+use crate::event::ScreenEvent;
+use holochain_types::prelude::CellId;
+use kitsune_p2p_types::dependencies::tokio;
+use once_cell::sync::Lazy;
+use ratatui::{prelude::*, widgets::*};
+use std::sync::{Arc, RwLock};
+use tokio::sync::Mutex;
+
+static SELECTED: Lazy<RwLock<usize>> = Lazy::new(|| RwLock::new(0));
+
+pub fn render_source_chain_widget<B: Backend>(
+    admin_client: Option<Arc<Mutex<AdminClient>>>,
+    selected_cell: Option<&CellId>,
+    events: Vec<ScreenEvent>,
+    frame: &mut Frame<B>,
+    rect: Rect,
+) {
+    let admin_client = match admin_client {
+        Some(a) => a,
+        None => {
+            show_message("No admin URL configured, to use this screen please re-run the terminal with `--admin-url <my-url>`", frame, rect);
+            return;
+        }
+    };
+
+    let cell_id = match selected_cell {
+        Some(c) => c,
+        None => {
+            show_message(
+                "No cell selected, to use this screen please select a cell on the Apps screen",
+                frame,
+                rect,
+            );
+            return;
+        }
+    };
+
+    let refresh = events.iter().any(|e| matches!(e, ScreenEvent::Refresh));
+    let state = match get_full_state(admin_client, cell_id, refresh) {
+        Ok(state) => state,
+        Err(e) => {
+            show_message(format!("{:?}", e).as_str(), frame, rect);
+            return;
+        }
+    };
+    let records = &state.source_chain_dump.records;
+
+    let mut selected = SELECTED.write().unwrap();
+    for event in &events {
+        navigate(event, &mut selected, records.len());
+    }
+
+    let content_layout = list_and_detail_layout(rect);
+
+    let list_items: Vec<ListItem> = records
+        .iter()
+        .map(|r| {
+            ListItem::new(format!(
+                "{:>4} {:<16} {}",
+                r.action.action_seq(),
+                r.action.action_type().to_string(),
+                r.action_address
+            ))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Source chain ({} records, {} published ops) ",
+                    records.len(),
+                    state.source_chain_dump.published_ops_count
+                ))
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(Color::White))
+        .highlight_symbol(">> ");
+
+    let selected = if records.is_empty() {
+        None
+    } else {
+        *selected = (*selected).min(records.len() - 1);
+        let record = &records[*selected];
+        let entry = match &record.entry {
+            Some(entry) => format_entry(entry),
+            None => "No entry".to_string(),
+        };
+        let detail = format!(
+            "hash      : {}\nsignature : {:?}\n\n{:#?}\n\n{}",
+            record.action_address, record.signature, record.action, entry
+        );
+        render_detail("Record", detail, frame, content_layout[1]);
+
+        Some(*selected)
+    };
+
+    frame.render_stateful_widget(
+        list,
+        content_layout[0],
+        &mut ListState::default().with_selected(selected),
+    );
+}
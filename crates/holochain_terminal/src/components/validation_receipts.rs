@@ -0,0 +1,108 @@
+use crate::client::AdminClient;
+use crate::components::cell_state::get_validation_receipts;
+use crate::components::common::{list_and_detail_layout, navigate, render_detail, show_message};
+use crate::event::ScreenEvent;
+use holochain_types::prelude::CellId;
+use kitsune_p2p_types::dependencies::tokio;
+use once_cell::sync::Lazy;
+use ratatui::{prelude::*, widgets::*};
+use std::sync::{Arc, RwLock};
+use tokio::sync::Mutex;
+
+static SELECTED: Lazy<RwLock<usize>> = Lazy::new(|| RwLock::new(0));
+
+pub fn render_validation_receipts_widget<B: Backend>(
+    admin_client: Option<Arc<Mutex<AdminClient>>>,
+    selected_cell: Option<&CellId>,
+    events: Vec<ScreenEvent>,
+    frame: &mut Frame<B>,
+    rect: Rect,
+) {
+    let admin_client = match admin_client {
+        Some(a) => a,
+        None => {
+            show_message("No admin URL configured, to use this screen please re-run the terminal with `--admin-url <my-url>`", frame, rect);
+            return;
+        }
+    };
+
+    let cell_id = match selected_cell {
+        Some(c) => c,
+        None => {
+            show_message(
+                "No cell selected, to use this screen please select a cell on the Apps screen",
+                frame,
+                rect,
+            );
+            return;
+        }
+    };
+
+    let refresh = events.iter().any(|e| matches!(e, ScreenEvent::Refresh));
+    let receipts = match get_validation_receipts(admin_client, cell_id, refresh) {
+        Ok(receipts) => receipts,
+        Err(e) => {
+            show_message(format!("{:?}", e).as_str(), frame, rect);
+            return;
+        }
+    };
+
+    let mut selected = SELECTED.write().unwrap();
+    for event in &events {
+        navigate(event, &mut selected, receipts.len());
+    }
+
+    let content_layout = list_and_detail_layout(rect);
+
+    let list_items: Vec<ListItem> = receipts
+        .iter()
+        .map(|r| {
+            ListItem::new(format!(
+                "{:<9} {}",
+                format!("{:?}", r.receipt.validation_status),
+                r.receipt.dht_op_hash
+            ))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Validation receipts for authored ops ({}) ",
+                    receipts.len()
+                ))
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(Color::White))
+        .highlight_symbol(">> ");
+
+    let selected = if receipts.is_empty() {
+        None
+    } else {
+        *selected = (*selected).min(receipts.len() - 1);
+        let receipt = &receipts[*selected];
+        let mut detail = vec![
+            format!("op hash         : {}", receipt.receipt.dht_op_hash),
+            format!("status          : {:?}", receipt.receipt.validation_status),
+            format!("when integrated : {}", receipt.receipt.when_integrated),
+            "validators      :".to_string(),
+        ];
+        detail.extend(
+            receipt
+                .receipt
+                .validators
+                .iter()
+                .map(|validator| format!("  {}", validator)),
+        );
+        render_detail("Receipt", detail.join("\n"), frame, content_layout[1]);
+
+        Some(*selected)
+    };
+
+    frame.render_stateful_widget(
+        list,
+        content_layout[0],
+        &mut ListState::default().with_selected(selected),
+    );
+}
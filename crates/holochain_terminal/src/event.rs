@@ -7,6 +7,7 @@ pub enum ScreenEvent {
     SwitchNetwork,
     NavDown,
     NavUp,
+    Select,
 }
 
 pub fn handle_events(app: &mut App) -> anyhow::Result<()> {
@@ -25,6 +26,8 @@ pub fn handle_events(app: &mut App) -> anyhow::Result<()> {
                     app.push_event(ScreenEvent::NavDown)
                 } else if key.code == KeyCode::Up {
                     app.push_event(ScreenEvent::NavUp)
+                } else if key.code == KeyCode::Enter {
+                    app.push_event(ScreenEvent::Select)
                 }
             }
         }
@@ -43,7 +43,7 @@ fn main() -> anyhow::Result<()> {
         (None, None)
     };
 
-    let mut app = App::new(args, admin_client, app_client, 2);
+    let mut app = App::new(args, admin_client, app_client, 6);
 
     let backend = CrosstermBackend::new(io::stderr());
     let terminal = Terminal::new(backend)?;
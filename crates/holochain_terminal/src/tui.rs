@@ -1,6 +1,10 @@
 use crate::app::App;
+use crate::components::apps::render_apps_widget;
 use crate::components::bootstrap::render_bootstrap_widget;
+use crate::components::dht_ops::render_dht_ops_widget;
 use crate::components::network_info::render_network_info_widget;
+use crate::components::source_chain::render_source_chain_widget;
+use crate::components::validation_receipts::render_validation_receipts_widget;
 use crossterm::terminal;
 use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
 use ratatui::backend::Backend;
@@ -68,11 +72,18 @@ fn render<B: Backend>(app: &mut App, frame: &mut Frame<B>) {
         .constraints([Constraint::Length(2), Constraint::Min(0)])
         .split(frame.size());
 
-    let titles = ["Network", "Bootstrap"]
-        .iter()
-        .cloned()
-        .map(Line::from)
-        .collect();
+    let titles = [
+        "Network",
+        "Bootstrap",
+        "Apps",
+        "Source chain",
+        "DHT ops",
+        "Validation receipts",
+    ]
+    .iter()
+    .cloned()
+    .map(Line::from)
+    .collect();
     let tabs = Tabs::new(titles)
         .select(app.tab_index())
         .block(
@@ -96,6 +107,46 @@ fn render<B: Backend>(app: &mut App, frame: &mut Frame<B>) {
         1 => {
             render_bootstrap_widget(app.args(), events, frame, root_layout[1]);
         }
+        2 => {
+            let admin_client = app.admin_client();
+            render_apps_widget(
+                admin_client,
+                app.selected_cell_mut(),
+                events,
+                frame,
+                root_layout[1],
+            );
+        }
+        3 => {
+            let admin_client = app.admin_client();
+            render_source_chain_widget(
+                admin_client,
+                app.selected_cell(),
+                events,
+                frame,
+                root_layout[1],
+            );
+        }
+        4 => {
+            let admin_client = app.admin_client();
+            render_dht_ops_widget(
+                admin_client,
+                app.selected_cell(),
+                events,
+                frame,
+                root_layout[1],
+            );
+        }
+        5 => {
+            let admin_client = app.admin_client();
+            render_validation_receipts_widget(
+                admin_client,
+                app.selected_cell(),
+                events,
+                frame,
+                root_layout[1],
+            );
+        }
         _ => {
             panic!("Page not implemented");
         }
@@ -23,6 +23,8 @@ pub struct HostFnAccess {
     pub keystore: Permission,
     /// Access to deterministic keystore functions.
     pub keystore_deterministic: Permission,
+    /// Can read and write the zome's local key/value store
+    pub local_store: Permission,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -48,6 +50,7 @@ impl HostFnAccess {
         non_determinism: Permission,
         keystore: Permission,
         keystore_deterministic: Permission,
+        local_store: Permission,
     ) -> Self {
         Self {
             agent_info,
@@ -60,6 +63,7 @@ impl HostFnAccess {
             non_determinism,
             keystore,
             keystore_deterministic,
+            local_store,
         }
     }
     /// Allow all access
@@ -75,6 +79,7 @@ impl HostFnAccess {
             keystore_deterministic: Permission::Allow,
             bindings: Permission::Allow,
             bindings_deterministic: Permission::Allow,
+            local_store: Permission::Allow,
         }
     }
 
@@ -91,6 +96,7 @@ impl HostFnAccess {
             keystore_deterministic: Permission::Deny,
             bindings: Permission::Deny,
             bindings_deterministic: Permission::Deny,
+            local_store: Permission::Deny,
         }
     }
 }
@@ -83,6 +83,17 @@ pub struct UpdateCoordinatorsPayload {
     pub source: CoordinatorSource,
 }
 
+/// The instructions on how to update the coordinator zomes of an installed app
+/// from a new version of its bundle.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct UpdateAppPayload {
+    /// The app to update.
+    pub installed_app_id: InstalledAppId,
+    /// Where to find the new version of the app bundle.
+    #[serde(flatten)]
+    pub source: AppBundleSource,
+}
+
 /// The arguments to create a clone of an existing cell.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct CreateCloneCellPayload {
@@ -551,6 +562,38 @@ impl InstalledAppCommon {
         &self.role_assignments
     }
 
+    /// Replace the manifest, e.g. after the app was updated to a new version
+    /// of its bundle.
+    pub fn update_manifest(&mut self, manifest: AppManifest) {
+        self.manifest = manifest;
+    }
+
+    /// Set the storage arc policy for all cells of a role.
+    pub fn set_arc_policy(
+        &mut self,
+        role_name: &RoleName,
+        arc_policy: Option<ArcPolicy>,
+    ) -> AppResult<()> {
+        if let Some(arc_policy) = &arc_policy {
+            if !arc_policy.is_valid() {
+                return Err(AppError::InvalidArcPolicy(
+                    role_name.clone(),
+                    arc_policy.clone(),
+                ));
+            }
+        }
+        self.role_mut(role_name)?.set_arc_policy(arc_policy);
+        Ok(())
+    }
+
+    /// Get the storage arc policy of the role which the given cell belongs to.
+    pub fn arc_policy_for_cell(&self, cell_id: &CellId) -> Option<&ArcPolicy> {
+        self.role_assignments
+            .values()
+            .find(|role| role.contains_cell(cell_id))
+            .and_then(|role| role.arc_policy())
+    }
+
     /// Add a clone cell.
     pub fn add_clone(&mut self, role_name: &RoleName, cell_id: &CellId) -> AppResult<CloneId> {
         let app_role_assignment = self.role_mut(role_name)?;
@@ -776,6 +819,7 @@ impl InstalledAppCommon {
                     clone_limit: 256,
                     next_clone_index: 0,
                     disabled_clones: HashMap::new(),
+                    arc_policy: None,
                 };
                 (role_name, role)
             })
@@ -1023,6 +1067,10 @@ pub struct AppRoleAssignment {
     /// any longer and are not returned as part of the app info either.
     /// Disabled clone cells can be deleted through the Admin API.
     disabled_clones: HashMap<CloneId, CellId>,
+    /// The storage arc policy for all cells of this role, including clones.
+    /// Initially taken from the manifest, but may be changed through the Admin API.
+    #[serde(default)]
+    arc_policy: Option<ArcPolicy>,
 }
 
 impl AppRoleAssignment {
@@ -1035,6 +1083,7 @@ impl AppRoleAssignment {
             clones: HashMap::new(),
             next_clone_index: 0,
             disabled_clones: HashMap::new(),
+            arc_policy: None,
         }
     }
 
@@ -1077,6 +1126,23 @@ impl AppRoleAssignment {
         self.clones.len() as u32 == self.clone_limit
     }
 
+    /// Accessor
+    pub fn arc_policy(&self) -> Option<&ArcPolicy> {
+        self.arc_policy.as_ref()
+    }
+
+    /// Set the storage arc policy for all cells of this role.
+    pub fn set_arc_policy(&mut self, arc_policy: Option<ArcPolicy>) {
+        self.arc_policy = arc_policy;
+    }
+
+    /// Whether the cell belongs to this role, either as the base cell or as a clone.
+    pub fn contains_cell(&self, cell_id: &CellId) -> bool {
+        self.base_cell_id == *cell_id
+            || self.clones.values().any(|c| c == cell_id)
+            || self.disabled_clones.values().any(|c| c == cell_id)
+    }
+
     /// Transformer
     pub fn into_provisioned_cell(self) -> Option<CellId> {
         if self.is_provisioned {
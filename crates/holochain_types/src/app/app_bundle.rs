@@ -65,6 +65,12 @@ impl AppBundle {
         membrane_proofs: HashMap<RoleName, MembraneProof>,
     ) -> AppBundleResult<AppRoleResolution> {
         let AppManifestValidated { name: _, roles } = self.manifest().clone().validate()?;
+        let arc_policies: HashMap<RoleName, ArcPolicy> = self
+            .manifest()
+            .app_roles()
+            .into_iter()
+            .filter_map(|role| Some((role.name, role.arc_policy?)))
+            .collect();
         let bundle = Arc::new(self);
         let tasks = roles.into_iter().map(|(role_name, role)| async {
             let bundle = bundle.clone();
@@ -73,7 +79,7 @@ impl AppBundle {
                 bundle.resolve_cell(dna_store, role_name, role).await?,
             ))
         });
-        let resolution = futures::future::join_all(tasks)
+        let mut resolution = futures::future::join_all(tasks)
             .await
             .into_iter()
             .collect::<AppBundleResult<Vec<_>>>()?
@@ -127,10 +133,47 @@ impl AppBundle {
                 },
             )?;
 
+        for (role_name, role) in resolution.role_assignments.iter_mut() {
+            role.set_arc_policy(arc_policies.get(role_name).cloned());
+        }
+
         // let resolution = cells.into_iter();
         Ok(resolution)
     }
 
+    /// Resolve the DNA of every role of this bundle, with the modifiers given
+    /// in the manifest, without provisioning any cells.
+    ///
+    /// Roles which use an existing cell have no DNA in the bundle, so they are
+    /// left out.
+    pub async fn resolve_dnas(&self) -> AppBundleResult<HashMap<RoleName, DnaFile>> {
+        let AppManifestValidated { name: _, roles } = self.manifest().clone().validate()?;
+        let mut dnas = HashMap::new();
+        for (role_name, role) in roles {
+            let (location, modifiers) = match role {
+                AppRoleManifestValidated::Create {
+                    location,
+                    modifiers,
+                    ..
+                }
+                | AppRoleManifestValidated::CreateIfNotExists {
+                    location,
+                    modifiers,
+                    ..
+                }
+                | AppRoleManifestValidated::CloneOnly {
+                    location,
+                    modifiers,
+                    ..
+                } => (location, modifiers),
+                AppRoleManifestValidated::UseExisting { .. } => continue,
+            };
+            let (dna_file, _) = self.resolve_location(&location, modifiers).await?;
+            dnas.insert(role_name, dna_file);
+        }
+        Ok(dnas)
+    }
+
     async fn resolve_cell(
         &self,
         dna_store: &impl DnaStore,
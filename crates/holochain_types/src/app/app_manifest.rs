@@ -45,7 +45,7 @@ pub mod app_manifest_validated;
 mod current;
 mod error;
 
-pub use app_manifest_v1::{AppRoleDnaManifest, CellProvisioning};
+pub use app_manifest_v1::{AppRoleDnaManifest, ArcPolicy, CellProvisioning};
 pub use current::*;
 pub use error::*;
 
@@ -123,6 +123,7 @@ impl AppManifest {
                 AppRoleManifest {
                     name: role_name,
                     provisioning: None,
+                    arc_policy: None,
                     dna: AppRoleDnaManifest {
                         location: Some(mr_bundle::Location::Bundled(path)),
                         modifiers: Default::default(),
@@ -17,6 +17,7 @@ use super::{
 use crate::prelude::{RoleName, YamlProperties};
 use holo_hash::DnaHashB64;
 use holochain_zome_types::prelude::*;
+use kitsune_p2p_dht::prelude::{ArqClamping, ArqStrat};
 use std::collections::HashMap;
 
 /// Version 1 of the App manifest schema
@@ -61,6 +62,12 @@ pub struct AppRoleManifest {
     /// Declares where to find the DNA, and options to modify it before
     /// inclusion in a Cell
     pub dna: AppRoleDnaManifest,
+
+    /// How much of the DHT the cells of this role commit to holding.
+    /// If not set, the conductor-wide arc settings are used.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arc_policy: Option<ArcPolicy>,
 }
 
 impl AppRoleManifest {
@@ -70,6 +77,7 @@ impl AppRoleManifest {
             name,
             provisioning: Some(CellProvisioning::default()),
             dna: AppRoleDnaManifest::sample(),
+            arc_policy: None,
         }
     }
 }
@@ -169,6 +177,68 @@ impl Default for CellProvisioning {
     }
 }
 
+/// The storage arc policy for the cells of an app role, which determines how
+/// much of the DHT each cell commits to holding. This lets different apps
+/// running the same DNA on one conductor choose different storage commitments,
+/// e.g. a mobile client which holds nothing and an always-on server which
+/// holds everything.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "strategy")]
+#[cfg_attr(
+    feature = "fuzzing",
+    derive(arbitrary::Arbitrary, proptest_derive::Arbitrary)
+)]
+pub enum ArcPolicy {
+    /// Hold no data at all, relying on other peers for storage ("leech").
+    Empty,
+    /// Always hold the entire DHT.
+    Full,
+    /// Always hold this percentage of the DHT, regardless of network conditions.
+    Fixed {
+        /// The percentage of the DHT to hold, from 0 to 100.
+        percent: u8,
+    },
+    /// Resize the arc dynamically, aiming for a network coverage (redundancy)
+    /// between `min` and `max`.
+    Coverage {
+        /// The minimum number of peers which should hold any given location.
+        min: u32,
+        /// The maximum number of peers which should hold any given location.
+        max: u32,
+    },
+}
+
+impl ArcPolicy {
+    /// Whether the parameters of this policy make sense.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Empty | Self::Full => true,
+            Self::Fixed { percent } => *percent <= 100,
+            Self::Coverage { min, max } => *min > 0 && max >= min,
+        }
+    }
+
+    /// Apply this policy to the arc resizing strategy used for the rest of
+    /// the space.
+    pub fn to_arq_strat(&self, base: &ArqStrat) -> ArqStrat {
+        let mut strat = base.clone();
+        match self {
+            Self::Empty => strat.local_storage.arc_clamping = Some(ArqClamping::Empty),
+            Self::Full => strat.local_storage.arc_clamping = Some(ArqClamping::Full),
+            Self::Fixed { percent } => {
+                strat.local_storage.arc_clamping = Some(ArqClamping::Fixed(*percent as f64 / 100.0))
+            }
+            Self::Coverage { min, max } => {
+                strat.local_storage.arc_clamping = None;
+                strat.min_coverage = *min as f64;
+                strat.buffer = *max as f64 / *min as f64 - 1.0;
+            }
+        }
+        strat
+    }
+}
+
 impl AppManifestV1 {
     /// Update the network seed for all DNAs used in Create-provisioned Cells.
     /// Cells with other provisioning strategies are not affected.
@@ -202,7 +272,13 @@ impl AppManifestV1 {
                      name,
                      provisioning,
                      dna,
+                     arc_policy,
                  }| {
+                    if let Some(arc_policy) = arc_policy {
+                        if !arc_policy.is_valid() {
+                            return Err(AppManifestError::InvalidArcPolicy(name, arc_policy));
+                        }
+                    }
                     let AppRoleDnaManifest {
                         location,
                         installed_hash,
@@ -300,6 +376,7 @@ pub mod tests {
                 clone_limit: 50,
             },
             provisioning: Some(CellProvisioning::Create { deferred: false }),
+            arc_policy: None,
         }];
         AppManifestV1 {
             name: "Test app".to_string(),
@@ -367,6 +444,36 @@ roles:
         }
     }
 
+    #[tokio::test]
+    async fn manifest_v1_arc_policy() {
+        let location = Some(mr_bundle::Location::Path(PathBuf::from("/tmp/test.dna")));
+        let mut manifest =
+            app_manifest_fixture(location, fixt!(DnaHash), DnaModifiersOpt::none()).await;
+
+        let yaml = r#"
+strategy: coverage
+min: 10
+max: 20
+"#;
+        let arc_policy: ArcPolicy = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(arc_policy, ArcPolicy::Coverage { min: 10, max: 20 });
+        let strat = arc_policy.to_arq_strat(&ArqStrat::standard(Default::default()));
+        assert_eq!(strat.min_coverage, 10.0);
+        assert_eq!(strat.max_coverage(), 20.0);
+
+        manifest.roles[0].arc_policy = Some(arc_policy);
+        manifest.clone().validate().unwrap();
+
+        manifest.roles[0].arc_policy = Some(ArcPolicy::Fixed { percent: 101 });
+        assert_eq!(
+            manifest.validate().unwrap_err(),
+            AppManifestError::InvalidArcPolicy(
+                "role_name".into(),
+                ArcPolicy::Fixed { percent: 101 }
+            )
+        );
+    }
+
     #[tokio::test]
     async fn manifest_v1_set_network_seed() {
         let mut u = arbitrary::Unstructured::new(&[0]);
@@ -1,7 +1,7 @@
 use holochain_serialized_bytes::SerializedBytesError;
 use thiserror::Error;
 
-use crate::prelude::RoleName;
+use crate::prelude::{ArcPolicy, RoleName};
 
 #[allow(missing_docs)]
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -12,6 +12,9 @@ pub enum AppManifestError {
     #[error("Invalid manifest for app role '{0}': Using strategy 'clone-only' with clone_limit == 0 is pointless")]
     InvalidStrategyCloneOnly(RoleName),
 
+    #[error("Invalid manifest for app role '{0}': Invalid arc policy {1:?}")]
+    InvalidArcPolicy(RoleName, ArcPolicy),
+
     #[error(transparent)]
     SerializationError(#[from] SerializedBytesError),
 }
@@ -27,5 +27,8 @@ pub enum AppError {
 
     #[error("Tried to install app '{0}' which contains duplicate role names. The following role names have duplicates: {1:?}")]
     DuplicateRoleNames(InstalledAppId, Vec<RoleName>),
+
+    #[error("Invalid arc policy for app role '{0}': {1:?}")]
+    InvalidArcPolicy(RoleName, ArcPolicy),
 }
 pub type AppResult<T> = Result<T, AppError>;
@@ -127,6 +127,7 @@ impl DnaBundle {
                     },
                     integrity_zomes,
                     coordinator_zomes,
+                    lineage: manifest.lineage.iter().cloned().map(Into::into).collect(),
                 };
 
                 let original_hash = DnaHash::with_data_sync(&dna_def);
@@ -212,6 +213,7 @@ impl DnaBundle {
                 zomes: integrity,
             },
             coordinator: CoordinatorManifest { zomes: coordinator },
+            lineage: dna_def.lineage.into_iter().map(Into::into).collect(),
         }
         .into())
     }
@@ -252,6 +254,7 @@ pub(super) async fn hash_bytes(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
     use std::path::PathBuf;
 
     use super::*;
@@ -289,6 +292,7 @@ mod tests {
                 ],
             },
             coordinator: CoordinatorManifest { zomes: vec![] },
+            lineage: vec![DnaHash::from_raw_36(vec![0xdb; 36]).into()],
         };
         let resources = vec![(path1, wasm1.into()), (path2, wasm2.into())];
 
@@ -320,6 +324,12 @@ mod tests {
             .0;
         assert_eq!(dna_file.dna_def().integrity_zomes.len(), 2);
         assert_eq!(dna_file.code().len(), 2);
+        assert_eq!(
+            dna_file.dna_def().lineage,
+            [DnaHash::from_raw_36(vec![0xdb; 36])]
+                .into_iter()
+                .collect::<HashSet<_>>()
+        );
 
         // - Check that properties and UUID can be overridden
         let properties: YamlProperties = serde_yaml::Value::from(42).into();
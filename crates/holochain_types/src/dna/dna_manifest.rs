@@ -53,6 +53,7 @@ impl DnaManifest {
         origin_time: HumanTimestamp,
         integrity_zomes: Vec<ZomeManifest>,
         coordinator_zomes: Vec<ZomeManifest>,
+        lineage: Vec<DnaHashB64>,
     ) -> Self {
         DnaManifestCurrent::new(
             name,
@@ -60,6 +61,7 @@ impl DnaManifest {
             CoordinatorManifest {
                 zomes: coordinator_zomes,
             },
+            lineage,
         )
         .into()
     }
@@ -84,6 +86,13 @@ impl DnaManifest {
             DnaManifest::V1(manifest) => manifest.name.clone(),
         }
     }
+
+    /// Getter for lineage
+    pub fn lineage(&self) -> Vec<DnaHashB64> {
+        match self {
+            DnaManifest::V1(manifest) => manifest.lineage.clone(),
+        }
+    }
 }
 
 impl TryFrom<DnaManifest> for ValidatedDnaManifest {
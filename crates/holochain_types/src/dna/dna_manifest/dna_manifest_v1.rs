@@ -41,6 +41,9 @@ use serde_with::serde_as;
 /// When there's only one integrity zome, it will automatically be a dependency
 /// of the coordinator zomes. It doesn't need to be specified explicitly.
 ///
+/// A DNA which replaces previous versions of itself can list their hashes
+/// in its lineage.
+///
 /// ```yaml
 /// manifest_version: "1"
 /// name: single integrity dna
@@ -58,6 +61,20 @@ use serde_with::serde_as;
 ///     - name: zome4
 ///       bundled: ../dna2/zomes/zome2.wasm
 /// ```
+///
+/// ```yaml
+/// manifest_version: "1"
+/// name: upgraded dna
+/// integrity:
+///   network_seed: 00000000-0000-0000-0000-000000000000
+///   properties: ~
+///   origin_time: 2022-02-11T23:05:19.470323Z
+///   zomes:
+///     - name: zome1
+///       bundled: ../dna1/zomes/zome1.wasm
+/// lineage:
+///   - uhC0kWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm
+/// ```
 
 #[serde_as]
 #[derive(
@@ -85,6 +102,17 @@ pub struct DnaManifestV1 {
     ///
     /// Does not affect the [`DnaHash`].
     pub coordinator: CoordinatorManifest,
+
+    /// The hashes of previous versions of this DNA which this DNA supersedes.
+    ///
+    /// When a cell is created from this DNA, the conductor uses these to find
+    /// the agent's cells of previous versions, so that the app can read from
+    /// them while upgrading.
+    ///
+    /// Does not affect the [`DnaHash`].
+    #[serde(default)]
+    #[builder(default)]
+    pub lineage: Vec<DnaHashB64>,
 }
 
 impl DnaManifestV1 {
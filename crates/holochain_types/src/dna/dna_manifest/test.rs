@@ -50,6 +50,33 @@ coordinator:
     let _manifest: DnaManifest = serde_yaml::from_str(&manifest_yaml).unwrap();
 }
 
+#[test]
+fn can_deserialize_dna_manifest_lineage() {
+    let manifest_yaml = r#"
+---
+manifest_version: "1"
+name: test_dna
+integrity:
+  network_seed: blablabla
+  origin_time: 2022-02-11T23:29:00.789576Z
+  properties: ~
+  zomes:
+    - name: zome1
+      bundled: zome-1.wasm
+lineage:
+  - uhC0kWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm
+        "#;
+
+    let manifest: DnaManifest = serde_yaml::from_str(&manifest_yaml).unwrap();
+    assert_eq!(
+        manifest.lineage(),
+        vec![
+            DnaHashB64::from_b64_str("uhC0kWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm")
+                .unwrap()
+        ]
+    );
+}
+
 #[test]
 fn deserialize_dna_manifest_coordinator_only() {
     let manifest_yaml = r#"
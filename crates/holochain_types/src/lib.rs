@@ -28,6 +28,7 @@ pub mod entry;
 pub mod link;
 mod macros;
 pub mod metadata;
+pub mod op_archive;
 pub mod prelude;
 pub mod rate_limit;
 pub mod record;
@@ -0,0 +1,188 @@
+//! Types for moving DHT ops between conductors without going through the network.
+//!
+//! An [`OpArchive`] is a self-describing bundle of ops for a single DNA, which
+//! is signed by one of the exporting conductor's agents to produce a
+//! [`SignedOpArchive`]. The signed archive can be carried to another
+//! conductor by any means (e.g. on a USB stick) and imported there,
+//! where each op is validated as if it had been received over the network.
+
+use holo_hash::{AgentPubKey, DnaHash};
+use holochain_keystore::{AgentPubKeyExt, KeystoreError, LairResult, MetaLairClient};
+use holochain_serialized_bytes::prelude::*;
+use holochain_zome_types::prelude::*;
+use kitsune_p2p_dht::region::RegionCoords;
+
+use crate::dht_op::DhtOp;
+
+/// The version of the [`OpArchive`] format produced by this version of Holochain.
+pub const OP_ARCHIVE_VERSION: u16 = 1;
+
+/// Which ops to include when exporting an [`OpArchive`].
+///
+/// Only integrated ops are ever exported.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpExportFilter {
+    /// All ops held for the DNA.
+    All,
+    /// Ops whose action was authored within this window (both ends inclusive).
+    TimeWindow {
+        /// The earliest timestamp to include.
+        start: Timestamp,
+        /// The latest timestamp to include.
+        end: Timestamp,
+    },
+    /// Ops within any of these regions, as used by historical gossip.
+    /// The regions are interpreted according to the DNA's topology.
+    Regions(Vec<RegionCoords>),
+}
+
+/// A self-describing bundle of DHT ops for a single DNA.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpArchive {
+    /// The version of the archive format, see [`OP_ARCHIVE_VERSION`].
+    pub version: u16,
+    /// The DNA which all of the ops belong to.
+    pub dna_hash: DnaHash,
+    /// When the archive was exported.
+    pub exported_at: Timestamp,
+    /// The filter which was used to select the ops.
+    pub filter: OpExportFilter,
+    /// The ops themselves.
+    pub ops: Vec<DhtOp>,
+}
+
+impl OpArchive {
+    /// Construct a new archive of the current version, exported now.
+    pub fn new(dna_hash: DnaHash, filter: OpExportFilter, ops: Vec<DhtOp>) -> Self {
+        Self {
+            version: OP_ARCHIVE_VERSION,
+            dna_hash,
+            exported_at: Timestamp::now(),
+            filter,
+            ops,
+        }
+    }
+
+    /// Sign this archive with the given agent's key.
+    pub async fn sign(
+        self,
+        keystore: &MetaLairClient,
+        signer: AgentPubKey,
+    ) -> LairResult<SignedOpArchive> {
+        let signature = signer.sign(keystore, &self).await?;
+        Ok(SignedOpArchive {
+            archive: self,
+            signer,
+            signature,
+        })
+    }
+}
+
+/// An [`OpArchive`] along with the signature of the agent who exported it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedOpArchive {
+    /// The archive.
+    pub archive: OpArchive,
+    /// The agent who signed the archive.
+    pub signer: AgentPubKey,
+    /// The signer's signature of the msgpack-encoded archive.
+    pub signature: Signature,
+}
+
+impl SignedOpArchive {
+    /// Check that this archive is of a supported version and was signed by its signer.
+    ///
+    /// Note that this says nothing about the validity of the ops themselves,
+    /// which must still be validated individually.
+    pub async fn verify(&self) -> OpArchiveResult<()> {
+        if self.archive.version != OP_ARCHIVE_VERSION {
+            return Err(OpArchiveError::UnsupportedVersion(self.archive.version));
+        }
+        let data = holochain_serialized_bytes::encode(&self.archive)?;
+        if self
+            .signer
+            .verify_signature_raw(&self.signature, data.into())
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(OpArchiveError::InvalidSignature(self.signer.clone()))
+        }
+    }
+
+    /// Take the archive, discarding the signature.
+    pub fn into_archive(self) -> OpArchive {
+        self.archive
+    }
+}
+
+/// Errors involving an [`OpArchive`].
+#[derive(Debug, thiserror::Error)]
+pub enum OpArchiveError {
+    /// The archive was produced by an incompatible version of Holochain.
+    #[error("Unsupported op archive version: {0}. Expected version {OP_ARCHIVE_VERSION}")]
+    UnsupportedVersion(u16),
+
+    /// The signature does not match the archive contents.
+    #[error("The op archive signature is not valid for signer {0}")]
+    InvalidSignature(AgentPubKey),
+
+    /// The archive could not be serialized.
+    #[error(transparent)]
+    SerializedBytesError(#[from] SerializedBytesError),
+
+    /// The keystore failed to check the signature.
+    #[error(transparent)]
+    KeystoreError(#[from] KeystoreError),
+}
+
+/// Result type for [`OpArchiveError`].
+pub type OpArchiveResult<T> = Result<T, OpArchiveError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use ::fixt::prelude::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn signed_op_archive_roundtrip() {
+        let keystore = holochain_keystore::test_keystore();
+        let signer = keystore.new_sign_keypair_random().await.unwrap();
+        let archive = OpArchive::new(fixt!(DnaHash), OpExportFilter::All, vec![]);
+
+        let signed = archive.clone().sign(&keystore, signer).await.unwrap();
+        signed.verify().await.unwrap();
+
+        let signed: SignedOpArchive = holochain_serialized_bytes::decode(
+            &holochain_serialized_bytes::encode(&signed).unwrap(),
+        )
+        .unwrap();
+        signed.verify().await.unwrap();
+        assert_eq!(signed.into_archive(), archive);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn tampered_op_archive_is_rejected() {
+        let keystore = holochain_keystore::test_keystore();
+        let signer = keystore.new_sign_keypair_random().await.unwrap();
+        let archive = OpArchive::new(fixt!(DnaHash), OpExportFilter::All, vec![]);
+
+        let mut signed = archive.sign(&keystore, signer).await.unwrap();
+        signed.archive.filter = OpExportFilter::TimeWindow {
+            start: Timestamp::MIN,
+            end: Timestamp::MAX,
+        };
+        assert!(matches!(
+            signed.verify().await,
+            Err(OpArchiveError::InvalidSignature(_))
+        ));
+
+        signed.archive.version = OP_ARCHIVE_VERSION + 1;
+        assert!(matches!(
+            signed.verify().await,
+            Err(OpArchiveError::UnsupportedVersion(_))
+        ));
+    }
+}
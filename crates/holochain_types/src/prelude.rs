@@ -23,6 +23,7 @@ pub use crate::dna::*;
 pub use crate::entry::*;
 pub use crate::link::*;
 pub use crate::metadata::*;
+pub use crate::op_archive::*;
 pub use crate::rate_limit::*;
 pub use crate::record::*;
 pub use crate::signal::*;
@@ -18,12 +18,37 @@ pub const WASM_METERING_LIMIT: u64 = 100_000_000_000;
 /// We don't want tests to run forever, and it can take several minutes for 100 giga ops to run.
 pub const WASM_METERING_LIMIT: u64 = 10_000_000;
 
+/// The number of metering points which a single call into a wasm zome may use,
+/// by kind of call. Each operation costs one point.
+///
+/// A call which uses up its budget is trapped and fails with an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WasmMeteringLimits {
+    /// The budget for a zome function called by a client or another zome,
+    /// which is also used for any callback not listed below.
+    pub zome_call: u64,
+    /// The budget for each `validate` callback.
+    pub validation: u64,
+    /// The budget for each `init` callback.
+    pub init: u64,
+}
+
+impl Default for WasmMeteringLimits {
+    fn default() -> Self {
+        Self {
+            zome_call: WASM_METERING_LIMIT,
+            validation: WASM_METERING_LIMIT,
+            init: WASM_METERING_LIMIT,
+        }
+    }
+}
+
 /// Generate a Cranelift type (1 of 3 possible types) wasm compiler
 /// with Metering (use limits) in place.
 pub fn cranelift() -> Cranelift {
     let cost_function = |_operator: &wasmparser::Operator| -> u64 { 1 };
-    // @todo 100 giga-ops is totally arbitrary cutoff so we probably
-    // want to make the limit configurable somehow.
+    // This is only the initial limit of a new instance, the budget for each
+    // call is set before it runs according to the [`WasmMeteringLimits`].
     let metering = Arc::new(Metering::new(WASM_METERING_LIMIT, cost_function));
     let mut cranelift = Cranelift::default();
     cranelift.canonicalize_nans(true).push_middleware(metering);
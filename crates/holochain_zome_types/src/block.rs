@@ -42,7 +42,7 @@ impl From<kitsune_p2p_block::AgentSpaceBlockReason> for CellBlockReason {
 }
 
 /// Reason why we might want to block a node.
-#[derive(Clone, serde::Serialize, Debug)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Debug, Eq, PartialEq)]
 pub enum NodeBlockReason {
     Kitsune(kitsune_p2p_block::NodeBlockReason),
 }
@@ -54,7 +54,7 @@ impl From<kitsune_p2p_block::NodeBlockReason> for NodeBlockReason {
 }
 
 /// Reason why we might want to block an IP.
-#[derive(Clone, serde::Serialize, Debug)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Debug, Eq, PartialEq)]
 pub enum IpBlockReason {
     Kitsune(kitsune_p2p_block::IpBlockReason),
 }
@@ -65,12 +65,47 @@ impl From<kitsune_p2p_block::IpBlockReason> for IpBlockReason {
     }
 }
 
-/// The type to use for identifying blocking ipv4 addresses.
-type IpV4 = std::net::Ipv4Addr;
+/// The type to use for identifying blocked ipv4 and ipv6 addresses.
+type Ip = std::net::IpAddr;
+
+/// Encodes an [`Ip`] the way a bare [`std::net::Ipv4Addr`] or
+/// [`std::net::Ipv6Addr`] is encoded, so that block targets stored before
+/// ipv6 was supported still decode and still match when checking blocks.
+mod ip_serde {
+    use super::Ip;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    pub fn serialize<S: Serializer>(ip: &Ip, serializer: S) -> Result<S::Ok, S::Error> {
+        match ip {
+            Ip::V4(ip) => ip.serialize(serializer),
+            Ip::V6(ip) => ip.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ip, D::Error> {
+        if deserializer.is_human_readable() {
+            return Ip::deserialize(deserializer);
+        }
+        let octets = Vec::<u8>::deserialize(deserializer)?;
+        if let Ok(octets) = <[u8; 4]>::try_from(octets.as_slice()) {
+            Ok(octets.into())
+        } else if let Ok(octets) = <[u8; 16]>::try_from(octets.as_slice()) {
+            Ok(octets.into())
+        } else {
+            Err(serde::de::Error::invalid_length(
+                octets.len(),
+                &"4 octets for ipv4 or 16 octets for ipv6",
+            ))
+        }
+    }
+}
 
 /// Target of a block.
 /// Each target type has an ID and associated reason.
-#[derive(Clone, Debug)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Debug, Eq, PartialEq)]
 pub enum BlockTarget {
     /// Some cell did bad at the happ level.
     Cell(CellId, CellBlockReason),
@@ -78,7 +113,8 @@ pub enum BlockTarget {
     /// Some node is playing silly buggers.
     Node(kitsune_p2p_block::NodeId, NodeBlockReason),
     /// An entire college campus has it out for us.
-    Ip(IpV4, IpBlockReason),
+    /// Only enforced on direct (tx2) connections, where the remote ip is known.
+    Ip(#[serde(with = "ip_serde")] Ip, IpBlockReason),
 }
 
 impl From<kitsune_p2p_block::BlockTarget> for BlockTarget {
@@ -95,12 +131,12 @@ impl From<kitsune_p2p_block::BlockTarget> for BlockTarget {
     }
 }
 
-#[derive(Debug, serde::Serialize, Clone)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Eq, PartialEq)]
 pub enum BlockTargetId {
     Cell(CellId),
     NodeDna(kitsune_p2p_block::NodeId, DnaHash),
     Node(kitsune_p2p_block::NodeId),
-    Ip(IpV4),
+    Ip(#[serde(with = "ip_serde")] Ip),
 }
 
 impl From<kitsune_p2p_block::BlockTargetId> for BlockTargetId {
@@ -137,7 +173,7 @@ impl ToSql for BlockTargetId {
     }
 }
 
-#[derive(Debug, serde::Serialize, Clone)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Eq, PartialEq)]
 pub enum BlockTargetReason {
     Cell(CellBlockReason),
     NodeDna(NodeSpaceBlockReason),
@@ -167,6 +203,26 @@ impl From<BlockTarget> for BlockTargetReason {
     }
 }
 
+impl BlockTarget {
+    /// Rebuild a target from its ID and the reason it is blocked for,
+    /// if the reason is for that kind of target.
+    pub fn from_id_and_reason(id: BlockTargetId, reason: BlockTargetReason) -> Option<Self> {
+        match (id, reason) {
+            (BlockTargetId::Cell(cell_id), BlockTargetReason::Cell(reason)) => {
+                Some(Self::Cell(cell_id, reason))
+            }
+            (BlockTargetId::NodeDna(node_id, dna), BlockTargetReason::NodeDna(reason)) => {
+                Some(Self::NodeDna(node_id, dna, reason))
+            }
+            (BlockTargetId::Node(node_id), BlockTargetReason::Node(reason)) => {
+                Some(Self::Node(node_id, reason))
+            }
+            (BlockTargetId::Ip(ip), BlockTargetReason::Ip(reason)) => Some(Self::Ip(ip, reason)),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a block.
 /// Also can represent an unblock.
 /// NOT serializable and NOT pub fields by design. `try_new` MUST be the only
@@ -210,3 +266,62 @@ impl Block {
         self.interval.end()
     }
 }
+
+/// A block as it is stored by the conductor, for reporting blocks to admins.
+/// Unlike [`Block`] this is serializable, and it can't be used to block or
+/// unblock anything.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct BlockSpan {
+    /// Target of the block.
+    pub target: BlockTarget,
+    /// Start of the block, inclusive.
+    pub start: Timestamp,
+    /// End of the block, inclusive.
+    pub end: Timestamp,
+}
+
+impl From<Block> for BlockSpan {
+    fn from(block: Block) -> Self {
+        Self {
+            start: block.start(),
+            end: block.end(),
+            target: block.target,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// [`BlockTargetId`] as it was when only ipv4 could be blocked.
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    enum Ipv4BlockTargetId {
+        Cell(CellId),
+        NodeDna(kitsune_p2p_block::NodeId, DnaHash),
+        Node(kitsune_p2p_block::NodeId),
+        Ip(std::net::Ipv4Addr),
+    }
+
+    #[test]
+    fn ipv4_block_target_id_encoding_is_unchanged() {
+        let ipv4 = std::net::Ipv4Addr::new(192, 168, 0, 1);
+        let old = holochain_serialized_bytes::encode(&Ipv4BlockTargetId::Ip(ipv4)).unwrap();
+        let new = holochain_serialized_bytes::encode(&BlockTargetId::Ip(ipv4.into())).unwrap();
+        assert_eq!(old, new);
+        assert_eq!(
+            BlockTargetId::Ip(ipv4.into()),
+            holochain_serialized_bytes::decode::<_, BlockTargetId>(&old).unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_block_target_id_round_trip() {
+        let ipv6 = BlockTargetId::Ip(std::net::Ipv6Addr::LOCALHOST.into());
+        let encoded = holochain_serialized_bytes::encode(&ipv6).unwrap();
+        assert_eq!(
+            ipv6,
+            holochain_serialized_bytes::decode::<_, BlockTargetId>(&encoded).unwrap()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! Defines DnaDef struct
 
 use crate::prelude::*;
+use std::collections::HashSet;
 
 #[cfg(feature = "full-dna-def")]
 use holochain_integrity_types::info::DnaModifiersBuilder;
@@ -49,6 +50,12 @@ pub struct DnaDef {
     /// A vector of zomes that do not affect
     /// the [`DnaHash`].
     pub coordinator_zomes: CoordinatorZomes,
+
+    /// The hashes of previous versions of this DNA which this DNA supersedes.
+    /// Does not affect the [`DnaHash`].
+    #[serde(default)]
+    #[cfg_attr(feature = "full-dna-def", builder(default))]
+    pub lineage: HashSet<DnaHash>,
 }
 
 #[derive(Serialize, Debug, PartialEq, Eq)]
@@ -742,6 +742,7 @@ fixturator!(
         coordinator_zomes: CoordinatorZomesFixturator::new_indexed(Empty, get_fixt_index!())
             .next()
             .unwrap(),
+        lineage: Default::default(),
     };
 
     curve Unpredictable DnaDef {
@@ -764,6 +765,7 @@ fixturator!(
         coordinator_zomes: CoordinatorZomesFixturator::new_indexed(Empty, get_fixt_index!())
             .next()
             .unwrap(),
+        lineage: Default::default(),
     };
 
     curve Predictable DnaDef {
@@ -786,6 +788,7 @@ fixturator!(
         coordinator_zomes: CoordinatorZomesFixturator::new_indexed(Empty, get_fixt_index!())
             .next()
             .unwrap(),
+        lineage: Default::default(),
     };
 );
 
@@ -893,6 +896,7 @@ fixturator!(
         zome_names: vec![ZomeNameFixturator::new_indexed(Empty, get_fixt_index!())
             .next()
             .unwrap()],
+        lineage: Default::default(),
     };
 
     curve Unpredictable DnaInfo {
@@ -908,6 +912,7 @@ fixturator!(
         zome_names: vec![ZomeNameFixturator::new_indexed(Unpredictable, get_fixt_index!())
             .next()
             .unwrap()],
+        lineage: Default::default(),
     };
 
     curve Predictable DnaInfo {
@@ -923,5 +928,6 @@ fixturator!(
         zome_names: vec![ZomeNameFixturator::new_indexed(Predictable, get_fixt_index!())
             .next()
             .unwrap()],
+        lineage: Default::default(),
     };
 );
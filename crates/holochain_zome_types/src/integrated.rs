@@ -0,0 +1,15 @@
+//! Types for the `on_integrated` callback.
+
+use crate::prelude::*;
+
+/// An op that this agent has integrated as an authority for some part of the
+/// DHT, passed to the `on_integrated` callback so that coordinators can build
+/// local indexes over the data they hold.
+#[derive(Clone, PartialEq, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct IntegratedOp {
+    /// The op that was integrated.
+    pub op: Op,
+    /// The validation status the op was integrated with.
+    /// Rejected and abandoned ops are integrated as well as valid ones.
+    pub validation_status: ValidationStatus,
+}
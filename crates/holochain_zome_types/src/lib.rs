@@ -36,15 +36,18 @@ pub mod hash;
 pub mod info;
 #[allow(missing_docs)]
 pub mod init;
+pub mod integrated;
 pub mod judged;
 #[allow(missing_docs)]
 pub mod link;
+pub mod local_store;
 pub mod metadata;
 #[allow(missing_docs)]
 pub mod migrate_agent;
 #[allow(missing_docs)]
 pub mod op;
 pub mod prelude;
+pub mod private_entry;
 #[cfg(feature = "properties")]
 pub mod properties;
 pub mod query;
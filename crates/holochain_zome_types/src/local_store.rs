@@ -0,0 +1,23 @@
+//! Types for a zome's local key/value store.
+//!
+//! The store belongs to the agent's cell and is only visible to the zome that
+//! writes to it. It is not part of the source chain, so nothing in it is
+//! gossiped or validated.
+
+use crate::prelude::*;
+
+/// Store a value under a key in the calling zome's local store.
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct LocalPutInput {
+    /// The key to store the value under.
+    pub key: Bytes,
+    /// The value, replacing any value already stored under the key.
+    pub value: Bytes,
+}
+
+impl LocalPutInput {
+    /// Constructor.
+    pub fn new(key: Bytes, value: Bytes) -> Self {
+        Self { key, value }
+    }
+}
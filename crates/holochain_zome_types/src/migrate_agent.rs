@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use holochain_wasmer_common::*;
 
-#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[derive(Clone, PartialEq, Serialize, Deserialize, SerializedBytes, Debug)]
 pub enum MigrateAgent {
     Open,
     Close,
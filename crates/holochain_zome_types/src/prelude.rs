@@ -18,11 +18,14 @@ pub use crate::genesis::*;
 pub use crate::hash::*;
 pub use crate::info::*;
 pub use crate::init::*;
+pub use crate::integrated::*;
 pub use crate::judged::*;
 pub use crate::link::*;
+pub use crate::local_store::*;
 pub use crate::metadata::*;
 pub use crate::migrate_agent::*;
 pub use crate::op::*;
+pub use crate::private_entry::*;
 #[cfg(feature = "properties")]
 pub use crate::properties::*;
 pub use crate::query::ChainQueryFilter as QueryFilter;
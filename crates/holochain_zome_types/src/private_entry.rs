@@ -0,0 +1,37 @@
+//! Types for sharing private entries with chosen agents.
+
+use crate::prelude::*;
+use holo_hash::ActionHash;
+use holo_hash::AgentPubKey;
+
+/// Share a private entry from the caller's own source chain with some agents.
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct SharePrivateEntryInput {
+    /// The hash of the action that created the private entry.
+    pub action_hash: ActionHash,
+    /// The caller's X25519 public key to seal the record with.
+    /// The secret key must be held in the caller's keystore.
+    pub sender: X25519PubKey,
+    /// The agents to share the entry with, each with the X25519 public key
+    /// the record will be sealed for.
+    pub recipients: Vec<(AgentPubKey, X25519PubKey)>,
+}
+
+/// A private record sealed for a single recipient.
+///
+/// This is the payload delivered to the recipient's `recv_private_entry`
+/// function by `share_private_entry`, and is opened with `open_private_entry`.
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct SealedPrivateEntry {
+    /// The author of the record.
+    pub author: AgentPubKey,
+    /// The hash of the record's action, which the recipient can fetch from
+    /// the DHT with `must_get_action`.
+    pub action_hash: ActionHash,
+    /// The X25519 public key the record was sealed with.
+    pub sender: X25519PubKey,
+    /// The X25519 public key the record was sealed for.
+    pub recipient: X25519PubKey,
+    /// The record, including its private entry, encrypted for the recipient.
+    pub sealed_record: XSalsa20Poly1305EncryptedData,
+}
@@ -81,6 +81,13 @@ pub struct ChainQueryFilter {
     /// The query should be ordered in descending order (default is ascending),
     /// when run as a database query. There is no provisioning for in-memory ordering.
     pub order_descending: bool,
+    /// Skip this many of the matching actions, in the requested order,
+    /// before returning any. Used with `limit` to page through long chains.
+    #[serde(default)]
+    pub offset: Option<u32>,
+    /// Return at most this many of the matching actions, in the requested order.
+    #[serde(default)]
+    pub limit: Option<u32>,
 }
 
 /// A query for links to be used with host functions that support filtering links
@@ -252,12 +259,49 @@ impl ChainQueryFilter {
         self
     }
 
-    /// Set the order to ascending.
+    /// Set the order to descending.
     pub fn descending(mut self) -> Self {
         self.order_descending = true;
         self
     }
 
+    /// Skip the first `offset` matching actions.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Return at most `limit` matching actions.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Is this query restricted to a page of the matching actions?
+    pub fn is_paginated(&self) -> bool {
+        self.offset.is_some() || self.limit.is_some()
+    }
+
+    /// Apply `offset` and `limit` to actions that have already been filtered.
+    /// The actions are ordered by sequence number according to
+    /// `order_descending` before the page is taken, so that the same page is
+    /// selected regardless of the order the actions were found in.
+    fn paginate(&self, mut actions: Vec<ActionHashed>) -> Vec<ActionHashed> {
+        if !self.is_paginated() {
+            return actions;
+        }
+        if self.order_descending {
+            actions.sort_by_key(|action| std::cmp::Reverse(action.action_seq()));
+        } else {
+            actions.sort_by_key(|action| action.action_seq());
+        }
+        actions
+            .into_iter()
+            .skip(self.offset.unwrap_or(0) as usize)
+            .take(self.limit.map_or(usize::MAX, |limit| limit as usize))
+            .collect()
+    }
+
     /// If the sequence range supports fork disambiguation, apply it to remove
     /// actions that are not in the correct branch.
     /// Numerical range bounds do NOT support fork disambiguation, and neither
@@ -317,7 +361,8 @@ impl ChainQueryFilter {
 
     /// Filter a vector of hashed actions according to the query.
     pub fn filter_actions(&self, actions: Vec<ActionHashed>) -> Vec<ActionHashed> {
-        self.disambiguate_forks(actions)
+        let actions = self
+            .disambiguate_forks(actions)
             .into_iter()
             .filter(|action| {
                 self.action_type
@@ -343,7 +388,8 @@ impl ChainQueryFilter {
                         })
                         .unwrap_or(true)
             })
-            .collect()
+            .collect();
+        self.paginate(actions)
     }
 
     /// Filter a vector of records according to the query.
@@ -702,4 +748,54 @@ mod tests {
             [true, false, false, false, true, true, false].to_vec()
         );
     }
+
+    #[test]
+    fn filter_by_page() {
+        let actions = fixtures();
+
+        // The fork at seq 3 means there are two actions at that position.
+        assert_eq!(
+            map_query(&ChainQueryFilter::new().limit(3), &actions),
+            [true, true, true, false, false, false, false].to_vec()
+        );
+        assert_eq!(
+            map_query(&ChainQueryFilter::new().offset(2).limit(3), &actions),
+            [false, false, true, true, true, false, false].to_vec()
+        );
+        assert_eq!(
+            map_query(&ChainQueryFilter::new().offset(5), &actions),
+            [false, false, false, false, false, true, true].to_vec()
+        );
+        assert_eq!(
+            map_query(&ChainQueryFilter::new().descending().limit(2), &actions),
+            [false, false, false, false, false, true, true].to_vec()
+        );
+        assert_eq!(
+            map_query(&ChainQueryFilter::new().offset(7), &actions),
+            [false; 7].to_vec()
+        );
+
+        // The page is taken after the other filters are applied.
+        assert_eq!(
+            map_query(
+                &ChainQueryFilter::new()
+                    .entry_type(actions[0].entry_type().unwrap().clone())
+                    .offset(1)
+                    .limit(1),
+                &actions
+            ),
+            [false, false, false, false, true, false, false].to_vec()
+        );
+
+        // Pages come back in the requested order.
+        let page = ChainQueryFilter::new()
+            .descending()
+            .offset(1)
+            .limit(3)
+            .filter_actions(actions.to_vec());
+        assert_eq!(
+            page.iter().map(|a| a.action_seq()).collect::<Vec<_>>(),
+            vec![4, 3, 3]
+        );
+    }
 }
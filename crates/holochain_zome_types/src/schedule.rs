@@ -42,13 +42,21 @@ impl From<Duration> for Schedule {
 }
 
 /// A fully qualified scheduled function.
+/// The same function may be scheduled many times under different names, each
+/// with its own schedule and payload. Functions scheduled with `schedule`
+/// have the default empty name.
 #[derive(Debug, Clone, PartialEq)]
-pub struct ScheduledFn(ZomeName, FunctionName);
+pub struct ScheduledFn(ZomeName, FunctionName, String);
 
 impl ScheduledFn {
     /// Constructor.
     pub fn new(zome_name: ZomeName, fn_name: FunctionName) -> Self {
-        Self(zome_name, fn_name)
+        Self(zome_name, fn_name, String::new())
+    }
+
+    /// Constructor for a named schedule of a function.
+    pub fn named(zome_name: ZomeName, fn_name: FunctionName, name: String) -> Self {
+        Self(zome_name, fn_name, name)
     }
 
     /// ZomeName accessor.
@@ -60,4 +68,63 @@ impl ScheduledFn {
     pub fn fn_name(&self) -> &FunctionName {
         &self.1
     }
+
+    /// Schedule name accessor.
+    pub fn name(&self) -> &str {
+        &self.2
+    }
+}
+
+/// Input to `schedule_with_payload`.
+#[derive(serde::Serialize, serde::Deserialize, SerializedBytes, Debug, PartialEq, Clone)]
+pub struct ScheduleWithPayloadInput {
+    /// The function in the current zome to schedule.
+    pub fn_name: FunctionName,
+    /// The name of this schedule, unique per function.
+    /// Scheduling the same function under an existing name replaces the
+    /// schedule and payload.
+    pub name: String,
+    /// The payload the function will be called with.
+    pub payload: ExternIO,
+    /// When to first trigger the function.
+    /// `None` triggers it on the next iteration of the scheduler.
+    pub schedule: Option<Schedule>,
+}
+
+/// The input to a function scheduled with `schedule_with_payload`.
+#[derive(serde::Serialize, serde::Deserialize, SerializedBytes, Debug, PartialEq, Clone)]
+pub struct ScheduledFnInput {
+    /// The name the function was scheduled under.
+    pub name: String,
+    /// The schedule that triggered this call, `None` on the initial trigger.
+    pub schedule: Option<Schedule>,
+    /// The payload the function was scheduled with.
+    pub payload: ExternIO,
+}
+
+/// Input to `cancel_schedule`.
+#[derive(serde::Serialize, serde::Deserialize, SerializedBytes, Debug, PartialEq, Clone)]
+pub struct CancelScheduleInput {
+    /// The scheduled function in the current zome.
+    pub fn_name: FunctionName,
+    /// The name it was scheduled under.
+    pub name: String,
+}
+
+/// A pending schedule of a function for some cell.
+#[derive(serde::Serialize, serde::Deserialize, SerializedBytes, Debug, PartialEq, Clone)]
+pub struct ScheduledFnInfo {
+    /// The zome of the scheduled function.
+    pub zome_name: ZomeName,
+    /// The scheduled function.
+    pub fn_name: FunctionName,
+    /// The name the function was scheduled under.
+    pub name: String,
+    /// The current schedule, `None` if it will run once on the next iteration
+    /// of the scheduler.
+    pub schedule: Option<Schedule>,
+    /// The payload if the function was scheduled with one.
+    pub payload: Option<ExternIO>,
+    /// The earliest time the function will next be triggered.
+    pub next_trigger: Timestamp,
 }
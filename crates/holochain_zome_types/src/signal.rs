@@ -29,3 +29,31 @@ pub struct RemoteSignal {
     /// The signal to send.
     pub signal: ExternIO,
 }
+
+/// Remote signal many agents and wait for each of them to acknowledge delivery.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct AcknowledgedRemoteSignal {
+    /// Agents to send the signal to.
+    pub agents: Vec<AgentPubKey>,
+    /// The signal to send.
+    pub signal: ExternIO,
+    /// How long to wait for each agent to acknowledge the signal.
+    pub timeout: std::time::Duration,
+}
+
+/// The outcome of sending an acknowledged remote signal to a single agent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RemoteSignalDelivery {
+    /// The recipient's conductor received the signal and handed it to
+    /// `recv_remote_signal`.
+    Delivered,
+    /// The recipient could not be reached over the network.
+    Offline,
+    /// The recipient's conductor has blocked the sender.
+    Blocked,
+    /// The recipient refused the signal, e.g. because there is no cap grant
+    /// for `recv_remote_signal`.
+    Unauthorized,
+    /// The recipient did not acknowledge the signal in time.
+    Timeout,
+}
@@ -53,6 +53,9 @@ wasm_io_types! {
 
     fn call (Vec<zt::call::Call>) -> Vec<zt::prelude::ZomeCallResponse>;
 
+    // Call a zome function on a network agent, streaming a large payload and response.
+    fn call_remote_streamed (zt::call::Call) -> zt::prelude::ZomeCallResponse;
+
     // @todo List all the local capability claims.
     fn capability_claims (()) -> ();
 
@@ -101,6 +104,18 @@ wasm_io_types! {
     // Hash data on the host.
     fn hash (zt::hash::HashInput) -> zt::hash::HashOutput;
 
+    // Delete a key from the zome's local key/value store.
+    fn local_delete (zt::bytes::Bytes) -> bool;
+
+    // Get a value from the zome's local key/value store.
+    fn local_get (zt::bytes::Bytes) -> Option<zt::bytes::Bytes>;
+
+    // Store a value in the zome's local key/value store.
+    fn local_put (zt::local_store::LocalPutInput) -> ();
+
+    // Get the key/value pairs in the zome's local store that have a key prefix.
+    fn local_scan_prefix (zt::bytes::Bytes) -> Vec<(zt::bytes::Bytes, zt::bytes::Bytes)>;
+
     // Retreive a record from the DHT or short circuit.
     fn must_get_valid_record (zt::entry::MustGetValidRecordInput) -> zt::record::Record;
 
@@ -112,6 +127,9 @@ wasm_io_types! {
 
     fn must_get_agent_activity (zt::chain::MustGetAgentActivityInput) -> Vec<zt::op::RegisterAgentActivity>;
 
+    // Open a private record shared by another agent and check its integrity.
+    fn open_private_entry (zt::private_entry::SealedPrivateEntry) -> zt::record::Record;
+
     // Query the source chain for data.
     fn query (zt::query::ChainQueryFilter) -> Vec<crate::prelude::Record>;
 
@@ -121,12 +139,27 @@ wasm_io_types! {
     // Remotely signal many agents without waiting for responses
     fn send_remote_signal (zt::signal::RemoteSignal) -> ();
 
+    // Remotely signal many agents and wait for each to acknowledge delivery
+    fn send_remote_signal_acknowledged (zt::signal::AcknowledgedRemoteSignal) -> Vec<(AgentPubKey, zt::signal::RemoteSignalDelivery)>;
+
+    // Seal a private entry for some agents and deliver it to each of them
+    fn share_private_entry (zt::private_entry::SharePrivateEntryInput) -> Vec<(AgentPubKey, zt::prelude::ZomeCallResponse)>;
+
     // // @todo
     // fn send (()) -> ();
 
     // Schedule a schedulable function if it is not already.
     fn schedule (String) -> ();
 
+    // Schedule a named instance of a function with a payload
+    fn schedule_with_payload (zt::schedule::ScheduleWithPayloadInput) -> ();
+
+    // List the pending schedules of the current zome
+    fn list_schedules (()) -> Vec<zt::schedule::ScheduledFnInfo>;
+
+    // Cancel a pending schedule of a function in the current zome
+    fn cancel_schedule (zt::schedule::CancelScheduleInput) -> ();
+
     // @todo
     fn sleep (core::time::Duration) -> ();
 
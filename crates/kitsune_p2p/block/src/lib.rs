@@ -7,7 +7,7 @@ pub enum AgentSpaceBlockReason {
     BadCrypto,
 }
 
-#[derive(Clone, serde::Serialize, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Debug, Eq, PartialEq, Hash)]
 pub enum NodeBlockReason {
     /// The node did some bad cryptography.
     BadCrypto,
@@ -15,12 +15,12 @@ pub enum NodeBlockReason {
     DOS,
 }
 
-#[derive(Clone, serde::Serialize, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Debug, Eq, PartialEq, Hash)]
 pub enum NodeSpaceBlockReason {
     BadWire,
 }
 
-#[derive(Clone, serde::Serialize, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Debug, Eq, PartialEq, Hash)]
 pub enum IpBlockReason {
     /// Classic DOS.
     DOS,
@@ -36,13 +36,15 @@ pub enum BlockTarget {
         Arc<kitsune_p2p_bin_data::KitsuneSpace>,
         NodeSpaceBlockReason,
     ),
-    Ip(std::net::Ipv4Addr, IpBlockReason),
+    /// Only enforced on direct tx2 connections. Over tx5 the remote address
+    /// is hidden behind the WebRTC signal server, so ip blocks don't apply.
+    Ip(std::net::IpAddr, IpBlockReason),
 }
 
 pub enum BlockTargetId {
     Node(NodeId),
     NodeSpace(NodeId, Arc<kitsune_p2p_bin_data::KitsuneSpace>),
-    Ip(std::net::Ipv4Addr),
+    Ip(std::net::IpAddr),
 }
 
 impl From<BlockTarget> for BlockTargetId {
@@ -3,7 +3,7 @@ use num_traits::Zero;
 
 use crate::spacetime::{SpaceOffset, Topology};
 
-use super::{is_full, Arq, ArqClamping, ArqStrat};
+use super::{approximate_arq, is_full, Arq, ArqClamping, ArqStrat, U32_LEN};
 
 /// A "view" of the peers in a neighborhood. The view consists of a few
 /// observations about the distribution of peers within a particular arc, used
@@ -31,6 +31,15 @@ impl PeerView {
             }
         }
     }
+
+    /// Replace the strategy used to update arcs with this view, keeping the
+    /// observed peers. This lets a single agent use a different strategy
+    /// than the rest of the space.
+    pub fn with_strat(self, strat: ArqStrat) -> Self {
+        match self {
+            Self::Quantized(v) => Self::Quantized(PeerViewQ { strat, ..v }),
+        }
+    }
 }
 
 /// The Quantized PeerView
@@ -131,6 +140,17 @@ impl PeerViewQ {
                 *arq = Arq::new_full(topo, arq.start, topo.max_space_power(strat));
                 changed
             }
+            Some(ArqClamping::Fixed(coverage)) => {
+                let target = if coverage >= 1.0 {
+                    Arq::new_full(topo, arq.start, topo.max_space_power(strat))
+                } else {
+                    let len = (coverage.max(0.0) * U32_LEN as f64) as u64;
+                    approximate_arq(topo, strat, arq.start, len)
+                };
+                let changed = *arq != target;
+                *arq = target;
+                changed
+            }
             None => self.update_arq_with_stats(arq).changed,
         }
     }
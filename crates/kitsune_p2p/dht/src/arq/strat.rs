@@ -212,4 +212,6 @@ pub enum ArqClamping {
     Empty,
     /// Clamp all arqs to be full, and never shrink them.
     Full,
+    /// Clamp all arqs to cover this fraction of the DHT, between 0 and 1.
+    Fixed(f64),
 }
@@ -45,6 +45,14 @@ impl TelescopingTimes {
         Self::new(time_quantum + 1.into())
     }
 
+    /// Get TelescopingTimes from the origin time up until now, for gossip which
+    /// covers all of time (i.e. full-sync gossip, which runs without Recent gossip).
+    pub fn up_to_now(topo: &Topology) -> Self {
+        let time_quantum = TimeQuantum::from_timestamp(topo, Timestamp::now());
+        // Add 1 time quantum to "round up" so that the most recent region contains now.
+        Self::new(time_quantum + 1.into())
+    }
+
     /// Calculate the exponentially expanding time segments using the binary
     /// representation of the current timestamp.
     ///
@@ -154,6 +162,14 @@ mod tests {
         assert_eq!(s.num_quanta(), 2u64.pow(31));
     }
 
+    #[test]
+    fn up_to_now_covers_historical() {
+        let topo = Topology::standard_epoch(std::time::Duration::from_secs(60 * 60));
+        let historical = TelescopingTimes::historical(&topo);
+        let up_to_now = TelescopingTimes::up_to_now(&topo);
+        assert!(up_to_now.time > historical.time);
+    }
+
     fn lengths(t: TimeQuantum) -> Vec<u32> {
         TelescopingTimes::new(t)
             .segments()
@@ -214,6 +214,34 @@ fn test_clamp_empty() {
     assert!(view_full.actual_coverage() <= strat.max_coverage());
 }
 
+#[test]
+/// An arq clamped to a fixed size takes on that size immediately,
+/// no matter what its peers are doing, and then stays there.
+fn test_clamp_fixed() {
+    let topo = Topology::unit_zero();
+    let mut rng = seeded_rng(None);
+
+    let strat = ArqStrat {
+        min_coverage: 10.0,
+        buffer: 0.2,
+        ..ArqStrat::default()
+    };
+    let mut strat_clamped = strat.clone();
+    strat_clamped.local_storage.arc_clamping = Some(ArqClamping::Fixed(0.25));
+
+    // peers with far too little coverage, which would normally cause growth
+    let peers: Vec<_> = generate_ideal_coverage(&topo, &mut rng, &strat, Some(2.0), 100, 0.01);
+    let view = PeerViewQ::new(topo.clone(), strat_clamped, peers);
+
+    let mut arq = Arq::new_full(&topo, 0u32.into(), topo.max_space_power(&strat));
+    assert!(view.update_arq(&mut arq));
+    let coverage = arq.coverage(&topo);
+    assert!((coverage - 0.25).abs() < 0.01, "coverage = {}", coverage);
+
+    assert!(!view.update_arq(&mut arq));
+    assert_eq!(arq.coverage(&topo), coverage);
+}
+
 #[test]
 // XXX: We only want to do this if other peers have not moved. But currently
 //      we have no way of determining this.
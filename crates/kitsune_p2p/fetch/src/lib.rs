@@ -98,3 +98,27 @@ pub enum TransferMethod {
     derive(arbitrary::Arbitrary, proptest_derive::Arbitrary)
 )]
 pub struct FetchContext(pub u32);
+
+/// The bit of a [`FetchContext`] which kitsune reserves to mark an item as a
+/// priority. All other bits are free to be used by the host.
+pub const FETCH_CONTEXT_PRIORITY: u32 = 1 << 31;
+
+impl FetchContext {
+    /// Sets the priority flag *if* the param is true, otherwise leaves the
+    /// flag unchanged.
+    ///
+    /// Priority items are fetched ahead of all other items in the [`FetchPool`],
+    /// which is useful for ops which the host is actively waiting on.
+    pub fn with_priority(&self, priority: bool) -> Self {
+        if priority {
+            FetchContext(self.0 | FETCH_CONTEXT_PRIORITY)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns true if the priority flag is set.
+    pub fn is_priority(&self) -> bool {
+        self.0 & FETCH_CONTEXT_PRIORITY > 0
+    }
+}
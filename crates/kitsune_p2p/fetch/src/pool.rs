@@ -9,6 +9,8 @@
 //! from the current moment. The items thus returned are not guaranteed to be returned in
 //! order of last_fetch time, but they are guaranteed to be at least as old as the specified
 //! interval.
+//!
+//! Items whose [`FetchContext`] is marked as a priority are returned ahead of all others.
 
 use std::sync::Arc;
 use tokio::time::{Duration, Instant};
@@ -94,6 +96,10 @@ impl FetchPoolConfig for FetchPoolConfigBitwiseOr {
 pub struct State {
     /// Items ready to be fetched
     queue: LinkedHashMap<FetchKey, FetchPoolItem>,
+    /// The keys of items in the queue which are marked as a priority, in the
+    /// order they should next be tried. Keys are moved to the back once tried,
+    /// so that every priority item gets a turn.
+    priority: LinkedHashMap<FetchKey, ()>,
 }
 
 impl FetchPool {
@@ -142,6 +148,13 @@ impl FetchPool {
         })
     }
 
+    /// Mark any of these items which are already in the queue as a priority,
+    /// because the host is now actively waiting on them.
+    /// Keys which aren't in the queue are ignored.
+    pub fn prioritize(&self, space: &KSpace, keys: impl IntoIterator<Item = FetchKey>) {
+        self.state.share_mut(|s| s.prioritize(space, keys));
+    }
+
     /// Get a list of the next items that should be fetched.
     pub fn get_items_to_fetch(&self) -> Vec<(FetchKey, KSpace, FetchSource, Option<FetchContext>)> {
         self.state
@@ -177,6 +190,11 @@ impl State {
             transfer_method,
         } = args;
 
+        let is_priority = context.map(|c| c.is_priority()).unwrap_or(false);
+        if is_priority && !self.priority.contains_key(&key) {
+            self.priority.insert(key.clone(), ());
+        }
+
         match self.queue.entry(key) {
             Entry::Vacant(e) => {
                 let sources = if let Some(author) = author {
@@ -206,11 +224,14 @@ impl State {
                     size,
                     context,
                     last_fetch: None,
+                    added: Instant::now(),
+                    fetch_attempts: 0,
                 };
                 e.insert(item);
             }
             Entry::Occupied(mut e) => {
                 let v = e.get_mut();
+                let is_priority = is_priority || v.is_priority();
                 v.sources
                     .0
                     .insert(source.clone(), SourceRecord::new(source, transfer_method));
@@ -219,6 +240,11 @@ impl State {
                     (Some(a), None) => Some(a),
                     (None, Some(b)) => Some(b),
                     (None, None) => None,
+                };
+                // The host's merge function doesn't know about the priority flag,
+                // so make sure that it's never lost.
+                if is_priority {
+                    v.context = v.context.map(|c| c.with_priority(true));
                 }
             }
         }
@@ -235,8 +261,21 @@ impl State {
         }
     }
 
+    /// Mark any of these items which are already in the queue as a priority.
+    pub fn prioritize(&mut self, space: &KSpace, keys: impl IntoIterator<Item = FetchKey>) {
+        for key in keys {
+            if let Some(item) = self.queue.get_mut(&key) {
+                if &item.space == space && !item.is_priority() {
+                    item.context = Some(item.context.unwrap_or_default().with_priority(true));
+                    self.priority.insert(key, ());
+                }
+            }
+        }
+    }
+
     /// When an item has been successfully fetched, we can remove it from the queue.
     pub fn remove(&mut self, key: &FetchKey) -> Option<FetchPoolItem> {
+        self.priority.remove(key);
         self.queue.remove(key)
     }
 
@@ -289,15 +328,24 @@ impl<'a> Iterator for StateIter<'a> {
     type Item = (FetchKey, KSpace, FetchSource, Option<FetchContext>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let keys: Vec<_> = self
-            .state
-            .queue
+        let priority = &self.state.priority;
+        let keys: Vec<_> = priority
             .keys()
             .take(NUM_ITEMS_PER_POLL)
+            .chain(
+                self.state
+                    .queue
+                    .keys()
+                    .filter(|key| !priority.contains_key(*key))
+                    .take(NUM_ITEMS_PER_POLL),
+            )
             .cloned()
             .collect();
 
         for key in keys {
+            // Rotate priority keys along with the queue, so that the same few
+            // priority items can't starve the rest.
+            self.state.priority.get_refresh(&key);
             let item = self.state.queue.get_refresh(&key)?;
             let item_not_recently_fetched = item
                 .last_fetch
@@ -308,6 +356,7 @@ impl<'a> Iterator for StateIter<'a> {
                     // TODO what if we're recently tried to use this source and it's not available? The retry delay does not apply across items
                     let space = item.space.clone();
                     item.last_fetch = Some(Instant::now());
+                    item.fetch_attempts += 1;
                     return Some((key, space, source, item.context));
                 }
             }
@@ -318,7 +367,7 @@ impl<'a> Iterator for StateIter<'a> {
 }
 
 /// An item in the queue, corresponding to a single op or region to fetch
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Eq)]
 pub struct FetchPoolItem {
     /// Known sources from whom we can fetch this item.
     /// Sources will always be tried in order.
@@ -331,6 +380,39 @@ pub struct FetchPoolItem {
     pub context: Option<FetchContext>,
     /// The last time we tried fetching this item from any source
     last_fetch: Option<Instant>,
+    /// When this item was first added to the queue
+    added: Instant,
+    /// The number of times we have tried fetching this item
+    fetch_attempts: u32,
+}
+
+// `added` is only used for diagnostics, so it doesn't take part in equality.
+impl PartialEq for FetchPoolItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.sources == other.sources
+            && self.space == other.space
+            && self.size == other.size
+            && self.context == other.context
+            && self.last_fetch == other.last_fetch
+            && self.fetch_attempts == other.fetch_attempts
+    }
+}
+
+impl FetchPoolItem {
+    /// Whether this item has been marked as a priority
+    pub fn is_priority(&self) -> bool {
+        self.context.map(|c| c.is_priority()).unwrap_or(false)
+    }
+
+    /// The number of fetch attempts for this item which went unanswered
+    /// for longer than the retry delay. The most recent attempt is not
+    /// counted while it may still be answered.
+    fn failed_fetch_attempts(&self, item_retry_delay: Duration) -> u32 {
+        match self.last_fetch {
+            Some(t) if t.elapsed() < item_retry_delay => self.fetch_attempts.saturating_sub(1),
+            _ => self.fetch_attempts,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -431,6 +513,8 @@ mod tests {
             context,
             size: None,
             last_fetch: None,
+            added: Instant::now(),
+            fetch_attempts: 0,
         }
     }
 
@@ -698,7 +782,10 @@ mod tests {
                 .last_request = Some(Instant::now() - Duration::from_secs(3));
 
             let queue = queue.into_iter().collect();
-            State { queue }
+            State {
+                queue,
+                ..Default::default()
+            }
         };
 
         // We can try fetching items one source at a time by waiting 1 sec in between
@@ -747,6 +834,7 @@ mod tests {
 
             State {
                 queue: queue.into_iter().collect(),
+                ..Default::default()
             }
         };
 
@@ -783,6 +871,7 @@ mod tests {
 
             State {
                 queue: queue.into_iter().collect(),
+                ..Default::default()
             }
         };
 
@@ -810,7 +899,10 @@ mod tests {
             let queue = [(test_key_op(1), item(&cfg, test_sources([1]), test_ctx(1)))];
 
             let queue = queue.into_iter().collect();
-            State { queue }
+            State {
+                queue,
+                ..Default::default()
+            }
         };
 
         assert_eq!(1, q.iter_mut(&cfg).count());
@@ -906,6 +998,73 @@ mod tests {
         );
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn priority_items_are_fetched_first() {
+        let mut q = State::default();
+        let cfg = Config(1, 10);
+        let num_items = 2 * NUM_ITEMS_PER_POLL as u8;
+
+        for i in 0..num_items {
+            q.push(&cfg, test_req_op(i, test_ctx(0), test_source(i)));
+        }
+        let priority_ctx = Some(FetchContext::default().with_priority(true));
+        q.push(&cfg, test_req_op(num_items, priority_ctx, test_source(0)));
+
+        // Merging with a non-priority context doesn't lose the priority,
+        // even though the config's merge function knows nothing about it.
+        q.push(&cfg, test_req_op(num_items, test_ctx(1), test_source(1)));
+        assert!(q.queue.get(&test_key_op(num_items)).unwrap().is_priority());
+
+        let first = q.iter_mut(&cfg).next().unwrap();
+        assert_eq!(first.0, test_key_op(num_items));
+
+        // Once fetched, the item is no longer tracked as a priority
+        q.remove(&test_key_op(num_items));
+        assert!(q.priority.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn priority_items_take_turns() {
+        let mut q = State::default();
+        let cfg = Config(1, 10);
+        let num_items = 2 * NUM_ITEMS_PER_POLL as u8;
+
+        let priority_ctx = Some(FetchContext::default().with_priority(true));
+        for i in 0..num_items {
+            q.push(&cfg, test_req_op(i, priority_ctx, test_source(i)));
+        }
+
+        // Items which were just tried are skipped until the retry delay passes,
+        // so every priority item must get a turn, not just the first few.
+        let fetched: HashSet<_> = (0..num_items)
+            .map(|_| q.iter_mut(&cfg).next().unwrap().0)
+            .collect();
+        assert_eq!(num_items as usize, fetched.len());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn existing_items_can_be_prioritized() {
+        let mut q = State::default();
+        let cfg = Config(1, 10);
+        let num_items = 2 * NUM_ITEMS_PER_POLL as u8;
+
+        for i in 0..num_items {
+            q.push(&cfg, test_req_op(i, test_ctx(0), test_source(i)));
+        }
+
+        // Items in other spaces, or not in the queue at all, are left alone
+        q.prioritize(&test_space(1), [test_key_op(num_items - 2)]);
+        q.prioritize(
+            &test_space(0),
+            [test_key_op(num_items - 1), test_key_op(num_items)],
+        );
+        assert_eq!(1, q.priority.len());
+
+        let first = q.iter_mut(&cfg).next().unwrap();
+        assert_eq!(first.0, test_key_op(num_items - 1));
+        assert!(first.3.unwrap().is_priority());
+    }
+
     #[test]
     fn default_fetch_context_merge_maintains_flags_from_both_contexts() {
         const FLAG_1: u32 = 1 << 5;
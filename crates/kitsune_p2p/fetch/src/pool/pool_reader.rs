@@ -1,8 +1,12 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use kitsune_p2p_types::{fetch_pool::FetchPoolInfo, KSpace};
+use kitsune_p2p_types::{
+    fetch_pool::{FetchPoolInfo, FetchSourceBacklog},
+    KAgent, KSpace,
+};
+use tokio::time::Duration;
 
-use crate::FetchPool;
+use crate::{FetchPool, FetchSource};
 
 /// Read-only access to the queue
 #[derive(Debug, Clone, derive_more::From)]
@@ -11,18 +15,59 @@ pub struct FetchPoolReader(FetchPool);
 impl FetchPoolReader {
     /// Get info about the queue, filtered by space
     pub fn info(&self, spaces: HashSet<KSpace>) -> FetchPoolInfo {
-        let (count, bytes) = self.0.state.share_ref(|s| {
-            s.queue
-                .values()
-                .filter(|v| spaces.contains(&v.space))
-                .filter_map(|v| v.size.map(|s| s.get()))
-                .fold((0, 0), |(c, s), t| (c + 1, s + t))
+        let item_retry_delay = self.0.config.item_retry_delay();
+        let mut info = FetchPoolInfo::default();
+        let mut oldest_item_age: Option<Duration> = None;
+        let mut backlog: HashMap<KAgent, usize> = HashMap::new();
+
+        self.0.state.share_ref(|s| {
+            for item in s.queue.values().filter(|v| spaces.contains(&v.space)) {
+                if let Some(size) = item.size {
+                    info.num_ops_to_fetch += 1;
+                    info.op_bytes_to_fetch += size.get();
+                }
+                info.num_pending += 1;
+                if item.is_priority() {
+                    info.num_priority += 1;
+                }
+                info.failed_fetch_attempts += item.failed_fetch_attempts(item_retry_delay) as usize;
+
+                let age = item.added.elapsed();
+                oldest_item_age = Some(oldest_item_age.map_or(age, |oldest| oldest.max(age)));
+
+                for source in item.sources.0.keys() {
+                    match source {
+                        FetchSource::Agent(agent) => {
+                            *backlog.entry(agent.clone()).or_default() += 1
+                        }
+                    }
+                }
+            }
         });
 
-        FetchPoolInfo {
-            op_bytes_to_fetch: bytes,
-            num_ops_to_fetch: count,
-        }
+        info.oldest_item_age_ms = oldest_item_age.map(|age| age.as_millis() as u64);
+        info.source_backlog = backlog
+            .into_iter()
+            .map(|(agent, num_ops)| FetchSourceBacklog { agent, num_ops })
+            .collect();
+        info.source_backlog
+            .sort_by(|a, b| b.num_ops.cmp(&a.num_ops));
+        info
+    }
+
+    /// Get info about the queue for each space which has items in it
+    pub fn info_by_space(&self) -> HashMap<KSpace, FetchPoolInfo> {
+        let spaces: HashSet<KSpace> = self
+            .0
+            .state
+            .share_ref(|s| s.queue.values().map(|v| v.space.clone()).collect());
+        spaces
+            .into_iter()
+            .map(|space| {
+                let info = self.info([space.clone()].into_iter().collect());
+                (space, info)
+            })
+            .collect()
     }
 }
 
@@ -30,7 +75,7 @@ impl FetchPoolReader {
 mod tests {
     use super::*;
     use crate::test_utils::*;
-    use crate::{pool::tests::*, State};
+    use crate::{pool::tests::*, FetchContext, State};
     use kitsune_p2p_types::tx2::tx2_utils::ShareOpen;
     use linked_hash_map::LinkedHashMap;
     use std::sync::Arc;
@@ -41,6 +86,7 @@ mod tests {
             config: Arc::new(Config(1, 1)),
             state: ShareOpen::new(State {
                 queue: LinkedHashMap::new(),
+                ..Default::default()
             }),
         });
 
@@ -60,7 +106,10 @@ mod tests {
             let queue = queue.into_iter().collect();
             FetchPoolReader(FetchPool {
                 config: Arc::new(cfg),
-                state: ShareOpen::new(State { queue }),
+                state: ShareOpen::new(State {
+                    queue,
+                    ..Default::default()
+                }),
             })
         };
 
@@ -86,7 +135,10 @@ mod tests {
             let queue = queue.into_iter().collect();
             FetchPoolReader(FetchPool {
                 config: Arc::new(cfg),
-                state: ShareOpen::new(State { queue }),
+                state: ShareOpen::new(State {
+                    queue,
+                    ..Default::default()
+                }),
             })
         };
 
@@ -96,6 +148,40 @@ mod tests {
         assert_eq!(info.op_bytes_to_fetch, 1100);
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn queue_info_diagnostics() {
+        let pool = FetchPool::new(Arc::new(Config(10, 10)));
+        pool.push(test_req_op(1, test_ctx(0), test_source(1)));
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+
+        let priority_ctx = Some(FetchContext::default().with_priority(true));
+        pool.push(test_req_op(2, priority_ctx, test_source(1)));
+        pool.push(test_req_op(2, None, test_source(2)));
+
+        // Try to fetch each item twice, letting the retry delay elapse in between
+        assert_eq!(pool.get_items_to_fetch().len(), 2);
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(pool.get_items_to_fetch().len(), 2);
+
+        let info = FetchPoolReader::from(pool).info([test_space(0)].into_iter().collect());
+        assert_eq!(info.num_pending, 2);
+        assert_eq!(info.num_priority, 1);
+        assert_eq!(info.oldest_item_age_ms, Some(15_000));
+        // The latest attempts may still be answered, so only the first ones count as failed
+        assert_eq!(info.failed_fetch_attempts, 2);
+
+        let FetchSource::Agent(agent_1) = test_source(1);
+        assert_eq!(
+            info.source_backlog[0],
+            FetchSourceBacklog {
+                agent: agent_1,
+                num_ops: 2
+            }
+        );
+        assert_eq!(info.source_backlog[1].num_ops, 1);
+    }
+
     #[test]
     fn queue_info_filter_spaces() {
         let cfg = Config(1, 1);
@@ -116,7 +202,10 @@ mod tests {
             let queue = queue.into_iter().collect();
             FetchPoolReader(FetchPool {
                 config: Arc::new(cfg),
-                state: ShareOpen::new(State { queue }),
+                state: ShareOpen::new(State {
+                    queue,
+                    ..Default::default()
+                }),
             })
         };
 
@@ -19,8 +19,56 @@
 //! deterministic hashes associated with each based on the contents, which are sent to the gossip partner.
 //! For regions which mismatch, the ops in those regions will be exchanged between partners. For regions
 //! which match, no data will be transferred.
+//!
+//! For small networks where every node is expected to hold everything, the sharding machinery
+//! only adds overhead. The "full-sync" strategy runs a single gossip loop which ignores arcs
+//! entirely and reconciles all ops, from the origin of time up to now, using region diffs.
+//! It still exchanges agent info blooms, since there is no Recent gossip to do so.
+//!
+//! The strategy (or comma-separated list of strategies) is chosen by the `gossip_strategy`
+//! tuning param, and can be overridden per space by the host via
+//! [`KitsuneHost::gossip_strategy`](crate::KitsuneHost::gossip_strategy).
+//! Each strategy name maps to one or more gossip module factories in `strategy_factories`,
+//! which is the single place a new strategy needs to be registered.
 
+pub mod full_sync;
 pub mod sharded_gossip;
 
 mod common;
 pub use common::*;
+
+use crate::types::gossip::{GossipModuleFactory, GossipModuleType};
+
+/// Look up the gossip module factories which implement the named gossip strategy.
+///
+/// Returns `None` if the strategy is not known.
+pub(crate) fn strategy_factories(
+    strategy: &str,
+    tuning_params: &kitsune_p2p_types::config::KitsuneP2pTuningParams,
+    bandwidth_throttles: &sharded_gossip::BandwidthThrottles,
+) -> Option<Vec<(GossipModuleType, GossipModuleFactory)>> {
+    match strategy {
+        "sharded-gossip" => {
+            let mut gossips = vec![];
+            if !tuning_params.disable_recent_gossip {
+                gossips.push((
+                    GossipModuleType::ShardedRecent,
+                    sharded_gossip::recent_factory(bandwidth_throttles.recent()),
+                ));
+            }
+            if !tuning_params.disable_historical_gossip {
+                gossips.push((
+                    GossipModuleType::ShardedHistorical,
+                    sharded_gossip::historical_factory(bandwidth_throttles.historical()),
+                ));
+            }
+            Some(gossips)
+        }
+        "full-sync" => Some(vec![(
+            GossipModuleType::FullSync,
+            full_sync::full_sync_factory(bandwidth_throttles.historical()),
+        )]),
+        "none" => Some(vec![]),
+        _ => None,
+    }
+}
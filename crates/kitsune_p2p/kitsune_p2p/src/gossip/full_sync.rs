@@ -0,0 +1,56 @@
+//! Full-sync gossip strategy.
+//!
+//! Every node is expected to hold every op, so arcs are ignored. A single gossip loop
+//! reconciles all ops from the origin of time up to now using region diffs, and also
+//! exchanges agent info blooms, since there is no Recent gossip loop to do so.
+//!
+//! The round protocol is shared with sharded gossip; this module only decides which
+//! arcs and time window a round covers, via [`GossipType::FullSync`].
+
+use super::sharded_gossip::{BandwidthThrottle, GossipType, ShardedGossip};
+use crate::meta_net::*;
+use crate::metrics::MetricsSync;
+use crate::types::gossip::*;
+use crate::types::*;
+use crate::HostApiLegacy;
+use kitsune_p2p_fetch::FetchPool;
+use kitsune_p2p_types::config::*;
+use std::sync::Arc;
+
+struct FullSyncGossipFactory {
+    bandwidth: Arc<BandwidthThrottle>,
+}
+
+impl FullSyncGossipFactory {
+    fn new(bandwidth: Arc<BandwidthThrottle>) -> Self {
+        Self { bandwidth }
+    }
+}
+
+impl AsGossipModuleFactory for FullSyncGossipFactory {
+    fn spawn_gossip_task(
+        &self,
+        config: Arc<KitsuneP2pConfig>,
+        space: Arc<KitsuneSpace>,
+        ep_hnd: MetaNet,
+        host: HostApiLegacy,
+        metrics: MetricsSync,
+        fetch_pool: FetchPool,
+    ) -> GossipModule {
+        GossipModule(ShardedGossip::new(
+            config,
+            space,
+            ep_hnd,
+            host,
+            GossipType::FullSync,
+            self.bandwidth.clone(),
+            metrics,
+            fetch_pool,
+        ))
+    }
+}
+
+/// Create a full-sync `GossipModuleFactory`
+pub fn full_sync_factory(bandwidth: Arc<BandwidthThrottle>) -> GossipModuleFactory {
+    GossipModuleFactory(Arc::new(FullSyncGossipFactory::new(bandwidth)))
+}
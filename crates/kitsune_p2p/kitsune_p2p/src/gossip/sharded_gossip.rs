@@ -98,6 +98,10 @@ pub enum GossipType {
     /// data. It runs less frequently, and expects diffs to be infrequent
     /// at each round.
     Historical,
+    /// The FullSync gossip type is aimed at small networks where every node
+    /// holds everything. It ignores arcs, and syncs all data up to the present
+    /// moment using region diffs, while also exchanging agent info.
+    FullSync,
 }
 
 /// The entry point for the sharded gossip strategy.
@@ -136,6 +140,7 @@ impl std::fmt::Display for GossipType {
         match self {
             GossipType::Recent => write!(f, "recent"),
             GossipType::Historical => write!(f, "historical"),
+            GossipType::FullSync => write!(f, "full-sync"),
         }
     }
 }
@@ -254,7 +259,7 @@ impl ShardedGossip {
                 let s = tracing::trace_span!("process_outgoing_recent", cert = ?cert, agents = ?self.gossip.show_local_agents());
                 s.in_scope(|| tracing::trace!(?gossip));
             }
-            GossipType::Historical => {
+            GossipType::Historical | GossipType::FullSync => {
                 let s = tracing::trace_span!("process_outgoing_historical", cert = ?cert, agents = ?self.gossip.show_local_agents());
                 match &gossip {
                     ShardedGossipWire::MissingOpHashes(MissingOpHashes { ops, finished }) => {
@@ -317,6 +322,7 @@ impl ShardedGossip {
         let gossip_type_char = match self.gossip.gossip_type {
             GossipType::Recent => 'R',
             GossipType::Historical => 'H',
+            GossipType::FullSync => 'F',
         };
 
         if let Some(msg) = outgoing.as_ref() {
@@ -762,6 +768,7 @@ impl ShardedGossipLocal {
         let threshold = Duration::from_secs(self.tuning_params.danger_gossip_recent_threshold_secs);
         match self.gossip_type {
             GossipType::Recent => time_range(threshold, NOW),
+            GossipType::FullSync => Timestamp::from_micros(0)..Timestamp::now(),
             GossipType::Historical => {
                 let one_hour_ago = std::time::UNIX_EPOCH
                     .elapsed()
@@ -888,7 +895,7 @@ impl ShardedGossipLocal {
                 s.in_scope(|| self.log_state());
                 s
             }
-            GossipType::Historical => match &msg {
+            GossipType::Historical | GossipType::FullSync => match &msg {
                 ShardedGossipWire::MissingOpHashes(MissingOpHashes { ops, finished }) => {
                     let s = tracing::trace_span!("process_incoming_historical", ?peer_cert, agents = ?self.show_local_agents(), msg = %"MissingOpHashes", num_ops = %ops.len(), ?finished);
                     s.in_scope(|| self.log_state());
@@ -1014,9 +1021,7 @@ impl ShardedGossipLocal {
                 // TODO: come back to this later after implementing batching for
                 //      region gossip, for now I just don't care about the state,
                 //      and just want to handle the incoming ops.
-                if (self.gossip_type == GossipType::Historical || state.is_some())
-                    && !ops.is_empty()
-                {
+                if (self.gossip_type != GossipType::Recent || state.is_some()) && !ops.is_empty() {
                     if let Some(state) = state.as_ref() {
                         if let Some(agent) = state.remote_agent_list.get(0) {
                             // there is at least 1 agent
@@ -1475,6 +1480,7 @@ impl From<GossipType> for GossipModuleType {
         match g {
             GossipType::Recent => GossipModuleType::ShardedRecent,
             GossipType::Historical => GossipModuleType::ShardedHistorical,
+            GossipType::FullSync => GossipModuleType::FullSync,
         }
     }
 }
@@ -1484,6 +1490,7 @@ impl From<GossipModuleType> for GossipType {
         match g {
             GossipModuleType::ShardedRecent => GossipType::Recent,
             GossipModuleType::ShardedHistorical => GossipType::Historical,
+            GossipModuleType::FullSync => GossipType::FullSync,
         }
     }
 }
@@ -47,12 +47,7 @@ impl ShardedGossipLocal {
         }
 
         // Get the local intervals.
-        let local_agent_arcs: Vec<_> =
-            store::local_agent_arcs(&self.host_api, &self.space, &local_agents)
-                .await?
-                .into_iter()
-                .map(|(_, a)| a.into())
-                .collect();
+        let local_agent_arcs = self.local_gossip_arcs(&local_agents).await?;
 
         let mut gossip = Vec::new();
 
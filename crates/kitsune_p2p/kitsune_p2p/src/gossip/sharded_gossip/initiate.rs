@@ -31,11 +31,7 @@ impl ShardedGossipLocal {
         }
 
         // Get the local agents intervals.
-        let intervals: Vec<_> = store::local_arcs(&self.host_api, &self.space, &local_agents)
-            .await?
-            .into_iter()
-            .map(DhtArcRange::from)
-            .collect();
+        let intervals = self.local_gossip_arcs(&local_agents).await?;
 
         // Choose a remote agent to gossip with.
         let remote_agent = self
@@ -128,12 +124,7 @@ impl ShardedGossipLocal {
         }
 
         // Get the local intervals.
-        let local_agent_arcs =
-            store::local_agent_arcs(&self.host_api, &self.space, &local_agents).await?;
-        let local_arcs: Vec<DhtArcRange> = local_agent_arcs
-            .into_iter()
-            .map(|(_, arc)| arc.into())
-            .collect();
+        let local_arcs = self.local_gossip_arcs(&local_agents).await?;
 
         let agent_list = self
             .host_api
@@ -187,6 +178,24 @@ impl ShardedGossipLocal {
         Ok(gossip)
     }
 
+    /// Get the intervals our local agents gossip over.
+    /// - Full-sync gossip ignores arcs and always covers the full DHT.
+    pub(super) async fn local_gossip_arcs(
+        &self,
+        local_agents: &HashSet<Arc<KitsuneAgent>>,
+    ) -> KitsuneResult<Vec<DhtArcRange>> {
+        if let GossipType::FullSync = self.gossip_type {
+            return Ok(vec![DhtArcRange::Full]);
+        }
+        Ok(
+            store::local_agent_arcs(&self.host_api, &self.space, local_agents)
+                .await?
+                .into_iter()
+                .map(|(_, arc)| arc.into())
+                .collect(),
+        )
+    }
+
     /// Fetch a current list of agents to initiate gossip with.
     pub(super) async fn query_agents_by_local_agents(&self) -> KitsuneResult<Vec<AgentInfoSigned>> {
         let local_agents = self.inner.share_mut(|i, _| Ok(i.local_agents.clone()))?;
@@ -199,7 +208,7 @@ impl ShardedGossipLocal {
     }
 
     /// Generate the bloom filters and generate a new state.
-    /// - Agent bloom is only generated if this is a `Recent` gossip type.
+    /// - Agent bloom is only generated if this is a `Recent` or `FullSync` gossip type.
     /// - Empty blooms are not created.
     /// - A new state is created for this round.
     pub(super) async fn generate_blooms_or_regions(
@@ -214,11 +223,12 @@ impl ShardedGossipLocal {
         let remote_arc_set: DhtArcSet = remote_arc_set.into();
         let common_arc_set = Arc::new(arc_set.intersection(&remote_arc_set));
 
-        let region_set = if let GossipType::Historical = self.gossip_type {
+        let region_set = if self.gossip_type != GossipType::Recent {
             let region_set = store::query_region_set(
                 self.host_api.clone().api,
                 self.space.clone(),
                 common_arc_set.clone(),
+                self.gossip_type,
             )
             .await?;
             gossip.push(ShardedGossipWire::op_regions(region_set.clone()));
@@ -236,13 +246,17 @@ impl ShardedGossipLocal {
         )?;
 
         // Generate the agent bloom.
-        if let GossipType::Recent = self.gossip_type {
+        // Full-sync gossip has no Recent gossip running alongside it,
+        // so it is responsible for gossiping agent info too.
+        if self.gossip_type != GossipType::Historical {
             let bloom = self.generate_agent_bloom(state.clone()).await?;
             if let Some(bloom) = bloom {
                 let bloom = encode_bloom_filter(&bloom);
                 gossip.push(ShardedGossipWire::agents(bloom));
             }
+        }
 
+        if let GossipType::Recent = self.gossip_type {
             // we consider recent gossip to have "sent its region"
             // for purposes of determining the round is complete
             state.regions_are_queued = true;
@@ -250,8 +264,9 @@ impl ShardedGossipLocal {
             self.next_bloom_batch(state, gossip).await
         } else {
             // Everything has already been taken care of for Historical
-            // gossip already. Just mark this true so that the state will not
-            // be considered "finished" until all op data is received.
+            // and FullSync gossip already. Just mark this true so that the
+            // state will not be considered "finished" until all op data is
+            // received.
             state.has_pending_historical_op_data = true;
             state.regions_are_queued = false;
             Ok(state)
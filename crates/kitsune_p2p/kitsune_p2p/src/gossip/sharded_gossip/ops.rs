@@ -120,7 +120,7 @@ impl ShardedGossipLocal {
                     round.regions_are_queued = true;
                     i.metrics.write().update_current_round(
                         peer_cert,
-                        GossipModuleType::ShardedHistorical,
+                        self.gossip_type.into(),
                         round,
                     );
                 } else {
@@ -190,7 +190,9 @@ impl ShardedGossipLocal {
         state: RoundState,
     ) -> KitsuneResult<Vec<ShardedGossipWire>> {
         match self.gossip_type {
-            GossipType::Historical => self.process_next_region_batch(state).await,
+            GossipType::Historical | GossipType::FullSync => {
+                self.process_next_region_batch(state).await
+            }
             GossipType::Recent => {
                 // Pop the next queued batch.
                 let next_batch = state
@@ -18,7 +18,7 @@ use kitsune_p2p_types::{
     KitsuneError, KitsuneResult,
 };
 
-use super::ShardedGossipLocal;
+use super::{GossipType, ShardedGossipLocal};
 
 /// Get all agent info signed for a space.
 pub(super) async fn all_agent_info(
@@ -59,19 +59,6 @@ pub(super) async fn local_agent_arcs(
         .collect::<Vec<_>>())
 }
 
-/// Get just the arc intervals for specified agents.
-pub(super) async fn local_arcs(
-    host_api: &HostApiLegacy,
-    space: &Arc<KitsuneSpace>,
-    local_agents: &HashSet<Arc<KitsuneAgent>>,
-) -> KitsuneResult<Vec<DhtArc>> {
-    Ok(local_agent_arcs(host_api, space, local_agents)
-        .await?
-        .into_iter()
-        .map(|(_, arc)| arc)
-        .collect())
-}
-
 /// Get `AgentInfoSigned` for all agents within a `DhtArcSet`.
 pub(super) async fn agent_info_within_arc_set(
     host_api: &HostApiLegacy,
@@ -268,11 +255,17 @@ pub(super) async fn query_region_set<'a>(
     host_api: HostApi,
     space: Arc<KitsuneSpace>,
     common_arc_set: Arc<DhtArcSet>,
+    gossip_type: GossipType,
 ) -> KitsuneResult<RegionSetLtcs> {
-    host_api
-        .query_region_set(space, common_arc_set)
-        .await
-        .map_err(KitsuneError::other)
+    match gossip_type {
+        GossipType::FullSync => {
+            host_api
+                .query_region_set_up_to_now(space, common_arc_set)
+                .await
+        }
+        _ => host_api.query_region_set(space, common_arc_set).await,
+    }
+    .map_err(KitsuneError::other)
 }
 
 /// Add new agent info to the p2p store.
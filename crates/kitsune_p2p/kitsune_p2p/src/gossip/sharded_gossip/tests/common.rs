@@ -114,6 +114,14 @@ impl KitsuneHost for StandardResponsesHostApi {
         .into()
     }
 
+    fn query_region_set_up_to_now(
+        &self,
+        space: Arc<KitsuneSpace>,
+        dht_arc_set: Arc<DhtArcSet>,
+    ) -> crate::KitsuneHostResult<RegionSetLtcs> {
+        self.query_region_set(space, dht_arc_set)
+    }
+
     fn record_metrics(
         &self,
         _space: Arc<KitsuneSpace>,
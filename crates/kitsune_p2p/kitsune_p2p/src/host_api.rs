@@ -4,12 +4,13 @@ use must_future::MustBoxFuture;
 use std::sync::Arc;
 
 use kitsune_p2p_types::{
-    bin_types::KitsuneSpace,
+    bin_types::{KitsuneAgent, KitsuneSpace},
     dependencies::lair_keystore_api,
     dht::{
         region::{Region, RegionCoords},
         region_set::RegionSetLtcs,
         spacetime::Topology,
+        ArqStrat,
     },
     dht_arc::DhtArcSet,
     KOpData, KOpHash,
@@ -60,6 +61,15 @@ pub trait KitsuneHost: 'static + Send + Sync + std::fmt::Debug {
         dht_arc_set: Arc<DhtArcSet>,
     ) -> KitsuneHostResult<RegionSetLtcs>;
 
+    /// Query aggregate dht op data to form an LTCS set of region data which
+    /// covers all time up to now, rather than only the historical portion.
+    /// This is used by the full-sync gossip strategy.
+    fn query_region_set_up_to_now(
+        &self,
+        space: Arc<KitsuneSpace>,
+        dht_arc_set: Arc<DhtArcSet>,
+    ) -> KitsuneHostResult<RegionSetLtcs>;
+
     /// Given an input list of regions, return a list of equal or greater length
     /// such that each region's size is less than the `size_limit`, by recursively
     /// subdividing regions which are over the size limit.
@@ -126,6 +136,25 @@ pub trait KitsuneHost: 'static + Send + Sync + std::fmt::Debug {
     ) {
     }
 
+    /// Get the gossip strategy to use for this space, as a comma-separated
+    /// list of strategy names (see `KitsuneP2pTuningParams::gossip_strategy`).
+    /// If `None`, the `gossip_strategy` tuning param is used.
+    fn gossip_strategy(&self, _space: &KitsuneSpace) -> Option<String> {
+        None
+    }
+
+    /// Get the arc resizing strategy to use for this local agent, if it
+    /// should differ from the one used for the rest of the space.
+    /// If `None`, the arc is resized according to the tuning params.
+    fn local_agent_arq_strat(
+        &self,
+        space: Arc<KitsuneSpace>,
+        agent: Arc<KitsuneAgent>,
+    ) -> KitsuneHostResult<Option<ArqStrat>> {
+        let _ = (space, agent);
+        futures::FutureExt::boxed(async move { Ok(None) }).into()
+    }
+
     /// Get the lair "tag" identifying the id seed to use for crypto signing.
     /// (this is currently only used in tx5/WebRTC if that feature is enabled.)
     fn lair_tag(&self) -> Option<Arc<str>> {
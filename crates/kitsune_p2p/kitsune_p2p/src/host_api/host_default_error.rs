@@ -96,6 +96,19 @@ pub trait KitsuneHostDefaultError: KitsuneHost + FetchPoolConfig {
         .into()))
     }
 
+    fn query_region_set_up_to_now(
+        &self,
+        _space: Arc<KitsuneSpace>,
+        _dht_arc_set: Arc<DhtArcSet>,
+    ) -> KitsuneHostResult<RegionSetLtcs> {
+        box_fut(Err(format!(
+            "error for unimplemented KitsuneHost test behavior: method {} of {}",
+            "query_region_set_up_to_now",
+            Self::NAME
+        )
+        .into()))
+    }
+
     /// Given an input list of regions, return a list of equal or greater length
     /// such that each region's size is less than the `size_limit`, by recursively
     /// subdividing regions which are over the size limit.
@@ -210,6 +223,14 @@ impl<T: KitsuneHostDefaultError> KitsuneHost for T {
         KitsuneHostDefaultError::query_region_set(self, space, dht_arc_set)
     }
 
+    fn query_region_set_up_to_now(
+        &self,
+        space: Arc<KitsuneSpace>,
+        dht_arc_set: Arc<DhtArcSet>,
+    ) -> KitsuneHostResult<RegionSetLtcs> {
+        KitsuneHostDefaultError::query_region_set_up_to_now(self, space, dht_arc_set)
+    }
+
     fn get_topology(&self, space: Arc<KitsuneSpace>) -> KitsuneHostResult<Topology> {
         KitsuneHostDefaultError::get_topology(self, space)
     }
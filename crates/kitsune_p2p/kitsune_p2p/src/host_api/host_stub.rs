@@ -174,6 +174,14 @@ impl KitsuneHost for HostStub {
         KitsuneHostDefaultError::query_region_set(&self.err, space, dht_arc_set)
     }
 
+    fn query_region_set_up_to_now(
+        &self,
+        space: Arc<KitsuneSpace>,
+        dht_arc_set: Arc<DhtArcSet>,
+    ) -> KitsuneHostResult<RegionSetLtcs> {
+        KitsuneHostDefaultError::query_region_set_up_to_now(&self.err, space, dht_arc_set)
+    }
+
     fn query_size_limited_regions(
         &self,
         space: Arc<KitsuneSpace>,
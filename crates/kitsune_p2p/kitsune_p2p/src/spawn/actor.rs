@@ -751,6 +751,16 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
         .into())
     }
 
+    fn handle_prioritize_fetch(
+        &mut self,
+        space: Arc<KitsuneSpace>,
+        op_hashes: Vec<Arc<KitsuneOpHash>>,
+    ) -> KitsuneP2pHandlerResult<()> {
+        self.fetch_pool
+            .prioritize(&space, op_hashes.into_iter().map(FetchKey::Op));
+        unit_ok_fut()
+    }
+
     fn handle_authority_for_hash(
         &mut self,
         space: Arc<KitsuneSpace>,
@@ -812,6 +822,7 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
             })
             .collect::<Vec<_>>();
         let stat_fut = self.ep_hnd.dump_network_stats();
+        let fetch_pool_info = FetchPoolReader::from(self.fetch_pool.clone()).info_by_space();
         Ok(async move {
             let mut stats = stat_fut.await?;
 
@@ -882,6 +893,22 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
                 }
             }
 
+            let fetch_pool_info: serde_json::Map<String, serde_json::Value> = fetch_pool_info
+                .into_iter()
+                .map(|(space, info)| {
+                    use base64::Engine;
+                    let dna_hash = format!(
+                        "uhC0k{}",
+                        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&**space)
+                    );
+                    (dna_hash, serde_json::json!(info))
+                })
+                .collect();
+            stats
+                .as_object_mut()
+                .ok_or(KitsuneP2pError::from("InvalidStats"))?
+                .insert("fetchPool".to_string(), fetch_pool_info.into());
+
             Ok(stats)
         }
         .boxed()
@@ -221,6 +221,28 @@ pub async fn nodespace_is_authorized(
     }
 }
 
+async fn ip_is_authorized(host: &HostApi, ip: std::net::IpAddr, now: Timestamp) -> MetaNetAuth {
+    match host.is_blocked(BlockTargetId::Ip(ip), now).await {
+        Ok(true) => MetaNetAuth::UnauthorizedDisconnect,
+        Ok(false) => MetaNetAuth::Authorized,
+        Err(_) => MetaNetAuth::UnauthorizedIgnore,
+    }
+}
+
+/// The IP address of the remote end of a direct connection, if its url has one.
+/// Connections through a proxy have the url of the proxy, so the address of
+/// the remote node isn't known for them.
+fn direct_connection_ip(url: &url2::Url2) -> Option<std::net::IpAddr> {
+    if url.scheme() == "kitsune-proxy" {
+        return None;
+    }
+    url.host_str()?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .ok()
+}
+
 pub type MetaNetEvtRecv = futures::channel::mpsc::Receiver<MetaNetEvt>;
 
 type ResStore = Arc<Mutex<HashMap<u64, tokio::sync::oneshot::Sender<wire::Wire>>>>;
@@ -730,6 +752,19 @@ impl MetaNet {
                         }
                     }
                     Tx2EpEvent::IncomingConnection(Tx2EpConnection { con, url }) => {
+                        if let Some(ip) = direct_connection_ip(url.as_url2()) {
+                            if let MetaNetAuth::UnauthorizedDisconnect =
+                                ip_is_authorized(&host, ip, Timestamp::now()).await
+                            {
+                                tracing::info!(%ip, "refusing connection from blocked ip");
+                                con.close(
+                                    UNAUTHORIZED_DISCONNECT_CODE,
+                                    UNAUTHORIZED_DISCONNECT_REASON,
+                                )
+                                .await;
+                                continue;
+                            }
+                        }
                         if evt_send
                             .send(MetaNetEvt::Connected {
                                 remote_url: url.to_string(),
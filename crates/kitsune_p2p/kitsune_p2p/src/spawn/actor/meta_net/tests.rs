@@ -141,6 +141,15 @@ write_test_struct! {
                 Ok(RegionSetLtcs::empty())
             })
         }
+        fn query_region_set_up_to_now(
+            &Self,
+            _space: Arc<KitsuneSpace>,
+            _dht_arc_set: Arc<DhtArcSet>,
+        ) -> KitsuneHostResult<RegionSetLtcs>, HostRet<RegionSetLtcs> {
+            Box::pin(async move {
+                Ok(RegionSetLtcs::empty())
+            })
+        }
         fn query_size_limited_regions(
             &Self,
             _space: Arc<KitsuneSpace>,
@@ -913,3 +922,110 @@ async fn request_timeout() {
 
     nodes.shutdown().await;
 }
+
+#[cfg(feature = "tx2")]
+#[tokio::test(flavor = "multi_thread")]
+async fn tx2_blocked_ip_is_disconnected() {
+    use crate::test_util::mock_network::{mock_network, to_kitsune_channel, KitsuneMock};
+    use kitsune_p2p_types::config::{AdapterFactoryMock, TransportConfig};
+
+    let blocked: std::net::IpAddr = std::net::Ipv4Addr::new(1, 2, 3, 4).into();
+    let blocked_url = "kitsune-quic://1.2.3.4:443";
+    let allowed_url = "kitsune-quic://5.6.7.8:443";
+
+    let events = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let mut test = Test::default();
+    test.is_blocked = Arc::new(move |target, _| {
+        let is_blocked = matches!(target, BlockTargetId::Ip(ip) if ip == blocked);
+        Box::pin(async move { Ok(is_blocked) })
+    });
+    test.recv = Arc::new({
+        let events = events.clone();
+        move |evt| match evt {
+            MetaNetEvt::Connected { remote_url, .. } => {
+                events.lock().push(("connected", remote_url))
+            }
+            MetaNetEvt::Disconnected { remote_url, .. } => {
+                events.lock().push(("disconnected", remote_url))
+            }
+            _ => (),
+        }
+    });
+    let (test, _i_s, evt_sender) = test.spawn().await;
+
+    let (from_kitsune_tx, _from_kitsune_rx) = tokio::sync::mpsc::channel(10);
+    let (to_kitsune_tx, to_kitsune_rx) = to_kitsune_channel(10);
+    let mut config = KitsuneP2pConfig::default();
+    config.transport_pool = vec![TransportConfig::Mock {
+        mock_network: AdapterFactoryMock(Arc::new(mock_network(from_kitsune_tx, to_kitsune_rx))),
+    }];
+    let (net, recv) = MetaNet::new_tx2(
+        HostApiLegacy {
+            api: Arc::new(test.clone()),
+            legacy: evt_sender,
+        },
+        config,
+        kitsune_p2p_types::tls::TlsConfig::new_ephemeral()
+            .await
+            .unwrap(),
+        Tx2ApiMetrics::default(),
+    )
+    .await
+    .unwrap();
+    test.spawn_receiver(recv);
+
+    for (i, url) in [blocked_url, allowed_url].into_iter().enumerate() {
+        to_kitsune_tx
+            .send(KitsuneMock::notify(
+                MsgId::new_notify(),
+                vec![i as u8 + 1; 32].into(),
+                TxUrl::from_str_panicking(url),
+                wire::Wire::failure("Hello World!".into()),
+            ))
+            .await
+            .unwrap();
+    }
+
+    tokio::time::timeout(std::time::Duration::from_secs(10), async {
+        loop {
+            {
+                let events = events.lock();
+                if events.contains(&("disconnected", blocked_url.to_string()))
+                    && events.contains(&("connected", allowed_url.to_string()))
+                {
+                    break;
+                }
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert!(!events
+        .lock()
+        .contains(&("connected", blocked_url.to_string())));
+
+    net.close(0, "").await;
+}
+
+#[test]
+fn direct_connection_ips() {
+    assert_eq!(
+        Some(std::net::Ipv4Addr::new(1, 2, 3, 4).into()),
+        direct_connection_ip(&url2::url2!("kitsune-quic://1.2.3.4:443")),
+    );
+    assert_eq!(
+        Some(std::net::Ipv6Addr::LOCALHOST.into()),
+        direct_connection_ip(&url2::url2!("kitsune-quic://[::1]:443")),
+    );
+    assert_eq!(
+        None,
+        direct_connection_ip(&url2::url2!("kitsune-quic://example.com:443")),
+    );
+    assert_eq!(
+        None,
+        direct_connection_ip(&url2::url2!(
+            "kitsune-proxy://VlyCSmL5WRKUTOLmF9wF0oFy5Jqbxy0I9KPeXqB_9Z4/kitsune-quic/h/1.2.3.4/p/443/--"
+        )),
+    );
+}
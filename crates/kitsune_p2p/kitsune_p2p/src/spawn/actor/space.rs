@@ -10,7 +10,7 @@ use kitsune_p2p_types::agent_info::AgentInfoSigned;
 use kitsune_p2p_types::codec::{rmp_decode, rmp_encode};
 use kitsune_p2p_types::config::KitsuneP2pConfig;
 use kitsune_p2p_types::config::NetworkType;
-use kitsune_p2p_types::dht::prelude::ArqClamping;
+use kitsune_p2p_types::dht::prelude::{ArqClamping, ArqStrat};
 use kitsune_p2p_types::dht_arc::{DhtArc, DhtArcRange, DhtArcSet};
 use kitsune_p2p_types::tx2::tx2_utils::TxUrl;
 use std::collections::{HashMap, HashSet};
@@ -186,8 +186,9 @@ impl SpaceInternalHandler for Space {
 
     fn handle_update_agent_info(&mut self) -> SpaceInternalHandlerResult<()> {
         let space = self.space.clone();
-        let mut mdns_handles = self.mdns_handles.clone();
+        let mdns_handles = self.mdns_handles.clone();
         let network_type = self.config.network_type.clone();
+        let use_mdns = self.config.uses_mdns();
         let mut agent_list = Vec::with_capacity(self.local_joined_agents.len());
         for agent in self.local_joined_agents.keys().cloned() {
             let arc = self.get_agent_arc(&agent);
@@ -196,6 +197,7 @@ impl SpaceInternalHandler for Space {
         let bootstrap_net = self.ro_inner.bootstrap_net;
         let ep_hnd = self.ro_inner.ep_hnd.clone();
         let evt_sender = self.host_api.legacy.clone();
+        let host_api = self.host_api.api.clone();
         let bootstrap_service = self.config.bootstrap_service.clone();
         let expires_after = self.config.tuning_params.agent_info_expires_after_ms as u64;
         let dynamic_arcs = self.config.tuning_params.gossip_dynamic_arcs;
@@ -212,9 +214,11 @@ impl SpaceInternalHandler for Space {
                     arc,
                     urls: &urls,
                     evt_sender: &evt_sender,
+                    host_api: &host_api,
                     internal_sender: &internal_sender,
                     network_type: network_type.clone(),
-                    mdns_handles: &mut mdns_handles,
+                    use_mdns,
+                    mdns_handles: &mdns_handles,
                     bootstrap_service: &bootstrap_service,
                     dynamic_arcs,
                 };
@@ -238,10 +242,12 @@ impl SpaceInternalHandler for Space {
     ) -> SpaceInternalHandlerResult<()> {
         let space = self.space.clone();
         let bootstrap_net = self.ro_inner.bootstrap_net;
-        let mut mdns_handles = self.mdns_handles.clone();
+        let mdns_handles = self.mdns_handles.clone();
         let network_type = self.config.network_type.clone();
+        let use_mdns = self.config.uses_mdns();
         let ep_hnd = self.ro_inner.ep_hnd.clone();
         let evt_sender = self.host_api.legacy.clone();
+        let host_api = self.host_api.api.clone();
         let internal_sender = self.i_s.clone();
         let bootstrap_service = self.config.bootstrap_service.clone();
         let expires_after = self.config.tuning_params.agent_info_expires_after_ms as u64;
@@ -258,9 +264,11 @@ impl SpaceInternalHandler for Space {
                 arc,
                 urls: &urls,
                 evt_sender: &evt_sender,
+                host_api: &host_api,
                 internal_sender: &internal_sender,
                 network_type: network_type.clone(),
-                mdns_handles: &mut mdns_handles,
+                use_mdns,
+                mdns_handles: &mdns_handles,
                 bootstrap_service: &bootstrap_service,
                 dynamic_arcs,
             };
@@ -639,9 +647,11 @@ struct UpdateAgentInfoInput<'borrow> {
     arc: DhtArc,
     urls: &'borrow Vec<TxUrl>,
     evt_sender: &'borrow futures::channel::mpsc::Sender<KitsuneP2pEvent>,
+    host_api: &'borrow HostApi,
     internal_sender: &'borrow ghost_actor::GhostSender<SpaceInternal>,
     network_type: NetworkType,
-    mdns_handles: &'borrow mut HashMap<Vec<u8>, Arc<AtomicBool>>,
+    use_mdns: bool,
+    mdns_handles: &'borrow MdnsHandles,
     bootstrap_service: &'borrow Option<Url2>,
     dynamic_arcs: bool,
 }
@@ -649,9 +659,13 @@ struct UpdateAgentInfoInput<'borrow> {
 async fn update_arc_length(
     evt_sender: &futures::channel::mpsc::Sender<KitsuneP2pEvent>,
     space: Arc<KitsuneSpace>,
+    arq_strat: Option<ArqStrat>,
     arc: &mut DhtArc,
 ) -> KitsuneP2pResult<()> {
-    let view = evt_sender.query_peer_density(space.clone(), *arc).await?;
+    let mut view = evt_sender.query_peer_density(space.clone(), *arc).await?;
+    if let Some(strat) = arq_strat {
+        view = view.with_strat(strat);
+    }
 
     let cov_before = arc.coverage() * 100.0;
     tracing::trace!("Updating arc for space {:?}:", space);
@@ -677,15 +691,23 @@ async fn update_single_agent_info(
         mut arc,
         urls,
         evt_sender,
+        host_api,
         internal_sender,
         network_type,
+        use_mdns,
         mdns_handles,
         bootstrap_service,
         dynamic_arcs,
     } = input;
 
-    if dynamic_arcs {
-        update_arc_length(evt_sender, space.clone(), &mut arc).await?;
+    // A strategy chosen for this particular agent applies even if dynamic
+    // arcs are disabled for the space as a whole.
+    let arq_strat = host_api
+        .local_agent_arq_strat(space.clone(), agent.clone())
+        .await
+        .map_err(KitsuneP2pError::other)?;
+    if dynamic_arcs || arq_strat.is_some() {
+        update_arc_length(evt_sender, space.clone(), arq_strat, &mut arc).await?;
     }
 
     // Update the agents arc through the internal sender.
@@ -726,40 +748,106 @@ async fn update_single_agent_info(
     // to be sure it makes it into the local store.
     put_local_agent_info(evt_sender.clone(), space.clone(), agent_info_signed.clone()).await?;
 
+    // Announce on the LAN first, so that an unreachable bootstrap
+    // service doesn't prevent local peers from finding us.
+    // Broadcast only valid AgentInfo
+    if use_mdns && !urls.is_empty() {
+        mdns_broadcast_agent_info(mdns_handles, &space, &agent, &agent_info_signed)?;
+    }
+
     // Push to the network as well
-    match network_type {
-        NetworkType::QuicMdns => {
-            // Broadcast only valid AgentInfo
-            if !urls.is_empty() {
-                // Kill previous broadcast for this space + agent
-                let key = [space.get_bytes(), agent.get_bytes()].concat();
-                if let Some(current_handle) = mdns_handles.get(&key) {
-                    mdns_kill_thread(current_handle.to_owned());
+    if network_type == NetworkType::QuicBootstrap {
+        kitsune_p2p_bootstrap_client::put(
+            bootstrap_service.clone(),
+            agent_info_signed.clone(),
+            bootstrap_net,
+        )
+        .await?;
+    }
+    Ok(agent_info_signed)
+}
+
+/// Broadcast handles for the local agents announced over mDNS,
+/// keyed by space and agent bytes.
+type MdnsHandles = Arc<parking_lot::Mutex<HashMap<Vec<u8>, Arc<AtomicBool>>>>;
+
+fn mdns_handle_key(space: &KitsuneSpace, agent: &KitsuneAgent) -> Vec<u8> {
+    [space.get_bytes(), agent.get_bytes()].concat()
+}
+
+/// Broadcast an agent info over mDNS, replacing any previous broadcast
+/// for the same agent.
+fn mdns_broadcast_agent_info(
+    mdns_handles: &MdnsHandles,
+    space: &KitsuneSpace,
+    agent: &KitsuneAgent,
+    agent_info_signed: &AgentInfoSigned,
+) -> KitsuneP2pResult<()> {
+    // Broadcast by using Space as service type and Agent as service name
+    let space_b64 = base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(&space[..]);
+    let agent_b64 = base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(&agent[..]);
+    // Broadcast rmp encoded agent_info_signed
+    let mut buffer = Vec::new();
+    rmp_encode(&mut buffer, agent_info_signed)?;
+    tracing::trace!(?space_b64, ?agent_b64);
+    let handle = mdns_create_broadcast_thread(space_b64, agent_b64, &buffer);
+    // Kill previous broadcast for this space + agent
+    if let Some(previous_handle) = mdns_handles
+        .lock()
+        .insert(mdns_handle_key(space, agent), handle)
+    {
+        mdns_kill_thread(previous_handle);
+    }
+    Ok(())
+}
+
+/// Stop broadcasting an agent info over mDNS.
+fn mdns_stop_broadcast(mdns_handles: &MdnsHandles, space: &KitsuneSpace, agent: &KitsuneAgent) {
+    if let Some(handle) = mdns_handles.lock().remove(&mdns_handle_key(space, agent)) {
+        mdns_kill_thread(handle);
+    }
+}
+
+/// Listen for agent infos broadcast over mDNS for this space,
+/// adding any which are found to the peer store.
+fn spawn_mdns_listener(
+    space: Arc<KitsuneSpace>,
+    evt_sender: futures::channel::mpsc::Sender<KitsuneP2pEvent>,
+) {
+    let space_b64 = base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(&space[..]);
+    tokio::task::spawn(async move {
+        let stream = mdns_listen(space_b64);
+        tokio::pin!(stream);
+        while let Some(maybe_response) = stream.next().await {
+            match maybe_response {
+                Ok(response) => {
+                    tracing::trace!(msg = "Peer found via MDNS", ?response);
+                    // Decode response
+                    let remote_agent_info_signed: AgentInfoSigned =
+                        match rmp_decode(&mut &*response.buffer) {
+                            Ok(agent_info_signed) => agent_info_signed,
+                            Err(e) => {
+                                tracing::error!(msg = "Failed to decode MDNS peer", ?e);
+                                continue;
+                            }
+                        };
+                    // Add to local storage
+                    if let Err(e) = evt_sender
+                        .put_agent_info_signed(PutAgentInfoSignedEvt {
+                            space: space.clone(),
+                            peer_data: vec![remote_agent_info_signed],
+                        })
+                        .await
+                    {
+                        tracing::error!(msg = "Failed to store MDNS peer", ?e);
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(msg = "Failed to get peers from MDNS", ?e);
                 }
-                // Broadcast by using Space as service type and Agent as service name
-                let space_b64 = base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(&space[..]);
-                let agent_b64 = base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(&agent[..]);
-                //println!("(MDNS) - Broadcasting of Agent {:?} ({}) in space {:?} ({} ; {})",
-                // agent, agent.get_bytes().len(), space, space.get_bytes().len(), space_b64.len());
-                // Broadcast rmp encoded agent_info_signed
-                let mut buffer = Vec::new();
-                rmp_encode(&mut buffer, &agent_info_signed)?;
-                tracing::trace!(?space_b64, ?agent_b64);
-                let handle = mdns_create_broadcast_thread(space_b64, agent_b64, &buffer);
-                // store handle in self
-                mdns_handles.insert(key, handle);
             }
         }
-        NetworkType::QuicBootstrap => {
-            kitsune_p2p_bootstrap_client::put(
-                bootstrap_service.clone(),
-                agent_info_signed.clone(),
-                bootstrap_net,
-            )
-            .await?;
-        }
-    }
-    Ok(agent_info_signed)
+    });
 }
 
 use crate::spawn::actor::space::agent_info_update::AgentInfoUpdateTask;
@@ -812,52 +900,11 @@ impl KitsuneP2pHandler for Space {
             module.local_agent_join(agent.clone());
         }
         let fut = self.i_s.update_single_agent_info(agent);
-        let evt_sender = self.host_api.legacy.clone();
-        match self.config.network_type {
-            NetworkType::QuicMdns => {
-                // Listen to MDNS service that has that space as service type
-                let space_b64 = base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(&space[..]);
-                if !self.mdns_listened_spaces.contains(&space_b64) {
-                    self.mdns_listened_spaces.insert(space_b64.clone());
-                    tokio::task::spawn(async move {
-                        let stream = mdns_listen(space_b64);
-                        tokio::pin!(stream);
-                        while let Some(maybe_response) = stream.next().await {
-                            match maybe_response {
-                                Ok(response) => {
-                                    tracing::trace!(msg = "Peer found via MDNS", ?response);
-                                    // Decode response
-                                    let maybe_agent_info_signed =
-                                        rmp_decode(&mut &*response.buffer);
-                                    if let Err(e) = maybe_agent_info_signed {
-                                        tracing::error!(msg = "Failed to decode MDNS peer", ?e);
-                                        continue;
-                                    }
-                                    if let Ok(remote_agent_info_signed) = maybe_agent_info_signed {
-                                        // Add to local storage
-                                        if let Err(e) = evt_sender
-                                            .put_agent_info_signed(PutAgentInfoSignedEvt {
-                                                space: space.clone(),
-                                                peer_data: vec![remote_agent_info_signed],
-                                            })
-                                            .await
-                                        {
-                                            tracing::error!(msg = "Failed to store MDNS peer", ?e);
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    tracing::error!(msg = "Failed to get peers from MDNS", ?e);
-                                }
-                            }
-                        }
-                    });
-                }
-            }
-            NetworkType::QuicBootstrap => {
-                // quic bootstrap is managed for the whole space
-                // see the Space::new() constructor
-            }
+        // Listen to MDNS service that has that space as service type.
+        // Quic bootstrap is managed for the whole space, see the Space::new() constructor
+        if self.config.uses_mdns() && !self.mdns_listening {
+            self.mdns_listening = true;
+            spawn_mdns_listener(space, self.host_api.legacy.clone());
         }
 
         Ok(fut.boxed().into())
@@ -870,6 +917,7 @@ impl KitsuneP2pHandler for Space {
     ) -> KitsuneP2pHandlerResult<()> {
         self.local_joined_agents.remove(&agent);
         self.agent_arcs.remove(&agent);
+        mdns_stop_broadcast(&self.mdns_handles, &self.space, &agent);
         self.update_metric_exchange_arcset();
         for module in self.gossip_mod.values() {
             module.local_agent_leave(agent.clone());
@@ -1216,6 +1264,17 @@ impl KitsuneP2pHandler for Space {
         unit_ok_fut()
     }
 
+    fn handle_prioritize_fetch(
+        &mut self,
+        space: Arc<KitsuneSpace>,
+        op_hashes: Vec<Arc<KitsuneOpHash>>,
+    ) -> KitsuneP2pHandlerResult<()> {
+        self.ro_inner
+            .fetch_pool
+            .prioritize(&space, op_hashes.into_iter().map(FetchKey::Op));
+        unit_ok_fut()
+    }
+
     fn handle_authority_for_hash(
         &mut self,
         _space: Arc<KitsuneSpace>,
@@ -1345,8 +1404,8 @@ pub(crate) struct Space {
     pub(crate) local_joined_agents: HashMap<Arc<KitsuneAgent>, Option<AgentInfoSigned>>,
     pub(crate) agent_arcs: HashMap<Arc<KitsuneAgent>, DhtArc>,
     pub(crate) config: Arc<KitsuneP2pConfig>,
-    mdns_handles: HashMap<Vec<u8>, Arc<AtomicBool>>,
-    mdns_listened_spaces: HashSet<String>,
+    mdns_handles: MdnsHandles,
+    mdns_listening: bool,
     gossip_mod: HashMap<GossipModuleType, GossipModule>,
 }
 
@@ -1397,35 +1456,20 @@ impl Space {
             tracing::info!("Gossip is disabled due to arc_clamping setting");
             HashMap::new()
         } else {
-            config
-                .tuning_params
-                .gossip_strategy
+            // The host may override the configured strategy for this particular space.
+            let gossip_strategy = host_api
+                .gossip_strategy(&space)
+                .unwrap_or_else(|| config.tuning_params.gossip_strategy.clone());
+            tracing::debug!(?space, %gossip_strategy, "Using gossip strategy");
+            gossip_strategy
                 .split(',')
-                .flat_map(|module| match module {
-                    "sharded-gossip" => {
-                        let mut gossips = vec![];
-                        if !config.tuning_params.disable_recent_gossip {
-                            gossips.push((
-                                GossipModuleType::ShardedRecent,
-                                crate::gossip::sharded_gossip::recent_factory(
-                                    bandwidth_throttles.recent(),
-                                ),
-                            ));
-                        }
-                        if !config.tuning_params.disable_historical_gossip {
-                            gossips.push((
-                                GossipModuleType::ShardedHistorical,
-                                crate::gossip::sharded_gossip::historical_factory(
-                                    bandwidth_throttles.historical(),
-                                ),
-                            ));
-                        }
-                        gossips
-                    }
-                    "none" => vec![],
-                    _ => {
-                        panic!("unknown gossip strategy: {}", module);
-                    }
+                .flat_map(|module| {
+                    crate::gossip::strategy_factories(
+                        module,
+                        &config.tuning_params,
+                        &bandwidth_throttles,
+                    )
+                    .unwrap_or_else(|| panic!("unknown gossip strategy: {}", module))
                 })
                 .map(|(module, factory)| {
                     (
@@ -1487,8 +1531,8 @@ impl Space {
             local_joined_agents: HashMap::new(),
             agent_arcs: HashMap::new(),
             config,
-            mdns_handles: HashMap::new(),
-            mdns_listened_spaces: HashSet::new(),
+            mdns_handles: MdnsHandles::default(),
+            mdns_listening: false,
             gossip_mod,
         }
     }
@@ -1552,16 +1596,15 @@ impl Space {
                 .map_err(KitsuneP2pError::other)?;
 
             // Push to the network as well
-            match network_type {
-                NetworkType::QuicMdns => tracing::warn!("NOT publishing leaves to mdns"),
-                NetworkType::QuicBootstrap => {
-                    kitsune_p2p_bootstrap_client::put(
-                        bootstrap_service.clone(),
-                        agent_info_signed,
-                        bootstrap_net,
-                    )
-                    .await?;
-                }
+            // Leaves are not broadcast over mdns, the agent's broadcast is
+            // simply stopped.
+            if network_type == NetworkType::QuicBootstrap {
+                kitsune_p2p_bootstrap_client::put(
+                    bootstrap_service.clone(),
+                    agent_info_signed,
+                    bootstrap_net,
+                )
+                .await?;
             }
 
             Ok(())
@@ -1580,7 +1623,9 @@ impl Space {
         self.agent_arcs.get(agent).cloned().unwrap_or_else(|| {
             match self.config.tuning_params.arc_clamping() {
                 Some(ArqClamping::Empty) => DhtArc::empty(agent.get_loc()),
-                Some(ArqClamping::Full) | None => DhtArc::full(agent.get_loc()),
+                Some(ArqClamping::Full) | Some(ArqClamping::Fixed(_)) | None => {
+                    DhtArc::full(agent.get_loc())
+                }
             }
         })
     }
@@ -1602,3 +1647,33 @@ async fn put_local_agent_info(
         .await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::fixt::prelude::*;
+    use kitsune_p2p_types::fixt::AgentInfoSignedFixturator;
+    use std::sync::atomic::Ordering;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn mdns_broadcast_is_replaced_on_refresh_and_stopped_on_leave() {
+        let info = fixt!(AgentInfoSigned);
+        let handles = MdnsHandles::default();
+
+        mdns_broadcast_agent_info(&handles, &info.space, &info.agent, &info).unwrap();
+        let first = handles.lock().values().next().cloned().unwrap();
+        assert!(first.load(Ordering::Relaxed));
+
+        // Refreshing the agent info replaces the previous broadcast
+        mdns_broadcast_agent_info(&handles, &info.space, &info.agent, &info).unwrap();
+        assert_eq!(1, handles.lock().len());
+        let second = handles.lock().values().next().cloned().unwrap();
+        assert!(!first.load(Ordering::Relaxed));
+        assert!(second.load(Ordering::Relaxed));
+
+        // Leaving stops the broadcast
+        mdns_stop_broadcast(&handles, &info.space, &info.agent);
+        assert!(handles.lock().is_empty());
+        assert!(!second.load(Ordering::Relaxed));
+    }
+}
@@ -195,6 +195,15 @@ impl KitsuneHost for SwitchboardEventHandler {
         .into()
     }
 
+    fn query_region_set_up_to_now(
+        &self,
+        space: Arc<KitsuneSpace>,
+        dht_arc_set: Arc<dht_arc::DhtArcSet>,
+    ) -> crate::KitsuneHostResult<dht::region_set::RegionSetLtcs> {
+        // The region set above already covers all of time.
+        self.query_region_set(space, dht_arc_set)
+    }
+
     fn get_topology(
         &self,
         _space: Arc<KitsuneSpace>,
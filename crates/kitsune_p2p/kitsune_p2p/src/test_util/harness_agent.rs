@@ -57,6 +57,14 @@ impl KitsuneHostDefaultError for HarnessHost {
     ) -> KitsuneHostResult<RegionSetLtcs> {
         box_fut(Ok(RegionSetLtcs::empty()))
     }
+
+    fn query_region_set_up_to_now(
+        &self,
+        _space: Arc<KitsuneSpace>,
+        _dht_arc_set: Arc<DhtArcSet>,
+    ) -> KitsuneHostResult<RegionSetLtcs> {
+        box_fut(Ok(RegionSetLtcs::empty()))
+    }
 }
 
 pub(crate) async fn spawn_test_agent(
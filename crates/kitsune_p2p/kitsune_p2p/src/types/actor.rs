@@ -94,6 +94,7 @@ type KSpaceOpt = Option<Arc<super::KitsuneSpace>>;
 type KAgent = Arc<super::KitsuneAgent>;
 type KAgents = Vec<Arc<super::KitsuneAgent>>;
 type KBasis = Arc<super::KitsuneBasis>;
+type KOpHash = Arc<super::KitsuneOpHash>;
 type Payload = Vec<u8>;
 type OptU64 = Option<u64>;
 type OptArc = Option<crate::dht_arc::DhtArc>;
@@ -153,6 +154,10 @@ ghost_actor::ghost_chan! {
         /// New data has been integrated and is ready for gossiping.
         fn new_integrated_data(space: KSpace) -> ();
 
+        /// The host is actively waiting on these ops, so fetch any of them which
+        /// are already in the fetch pool ahead of the rest of the backlog.
+        fn prioritize_fetch(space: KSpace, op_hashes: Vec<KOpHash>) -> ();
+
         /// Check if an agent is an authority for a hash.
         fn authority_for_hash(
             space: KSpace,
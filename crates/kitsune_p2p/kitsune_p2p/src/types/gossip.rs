@@ -19,6 +19,8 @@ pub enum GossipModuleType {
     ShardedRecent,
     /// Historical sharded gossip.
     ShardedHistorical,
+    /// Full-sync gossip, which ignores arcs and exchanges all data via region diffs.
+    FullSync,
 }
 
 /// Represents an interchangeable gossip strategy module
@@ -71,6 +71,14 @@ impl KitsuneHost for TestHost {
         todo!()
     }
 
+    fn query_region_set_up_to_now(
+        &self,
+        _space: Arc<kitsune_p2p_bin_data::KitsuneSpace>,
+        _dht_arc_set: Arc<kitsune_p2p_types::dht_arc::DhtArcSet>,
+    ) -> kitsune_p2p::KitsuneHostResult<kitsune_p2p_types::dht::prelude::RegionSetLtcs> {
+        todo!()
+    }
+
     fn query_size_limited_regions(
         &self,
         _space: Arc<kitsune_p2p_bin_data::KitsuneSpace>,
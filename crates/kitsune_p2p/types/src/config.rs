@@ -91,7 +91,10 @@ pub mod tuning_params_struct {
     }
 
     mk_tune! {
-        /// Gossip strategy to use. [Default: "sharded-gossip"]
+        /// Gossip strategy to use, as a comma-separated list of
+        /// "sharded-gossip", "full-sync" or "none".
+        /// "full-sync" ignores arcs and syncs everything, and is intended
+        /// for small networks. [Default: "sharded-gossip"]
         gossip_strategy: String = "sharded-gossip".to_string(),
 
         /// Delay between gossip loop iteration. [Default: 1s]
@@ -292,6 +295,14 @@ pub mod tuning_params_struct {
         /// The default value is 60 * 5 s = five minutes.
         /// The minimum value is 60 s = one minute.
         bootstrap_max_delay_s: u32 = 60 * 5,
+
+        /// Also announce local agents and discover peers on the local network
+        /// via mDNS, in addition to the bootstrap service. This is always the
+        /// case when the network type is `quic_mdns`.
+        ///
+        /// Note that the WebRTC transport still needs a signal server which
+        /// both peers can reach, though that server may itself be on the LAN.
+        mdns_discovery: bool = false,
     }
 
     impl KitsuneP2pTuningParams {
@@ -421,6 +432,12 @@ impl KitsuneP2pConfig {
         }
     }
 
+    /// Agent infos are announced and discovered on the local network via mDNS,
+    /// either exclusively or alongside the bootstrap service.
+    pub fn uses_mdns(&self) -> bool {
+        self.network_type == NetworkType::QuicMdns || self.tuning_params.mdns_discovery
+    }
+
     /// This config is making use of tx5 transport
     #[allow(dead_code)] // because of feature flipping
     pub fn is_tx5(&self) -> bool {
@@ -605,3 +622,24 @@ impl From<AdapterFactory> for AdapterFactoryMock {
         Self(adaptor_factory)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_mdns() {
+        let mut config = KitsuneP2pConfig::default();
+        assert!(!config.uses_mdns());
+
+        config.network_type = NetworkType::QuicMdns;
+        assert!(config.uses_mdns());
+
+        config.network_type = NetworkType::QuicBootstrap;
+        let config = config.tune(|mut tp| {
+            tp.mdns_discovery = true;
+            tp
+        });
+        assert!(config.uses_mdns());
+    }
+}
@@ -1,5 +1,7 @@
 //! Fetch queue types
 
+use crate::KAgent;
+
 /// Info about the fetch queue
 #[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct FetchPoolInfo {
@@ -8,4 +10,37 @@ pub struct FetchPoolInfo {
 
     /// Total number of ops expected to be received through fetches
     pub num_ops_to_fetch: usize,
+
+    /// Total number of ops waiting to be fetched, including those of unknown size
+    #[serde(default)]
+    pub num_pending: usize,
+
+    /// Number of pending ops which were marked as a priority, and so are
+    /// fetched ahead of all others
+    #[serde(default)]
+    pub num_priority: usize,
+
+    /// How long the oldest pending op has been waiting to be fetched, in milliseconds
+    #[serde(default)]
+    pub oldest_item_age_ms: Option<u64>,
+
+    /// Number of fetch requests for pending ops which went unanswered
+    /// within the retry delay
+    #[serde(default)]
+    pub failed_fetch_attempts: usize,
+
+    /// The number of pending ops which can be fetched from each source,
+    /// largest backlog first
+    #[serde(default)]
+    pub source_backlog: Vec<FetchSourceBacklog>,
+}
+
+/// The number of pending ops which can be fetched from a particular source
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FetchSourceBacklog {
+    /// The source agent
+    pub agent: KAgent,
+
+    /// The number of pending ops which this agent is a source for
+    pub num_ops: usize,
 }
@@ -0,0 +1,584 @@
+//! Wrap a tx2 backend adapter in runtime-controllable fault injection.
+//!
+//! This is intended for tests which need to simulate a degraded network:
+//! latency, packet loss, limited bandwidth, partitions which split the
+//! nodes into groups that cannot reach each other, and restrictions to a
+//! fixed set of links between nodes.
+
+use crate::tx2::tx2_adapter::*;
+use crate::tx2::tx2_utils::*;
+use crate::tx2::*;
+use crate::*;
+
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{BoxStream, StreamExt};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Faults which are applied to every frame sent across a link between
+/// two nodes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LinkFaults {
+    /// Delay added to every frame before it is sent.
+    pub latency: Duration,
+
+    /// The fraction (0.0 to 1.0) of frames which are silently dropped.
+    /// Drops are evenly spaced rather than random, so tests are repeatable.
+    pub loss: f64,
+
+    /// The maximum throughput of the link, in bytes per second.
+    /// Default: None = unlimited.
+    pub bandwidth: Option<u64>,
+}
+
+/// A handle for controlling the faults injected into any number of nodes
+/// which were wrapped with [`tx2_fault_adapter`]. Clones share the same
+/// state, and changes take effect immediately, including on connections
+/// which are already open.
+#[derive(Clone, Default)]
+pub struct FaultInjector(Arc<Mutex<FaultInjectorInner>>);
+
+impl std::fmt::Debug for FaultInjector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.0.lock();
+        f.debug_struct("FaultInjector")
+            .field("faults", &inner.faults)
+            .field("link_faults", &inner.link_faults)
+            .field("partition", &inner.partition)
+            .field("links", &inner.links)
+            .finish()
+    }
+}
+
+impl FaultInjector {
+    /// Construct a new injector which doesn't inject any faults yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the faults applied to every link which doesn't have its own
+    /// faults set via [`FaultInjector::set_link_faults`].
+    pub fn set_faults(&self, faults: LinkFaults) {
+        self.0.lock().faults = faults;
+    }
+
+    /// Set the faults applied to the link between two nodes, in both
+    /// directions. `None` reverts the link to the faults set via
+    /// [`FaultInjector::set_faults`].
+    pub fn set_link_faults(&self, a: usize, b: usize, faults: Option<LinkFaults>) {
+        let mut inner = self.0.lock();
+        let key = link_key(a, b);
+        match faults {
+            Some(faults) => {
+                inner.link_faults.insert(key, faults);
+            }
+            None => {
+                inner.link_faults.remove(&key);
+            }
+        }
+    }
+
+    /// Split the network into groups of nodes. Nodes can only reach other
+    /// nodes in the same group, and nodes which aren't in any group can't
+    /// reach anybody. Open connections between groups are closed the next
+    /// time either side tries to send on them.
+    pub fn partition<G, N>(&self, groups: G)
+    where
+        G: IntoIterator<Item = N>,
+        N: IntoIterator<Item = usize>,
+    {
+        let partition = groups
+            .into_iter()
+            .enumerate()
+            .flat_map(|(group, nodes)| nodes.into_iter().map(move |node| (node, group)))
+            .collect();
+        self.0.lock().partition = Some(partition);
+    }
+
+    /// Remove any partition, so that all nodes can reach each other again.
+    pub fn heal(&self) {
+        self.0.lock().partition = None;
+    }
+
+    /// Only allow nodes to reach each other across the given links, which
+    /// work in both directions. Nodes which aren't on any link can't reach
+    /// anybody. This applies on top of any partition, and open connections
+    /// across other links are closed the next time either side tries to
+    /// send on them.
+    pub fn restrict_links<L>(&self, links: L)
+    where
+        L: IntoIterator<Item = (usize, usize)>,
+    {
+        let links = links.into_iter().map(|(a, b)| link_key(a, b)).collect();
+        self.0.lock().links = Some(links);
+    }
+
+    /// Remove any link restriction, so that all nodes which aren't
+    /// partitioned can reach each other again.
+    pub fn unrestrict_links(&self) {
+        self.0.lock().links = None;
+    }
+
+    /// Remove all faults, including any partition and link restriction.
+    pub fn clear(&self) {
+        let mut inner = self.0.lock();
+        inner.faults = LinkFaults::default();
+        inner.link_faults.clear();
+        inner.partition = None;
+        inner.links = None;
+        inner.loss_acc.clear();
+    }
+
+    /// Check whether the current partition or link restriction prevents
+    /// two nodes from reaching each other.
+    pub fn is_partitioned(&self, a: usize, b: usize) -> bool {
+        self.0.lock().is_partitioned(a, b)
+    }
+
+    fn register(&self, cert: Tx2Cert, node: usize) {
+        self.0.lock().nodes.insert(cert, node);
+    }
+
+    /// Get the state of the link from a local node to a remote peer.
+    /// Peers which aren't wrapped by this injector are never partitioned.
+    fn link(&self, node: usize, peer_cert: &Tx2Cert) -> (bool, LinkFaults) {
+        let inner = self.0.lock();
+        match inner.nodes.get(peer_cert) {
+            Some(&peer) => (
+                inner.is_partitioned(node, peer),
+                inner
+                    .link_faults
+                    .get(&link_key(node, peer))
+                    .unwrap_or(&inner.faults)
+                    .clone(),
+            ),
+            None => (false, inner.faults.clone()),
+        }
+    }
+
+    /// Decide whether to drop the next frame sent from a local node to a
+    /// remote peer, given the loss rate. Each link keeps its own count, so
+    /// traffic on one link doesn't affect which frames are dropped on another.
+    fn should_drop(&self, node: usize, peer_cert: &Tx2Cert, loss: f64) -> bool {
+        if loss <= 0.0 {
+            return false;
+        }
+        let mut inner = self.0.lock();
+        let loss_acc = inner.loss_acc.entry((node, peer_cert.clone())).or_default();
+        *loss_acc += loss.min(1.0);
+        if *loss_acc >= 1.0 {
+            *loss_acc -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Wrap a tx2 backend adapter so that all traffic through it is subject to
+/// the faults configured on the injector. The node index identifies this
+/// node when setting up partitions and per-link faults, and must be unique
+/// among all nodes sharing the injector.
+pub fn tx2_fault_adapter(
+    sub_adapter: AdapterFactory,
+    injector: FaultInjector,
+    node: usize,
+) -> AdapterFactory {
+    injector.register(sub_adapter.local_cert(), node);
+    Arc::new(FaultBackendAdapt(Arc::new(FaultCtx {
+        injector,
+        node,
+        sub_adapter,
+    })))
+}
+
+// -- private -- //
+
+#[derive(Default)]
+struct FaultInjectorInner {
+    nodes: HashMap<Tx2Cert, usize>,
+    faults: LinkFaults,
+    link_faults: HashMap<(usize, usize), LinkFaults>,
+    partition: Option<HashMap<usize, usize>>,
+    links: Option<HashSet<(usize, usize)>>,
+    loss_acc: HashMap<(usize, Tx2Cert), f64>,
+}
+
+impl FaultInjectorInner {
+    fn is_partitioned(&self, a: usize, b: usize) -> bool {
+        if a == b {
+            return false;
+        }
+        let partitioned = match &self.partition {
+            None => false,
+            Some(partition) => {
+                partition.get(&a).is_none() || partition.get(&a) != partition.get(&b)
+            }
+        };
+        let unlinked = match &self.links {
+            None => false,
+            Some(links) => !links.contains(&link_key(a, b)),
+        };
+        partitioned || unlinked
+    }
+}
+
+fn link_key(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
+}
+
+struct FaultCtx {
+    injector: FaultInjector,
+    node: usize,
+    sub_adapter: AdapterFactory,
+}
+
+impl FaultCtx {
+    /// Wrap a newly established connection, or reject it if the
+    /// peer is on the other side of a partition.
+    async fn wrap_con(self: Arc<Self>, con: Con) -> KitsuneResult<Con> {
+        let (sub_con, chan_recv) = con;
+        let peer_cert = sub_con.peer_cert();
+        if self.injector.link(self.node, &peer_cert).0 {
+            sub_con.close(0, "partitioned").await;
+            return Err(format!("partitioned from peer {:?}", peer_cert).into());
+        }
+        let con: Arc<dyn ConAdapt> = Arc::new(FaultConAdapt {
+            ctx: self,
+            peer_cert,
+            sub_con,
+        });
+        Ok((con, chan_recv))
+    }
+}
+
+struct FaultOutChan {
+    ctx: Arc<FaultCtx>,
+    peer_cert: Tx2Cert,
+    sub_con: Arc<dyn ConAdapt>,
+    sub_chan: OutChan,
+}
+
+impl AsFramedWriter for FaultOutChan {
+    fn write(
+        &mut self,
+        msg_id: MsgId,
+        data: PoolBuf,
+        timeout: KitsuneTimeout,
+    ) -> BoxFuture<'_, KitsuneResult<()>> {
+        async move {
+            let (partitioned, faults) = self.ctx.injector.link(self.ctx.node, &self.peer_cert);
+            if partitioned {
+                self.sub_con.close(0, "partitioned").await;
+                return Err(KitsuneErrorKind::Closed.into());
+            }
+
+            let mut delay = faults.latency;
+            if let Some(bandwidth) = faults.bandwidth {
+                delay += Duration::from_secs_f64(data.len() as f64 / bandwidth.max(1) as f64);
+            }
+            if !delay.is_zero() {
+                timeout
+                    .mix("FaultOutChan::write", async move {
+                        tokio::time::sleep(delay).await;
+                        Ok(())
+                    })
+                    .await?;
+            }
+
+            if self
+                .ctx
+                .injector
+                .should_drop(self.ctx.node, &self.peer_cert, faults.loss)
+            {
+                return Ok(());
+            }
+
+            self.sub_chan.write(msg_id, data, timeout).await
+        }
+        .boxed()
+    }
+}
+
+struct FaultConAdapt {
+    ctx: Arc<FaultCtx>,
+    peer_cert: Tx2Cert,
+    sub_con: Arc<dyn ConAdapt>,
+}
+
+impl ConAdapt for FaultConAdapt {
+    fn uniq(&self) -> Uniq {
+        self.sub_con.uniq()
+    }
+
+    fn dir(&self) -> Tx2ConDir {
+        self.sub_con.dir()
+    }
+
+    fn peer_addr(&self) -> KitsuneResult<TxUrl> {
+        self.sub_con.peer_addr()
+    }
+
+    fn peer_cert(&self) -> Tx2Cert {
+        self.peer_cert.clone()
+    }
+
+    fn out_chan(&self, timeout: KitsuneTimeout) -> OutChanFut {
+        let ctx = self.ctx.clone();
+        let peer_cert = self.peer_cert.clone();
+        let sub_con = self.sub_con.clone();
+        let fut = self.sub_con.out_chan(timeout);
+        async move {
+            let sub_chan = fut.await?;
+            let out: OutChan = Box::new(FaultOutChan {
+                ctx,
+                peer_cert,
+                sub_con,
+                sub_chan,
+            });
+            Ok(out)
+        }
+        .boxed()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.sub_con.is_closed()
+    }
+
+    fn close(&self, code: u32, reason: &str) -> BoxFuture<'static, ()> {
+        self.sub_con.close(code, reason)
+    }
+}
+
+struct FaultConRecvAdapt(BoxStream<'static, ConFut>);
+
+impl FaultConRecvAdapt {
+    pub fn new(ctx: Arc<FaultCtx>, sub: Box<dyn ConRecvAdapt>) -> Self {
+        Self(
+            sub.map(move |fut| {
+                let ctx = ctx.clone();
+                async move { ctx.wrap_con(fut.await?).await }.boxed()
+            })
+            .boxed(),
+        )
+    }
+}
+
+impl futures::stream::Stream for FaultConRecvAdapt {
+    type Item = ConFut;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let inner = &mut self.0;
+        tokio::pin!(inner);
+        futures::stream::Stream::poll_next(inner, cx)
+    }
+}
+
+impl ConRecvAdapt for FaultConRecvAdapt {}
+
+struct FaultEndpointAdapt {
+    ctx: Arc<FaultCtx>,
+    sub_ep: Arc<dyn EndpointAdapt>,
+}
+
+impl EndpointAdapt for FaultEndpointAdapt {
+    fn debug(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "tx2_fault",
+            "node": self.ctx.node,
+            "faults": format!("{:?}", self.ctx.injector),
+            "sub_ep": self.sub_ep.debug(),
+        })
+    }
+
+    fn uniq(&self) -> Uniq {
+        self.sub_ep.uniq()
+    }
+
+    fn local_addr(&self) -> KitsuneResult<TxUrl> {
+        self.sub_ep.local_addr()
+    }
+
+    fn local_cert(&self) -> Tx2Cert {
+        self.sub_ep.local_cert()
+    }
+
+    fn connect(&self, url: TxUrl, timeout: KitsuneTimeout) -> ConFut {
+        let ctx = self.ctx.clone();
+        let fut = self.sub_ep.connect(url, timeout);
+        async move { ctx.wrap_con(fut.await?).await }.boxed()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.sub_ep.is_closed()
+    }
+
+    fn close(&self, code: u32, reason: &str) -> BoxFuture<'static, ()> {
+        self.sub_ep.close(code, reason)
+    }
+}
+
+struct FaultBackendAdapt(Arc<FaultCtx>);
+
+impl BindAdapt for FaultBackendAdapt {
+    fn bind(&self, url: TxUrl, timeout: KitsuneTimeout) -> EndpointFut {
+        let ctx = self.0.clone();
+        let fut = ctx.sub_adapter.bind(url, timeout);
+        async move {
+            let (sub_ep, con_recv) = fut.await?;
+            let ep: Arc<dyn EndpointAdapt> = Arc::new(FaultEndpointAdapt {
+                ctx: ctx.clone(),
+                sub_ep,
+            });
+            let con_recv: Box<dyn ConRecvAdapt> = Box::new(FaultConRecvAdapt::new(ctx, con_recv));
+            Ok((ep, con_recv))
+        }
+        .boxed()
+    }
+
+    fn local_cert(&self) -> Tx2Cert {
+        self.0.sub_adapter.local_cert()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn mk_node(
+        injector: &FaultInjector,
+        node: usize,
+    ) -> (Arc<dyn EndpointAdapt>, Box<dyn ConRecvAdapt>) {
+        let f = tx2_fault_adapter(
+            tx2_mem_adapter(MemConfig::default()).await.unwrap(),
+            injector.clone(),
+            node,
+        );
+        f.bind(
+            "none:".try_into().unwrap(),
+            KitsuneTimeout::from_millis(5000),
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn send(
+        con: &Arc<dyn ConAdapt>,
+        recv: &mut Box<dyn InChanRecvAdapt>,
+        t: KitsuneTimeout,
+    ) -> KitsuneResult<()> {
+        let mut out = con.out_chan(t).await?;
+        let mut buf = PoolBuf::new();
+        buf.extend_from_slice(b"hello");
+        out.write(0.into(), buf, t).await?;
+        let mut in_chan = recv.next().await.unwrap().await?;
+        let (_, buf) = in_chan.read(t).await?;
+        assert_eq!(b"hello", &*buf);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tx2_fault_partition_and_heal() {
+        let t = KitsuneTimeout::from_millis(5000);
+        let injector = FaultInjector::new();
+        let (ep1, _con_recv1) = mk_node(&injector, 1).await;
+        let (ep2, mut con_recv2) = mk_node(&injector, 2).await;
+        let addr2 = ep2.local_addr().unwrap();
+
+        let (con, _) = ep1.connect(addr2.clone(), t).await.unwrap();
+        let (_, mut chan_recv) = con_recv2.next().await.unwrap().await.unwrap();
+        send(&con, &mut chan_recv, t).await.unwrap();
+
+        injector.partition([vec![1], vec![2]]);
+        assert!(injector.is_partitioned(1, 2));
+
+        // the open connection is closed, and new ones are refused
+        assert!(send(&con, &mut chan_recv, t).await.is_err());
+        assert!(ep1.connect(addr2.clone(), t).await.is_err());
+        assert!(con_recv2.next().await.unwrap().await.is_err());
+
+        injector.heal();
+
+        let (con, _) = ep1.connect(addr2, t).await.unwrap();
+        let (_, mut chan_recv) = con_recv2.next().await.unwrap().await.unwrap();
+        send(&con, &mut chan_recv, t).await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tx2_fault_restrict_links() {
+        let t = KitsuneTimeout::from_millis(5000);
+        let injector = FaultInjector::new();
+        let (ep1, _con_recv1) = mk_node(&injector, 1).await;
+        let (ep2, mut con_recv2) = mk_node(&injector, 2).await;
+        let (ep3, mut con_recv3) = mk_node(&injector, 3).await;
+        let addr2 = ep2.local_addr().unwrap();
+        let addr3 = ep3.local_addr().unwrap();
+
+        injector.restrict_links([(2, 1)]);
+        assert!(!injector.is_partitioned(1, 2));
+        assert!(injector.is_partitioned(1, 3));
+        assert!(injector.is_partitioned(2, 3));
+
+        let (con, _) = ep1.connect(addr2, t).await.unwrap();
+        let (_, mut chan_recv) = con_recv2.next().await.unwrap().await.unwrap();
+        send(&con, &mut chan_recv, t).await.unwrap();
+
+        assert!(ep1.connect(addr3.clone(), t).await.is_err());
+        assert!(con_recv3.next().await.unwrap().await.is_err());
+
+        injector.unrestrict_links();
+
+        let (con, _) = ep1.connect(addr3, t).await.unwrap();
+        let (_, mut chan_recv) = con_recv3.next().await.unwrap().await.unwrap();
+        send(&con, &mut chan_recv, t).await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tx2_fault_latency_and_loss() {
+        let t = KitsuneTimeout::from_millis(5000);
+        let injector = FaultInjector::new();
+        let (ep1, _con_recv1) = mk_node(&injector, 1).await;
+        let (ep2, mut con_recv2) = mk_node(&injector, 2).await;
+
+        let (con, _) = ep1.connect(ep2.local_addr().unwrap(), t).await.unwrap();
+        let (_, mut chan_recv) = con_recv2.next().await.unwrap().await.unwrap();
+
+        injector.set_link_faults(
+            2,
+            1,
+            Some(LinkFaults {
+                latency: Duration::from_millis(100),
+                ..Default::default()
+            }),
+        );
+        let start = std::time::Instant::now();
+        send(&con, &mut chan_recv, t).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        // with 50% loss, exactly every other frame is dropped
+        injector.set_link_faults(
+            1,
+            2,
+            Some(LinkFaults {
+                loss: 0.5,
+                ..Default::default()
+            }),
+        );
+        let mut out = con.out_chan(t).await.unwrap();
+        for i in 0..4_u8 {
+            let mut buf = PoolBuf::new();
+            buf.extend_from_slice(&[i]);
+            out.write(0.into(), buf, t).await.unwrap();
+        }
+        let mut in_chan = chan_recv.next().await.unwrap().await.unwrap();
+        let (_, buf) = in_chan.read(t).await.unwrap();
+        assert_eq!(&[0], &*buf);
+        let (_, buf) = in_chan.read(t).await.unwrap();
+        assert_eq!(&[2], &*buf);
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
-    error::{BundleError, MrBundleResult},
+    error::{BundleError, MrBundleResult, SignatureError},
     location::Location,
     manifest::Manifest,
     resource::ResourceBytes,
+    signature::BundleSignature,
 };
 use holochain_util::ffs;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -19,7 +20,7 @@ pub type ResourceMap = BTreeMap<PathBuf, ResourceBytes>;
 /// by the receiver.
 ///
 /// The manifest may describe locations of resources not included in the Bundle.
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Bundle<M>
 where
@@ -46,6 +47,74 @@ where
     //        struct into two versions for each case.
     #[serde(skip)]
     root_dir: Option<PathBuf>,
+
+    /// Signatures over the manifest and resources, embedded into the bundle.
+    /// Any change to the manifest or resources drops these signatures.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    signatures: Vec<BundleSignature>,
+
+    /// The manifest and resources exactly as they were encoded in the bytes
+    /// this bundle was decoded from, which is what signatures are made over.
+    #[serde(skip)]
+    encoded: Option<EncodedContents>,
+}
+
+impl<M> PartialEq for Bundle<M>
+where
+    M: Manifest + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // The encoded contents only record how the bundle was read
+        self.manifest == other.manifest
+            && self.resources == other.resources
+            && self.root_dir == other.root_dir
+            && self.signatures == other.signatures
+    }
+}
+
+impl<M> Eq for Bundle<M> where M: Manifest + Eq {}
+
+/// The msgpack encodings of a bundle's manifest and of its resources.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+struct EncodedContents {
+    manifest: Vec<u8>,
+    resources: Vec<u8>,
+}
+
+impl EncodedContents {
+    fn new<M: Serialize>(manifest: &M, resources: &ResourceMap) -> MrBundleResult<Self> {
+        Ok(Self {
+            manifest: rmp_serde::to_vec_named(manifest)?,
+            resources: rmp_serde::to_vec_named(resources)?,
+        })
+    }
+
+    /// Find the manifest and resources in an encoded bundle, byte for byte.
+    /// Returns None if the bundle isn't encoded as a map with both of them.
+    fn read(encoded: &[u8]) -> Option<Self> {
+        let mut rest = encoded;
+        let mut manifest = None;
+        let mut resources = None;
+        for _ in 0..rmp::decode::read_map_len(&mut rest).ok()? {
+            let key_len = rmp::decode::read_str_len(&mut rest).ok()? as usize;
+            let key = rest.get(..key_len)?;
+            rest = &rest[key_len..];
+            let start = encoded.len() - rest.len();
+            serde::de::IgnoredAny::deserialize(&mut rmp_serde::Deserializer::new(&mut rest))
+                .ok()?;
+            let value = Some(encoded[start..encoded.len() - rest.len()].to_vec());
+            match key {
+                b"manifest" => manifest = value,
+                b"resources" => resources = value,
+                _ => (),
+            }
+        }
+        Some(Self {
+            manifest: manifest?,
+            resources: resources?,
+        })
+    }
 }
 
 impl<M> Bundle<M>
@@ -104,6 +173,8 @@ where
             manifest,
             resources,
             root_dir,
+            signatures: Vec::new(),
+            encoded: None,
         })
     }
 
@@ -174,14 +245,97 @@ where
         &self.resources
     }
 
-    /// An arbitrary and opaque encoding of the bundle data into a byte array
+    /// The bytes which a [`BundleSignature`] signs: the encoding of the manifest
+    /// followed by the encoding of the bundled resources, without any signatures.
+    ///
+    /// For a bundle which was decoded, these are exactly the bytes it was
+    /// decoded from, so that a signature covers the manifest and resources as
+    /// they are stored rather than as this version of the code would encode them.
+    pub fn canonical_bytes(&self) -> MrBundleResult<Vec<u8>> {
+        let encoded = self.encoded_contents()?;
+        Ok([encoded.manifest.as_slice(), encoded.resources.as_slice()].concat())
+    }
+
+    fn encoded_contents(&self) -> MrBundleResult<Cow<'_, EncodedContents>> {
+        Ok(match &self.encoded {
+            Some(encoded) => Cow::Borrowed(encoded),
+            None => Cow::Owned(EncodedContents::new(&self.manifest, &self.resources)?),
+        })
+    }
+
+    /// The signatures embedded into this bundle
+    pub fn signatures(&self) -> &[BundleSignature] {
+        &self.signatures
+    }
+
+    /// Embed a signature into this bundle, if it is a valid signature over
+    /// the bundle's contents.
+    pub fn add_signature(&mut self, signature: BundleSignature) -> MrBundleResult<()> {
+        signature.verify(&self.canonical_bytes()?)?;
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    /// Check that every embedded signature, and every given detached signature,
+    /// is a valid signature over the bundle's contents.
+    pub fn verify_signatures(&self, detached: &[BundleSignature]) -> MrBundleResult<()> {
+        let bytes = self.canonical_bytes()?;
+        for signature in self.signatures.iter().chain(detached) {
+            signature.verify(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Check that this bundle has a valid signature, embedded or detached, by
+    /// any of the given trusted ed25519 public keys, returning that signature.
+    pub fn verify_signed_by<'a>(
+        &'a self,
+        trusted_signers: &[[u8; 32]],
+        detached: &'a [BundleSignature],
+    ) -> MrBundleResult<&'a BundleSignature> {
+        let bytes = self.canonical_bytes()?;
+        self.signatures
+            .iter()
+            .chain(detached)
+            .filter(|signature| {
+                trusted_signers
+                    .iter()
+                    .any(|signer| signer[..] == signature.signer[..])
+            })
+            .find(|signature| signature.verify(&bytes).is_ok())
+            .ok_or_else(|| SignatureError::Untrusted.into())
+    }
+
+    /// An arbitrary and opaque encoding of the bundle data into a byte array.
+    ///
+    /// A decoded bundle is encoded with its manifest and resources exactly as
+    /// they were decoded, so that its signatures stay valid.
     pub fn encode(&self) -> MrBundleResult<Vec<u8>> {
-        crate::encode(self)
+        let encoded = self.encoded_contents()?;
+        let mut bytes = Vec::new();
+        let len = if self.signatures.is_empty() { 2 } else { 3 };
+        rmp::encode::write_map_len(&mut bytes, len)
+            .map_err(rmp_serde::encode::Error::InvalidValueWrite)?;
+        rmp::encode::write_str(&mut bytes, "manifest")
+            .map_err(rmp_serde::encode::Error::InvalidValueWrite)?;
+        bytes.extend_from_slice(&encoded.manifest);
+        rmp::encode::write_str(&mut bytes, "resources")
+            .map_err(rmp_serde::encode::Error::InvalidValueWrite)?;
+        bytes.extend_from_slice(&encoded.resources);
+        if !self.signatures.is_empty() {
+            rmp::encode::write_str(&mut bytes, "signatures")
+                .map_err(rmp_serde::encode::Error::InvalidValueWrite)?;
+            bytes.extend(rmp_serde::to_vec_named(&self.signatures)?);
+        }
+        crate::encoding::compress(&bytes)
     }
 
     /// Decode bytes produced by [`encode`](Bundle::encode)
     pub fn decode(bytes: &[u8]) -> MrBundleResult<Self> {
-        crate::decode(bytes)
+        let bytes = crate::encoding::decompress(bytes)?;
+        let mut bundle: Self = rmp_serde::from_read_ref(&bytes)?;
+        bundle.encoded = EncodedContents::read(&bytes);
+        Ok(bundle)
     }
 
     /// Given that the Manifest is located at the given absolute `path`, find
@@ -269,4 +423,99 @@ mod tests {
             Err(MrBundleError::BundleError(BundleError::BundledPathNotInManifest(path))) if path == PathBuf::from("3.thing")
         );
     }
+
+    fn sign(seed: u8, bytes: &[u8]) -> BundleSignature {
+        use ed25519_dalek::Signer;
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[seed; 32]).unwrap();
+        let public = ed25519_dalek::PublicKey::from(&secret);
+        let keypair = ed25519_dalek::Keypair { secret, public };
+        BundleSignature::new(public.to_bytes(), keypair.sign(bytes).to_bytes().to_vec())
+    }
+
+    #[tokio::test]
+    async fn bundle_signatures() {
+        let manifest = TestManifest(vec![Location::Bundled("1.thing".into())]);
+        let mut bundle =
+            Bundle::new_unchecked(manifest.clone(), vec![("1.thing".into(), vec![1].into())])
+                .unwrap();
+        let bytes = bundle.canonical_bytes().unwrap();
+        let embedded = sign(1, &bytes);
+        let detached = sign(2, &bytes);
+        let trusted = |signature: &BundleSignature| {
+            let mut key = [0; 32];
+            key.copy_from_slice(&signature.signer);
+            key
+        };
+
+        // A signature over other bytes can't be embedded
+        matches::assert_matches!(
+            bundle.add_signature(sign(1, b"other")),
+            Err(MrBundleError::SignatureError(
+                SignatureError::InvalidSignature
+            ))
+        );
+        bundle.add_signature(embedded.clone()).unwrap();
+
+        // Embedded signatures survive encoding, and don't change what is signed
+        let bundle: Bundle<TestManifest> = Bundle::decode(&bundle.encode().unwrap()).unwrap();
+        assert_eq!(bundle.signatures(), &[embedded.clone()]);
+        assert_eq!(bundle.canonical_bytes().unwrap(), bytes);
+        bundle
+            .verify_signatures(std::slice::from_ref(&detached))
+            .unwrap();
+
+        assert_eq!(
+            bundle.verify_signed_by(&[trusted(&embedded)], &[]).unwrap(),
+            &embedded
+        );
+        assert_eq!(
+            bundle
+                .verify_signed_by(&[trusted(&detached)], std::slice::from_ref(&detached))
+                .unwrap(),
+            &detached
+        );
+        matches::assert_matches!(
+            bundle.verify_signed_by(&[trusted(&detached)], &[]),
+            Err(MrBundleError::SignatureError(SignatureError::Untrusted))
+        );
+
+        // Changing the manifest drops the signatures
+        let bundle = bundle.update_manifest(manifest).unwrap();
+        assert!(bundle.signatures().is_empty());
+    }
+
+    #[tokio::test]
+    async fn bundle_signatures_cover_stored_bytes() {
+        let manifest = TestManifest(vec![Location::Bundled("1.thing".into())]);
+        let resources: ResourceMap = maplit::btreemap! { "1.thing".into() => vec![1].into() };
+
+        // Encode the manifest's list with a wider array marker than is needed,
+        // as another encoder might.
+        let mut manifest_bytes = rmp_serde::to_vec_named(&manifest).unwrap();
+        assert_eq!(manifest_bytes[0], 0x91);
+        manifest_bytes.splice(0..1, [0xdc, 0x00, 0x01]);
+        let resources_bytes = rmp_serde::to_vec_named(&resources).unwrap();
+        let mut bytes = Vec::new();
+        rmp::encode::write_map_len(&mut bytes, 2).unwrap();
+        rmp::encode::write_str(&mut bytes, "manifest").unwrap();
+        bytes.extend_from_slice(&manifest_bytes);
+        rmp::encode::write_str(&mut bytes, "resources").unwrap();
+        bytes.extend_from_slice(&resources_bytes);
+
+        let mut bundle: Bundle<TestManifest> =
+            Bundle::decode(&crate::encoding::compress(&bytes).unwrap()).unwrap();
+        assert_eq!(bundle, Bundle::new_unchecked(manifest, resources).unwrap());
+
+        // The stored bytes are signed, not a re-encoding of them
+        let stored = [manifest_bytes, resources_bytes].concat();
+        assert_eq!(bundle.canonical_bytes().unwrap(), stored);
+        let signature = sign(1, &stored);
+        bundle.add_signature(signature.clone()).unwrap();
+
+        // and are kept when the signed bundle is encoded again
+        let bundle: Bundle<TestManifest> = Bundle::decode(&bundle.encode().unwrap()).unwrap();
+        assert_eq!(bundle.canonical_bytes().unwrap(), stored);
+        assert_eq!(bundle.signatures(), &[signature]);
+        bundle.verify_signatures(&[]).unwrap();
+    }
 }
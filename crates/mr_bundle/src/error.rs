@@ -33,6 +33,9 @@ pub enum MrBundleError {
 
     #[error("This bundle failed to validate because: {0}")]
     BundleValidationError(String),
+
+    #[error(transparent)]
+    SignatureError(#[from] SignatureError),
 }
 pub type MrBundleResult<T> = Result<T, MrBundleError>;
 
@@ -55,6 +58,22 @@ pub enum BundleError {
 }
 pub type BundleResult<T> = Result<T, BundleError>;
 
+/// Errors which can occur while signing a Bundle or verifying its signatures
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum SignatureError {
+    #[error("The signer of a bundle signature is not a valid ed25519 public key")]
+    MalformedSigner,
+
+    #[error("A bundle signature is not a valid ed25519 signature")]
+    MalformedSignature,
+
+    #[error("A bundle signature does not match the contents of the bundle")]
+    InvalidSignature,
+
+    #[error("The bundle has no valid signature by any of the trusted keys")]
+    Untrusted,
+}
+
 /// Errors which can occur while unpacking resources from a Bundle
 #[cfg(feature = "packing")]
 #[derive(Debug, thiserror::Error)]
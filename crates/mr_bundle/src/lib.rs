@@ -20,6 +20,7 @@ pub mod error;
 mod location;
 mod manifest;
 mod resource;
+pub mod signature;
 pub(crate) mod util;
 
 #[cfg(feature = "packing")]
@@ -30,3 +31,4 @@ pub use encoding::{decode, encode};
 pub use location::Location;
 pub use manifest::Manifest;
 pub use resource::ResourceBytes;
+pub use signature::BundleSignature;
@@ -0,0 +1,73 @@
+//! Signatures over the contents of a [`Bundle`](crate::Bundle), to establish
+//! who produced it.
+//!
+//! A signature may either be embedded into the bundle itself, or kept in a
+//! detached signature file next to the bundle file.
+
+use crate::error::{MrBundleResult, SignatureError};
+use ed25519_dalek::Verifier;
+use holochain_util::ffs;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The file extension appended to a bundle's path to get the path of its
+/// detached signature file, e.g. `my-app.happ.sig`.
+pub const DETACHED_SIGNATURE_EXT: &str = "sig";
+
+/// An ed25519 signature over the canonical bytes of a bundle,
+/// i.e. its manifest and bundled resources.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub struct BundleSignature {
+    /// The ed25519 public key of the signer.
+    #[serde(with = "serde_bytes")]
+    pub signer: Vec<u8>,
+
+    /// The ed25519 signature.
+    #[serde(with = "serde_bytes")]
+    pub signature: Vec<u8>,
+}
+
+impl BundleSignature {
+    /// Constructor
+    pub fn new(signer: impl Into<Vec<u8>>, signature: impl Into<Vec<u8>>) -> Self {
+        Self {
+            signer: signer.into(),
+            signature: signature.into(),
+        }
+    }
+
+    /// Check that this is a valid signature by the signer over the given
+    /// canonical bundle bytes.
+    pub fn verify(&self, canonical_bytes: &[u8]) -> MrBundleResult<()> {
+        let signer = ed25519_dalek::PublicKey::from_bytes(&self.signer)
+            .map_err(|_| SignatureError::MalformedSigner)?;
+        let signature = ed25519_dalek::Signature::from_bytes(&self.signature)
+            .map_err(|_| SignatureError::MalformedSignature)?;
+        signer
+            .verify(canonical_bytes, &signature)
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        Ok(())
+    }
+}
+
+/// The path of the detached signature file for a bundle file.
+pub fn detached_signature_path(bundle_path: &Path) -> PathBuf {
+    let mut path = bundle_path.as_os_str().to_owned();
+    path.push(".");
+    path.push(DETACHED_SIGNATURE_EXT);
+    path.into()
+}
+
+/// Read the signatures from a detached signature file.
+pub async fn read_detached_signatures(path: &Path) -> MrBundleResult<Vec<BundleSignature>> {
+    crate::decode(&ffs::read(path).await?)
+}
+
+/// Write signatures to a detached signature file.
+pub async fn write_detached_signatures(
+    path: &Path,
+    signatures: &[BundleSignature],
+) -> MrBundleResult<()> {
+    Ok(ffs::write(path, &crate::encode(&signatures)?).await?)
+}
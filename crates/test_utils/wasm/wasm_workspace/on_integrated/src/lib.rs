@@ -0,0 +1,28 @@
+use hdk::prelude::*;
+
+#[hdk_extern]
+fn create(_: ()) -> ExternResult<ActionHash> {
+    create_cap_grant(CapGrantEntry {
+        tag: "on_integrated".into(),
+        access: ().into(),
+        functions: GrantedFunctions::All,
+    })
+}
+
+#[hdk_extern(infallible)]
+fn on_integrated(ops: Vec<IntegratedOp>) {
+    // Tell the UI about every record this agent now holds as an authority.
+    let records: Vec<(ActionHash, ValidationStatus)> = ops
+        .into_iter()
+        .filter_map(|integrated| match integrated.op {
+            Op::StoreRecord(StoreRecord { record }) => Some((
+                record.action_address().clone(),
+                integrated.validation_status,
+            )),
+            _ => None,
+        })
+        .collect();
+    if !records.is_empty() {
+        emit_signal(&records).ok();
+    }
+}
@@ -101,4 +101,46 @@ fn query_tock(_: ()) -> ExternResult<Vec<Record>> {
 #[hdk_extern]
 fn query_tock_init(_: ()) -> ExternResult<Vec<Record>> {
     _query(EntryTypesUnit::TockInit)
-}
\ No newline at end of file
+}
+#[hdk_extern(infallible)]
+fn remind(input: ScheduledFnInput) -> Option<Schedule> {
+    let text: String = input.payload.decode().ok()?;
+    create_entry(&EntryTypes::Reminder(Reminder {
+        name: input.name,
+        text,
+    }))
+    .ok();
+    None
+}
+
+#[hdk_extern]
+fn schedule_reminders(reminders: Vec<(String, String)>) -> ExternResult<()> {
+    for (name, text) in reminders {
+        hdk::prelude::schedule_with_payload("remind", name, text, None)?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+fn cancel_reminder(name: String) -> ExternResult<()> {
+    hdk::prelude::cancel_schedule("remind", name)
+}
+
+#[hdk_extern]
+fn list_schedules(_: ()) -> ExternResult<Vec<ScheduledFnInfo>> {
+    hdk::prelude::list_schedules()
+}
+
+#[hdk_extern]
+fn query_reminders(_: ()) -> ExternResult<Vec<Reminder>> {
+    _query(EntryTypesUnit::Reminder)?
+        .into_iter()
+        .map(|record| {
+            record
+                .entry()
+                .to_app_option::<Reminder>()
+                .map_err(|e| wasm_error!(e))?
+                .ok_or(wasm_error!(WasmErrorInner::Guest("Missing reminder".into())))
+        })
+        .collect()
+}
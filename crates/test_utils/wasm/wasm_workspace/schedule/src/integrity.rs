@@ -14,6 +14,12 @@ pub struct Tick;
 #[hdk_entry_helper]
 pub struct Tock;
 
+#[hdk_entry_helper]
+pub struct Reminder {
+    pub name: String,
+    pub text: String,
+}
+
 #[hdk_entry_defs]
 #[unit_enum(EntryTypesUnit)]
 pub enum EntryTypes {
@@ -21,4 +27,5 @@ pub enum EntryTypes {
     TockInit(TockInit),
     Tick(Tick),
     Tock(Tock),
+    Reminder(Reminder),
 }
@@ -16,6 +16,7 @@ impl From<Zomes> for ZomeName {
 fn set_access(_: ()) -> ExternResult<()> {
     let mut fns = BTreeSet::new();
     fns.insert((zome_info()?.name, "whoami".into()));
+    fns.insert((zome_info()?.name, "echo_bytes".into()));
     let functions = GrantedFunctions::Listed(fns);
     create_cap_grant(CapGrantEntry {
         tag: "".into(),
@@ -53,6 +54,32 @@ fn whoarethey(agent_pubkey: AgentPubKey) -> ExternResult<AgentInfo> {
     }
 }
 
+// returns the bytes it was called with
+#[hdk_extern]
+fn echo_bytes(bytes: Vec<u8>) -> ExternResult<Vec<u8>> {
+    Ok(bytes)
+}
+
+// sends the bytes to the given agent and back again with a streamed call,
+// so they can be larger than a single network message
+#[hdk_extern]
+fn echo_bytes_remote_streamed(
+    (agent_pubkey, bytes): (AgentPubKey, Vec<u8>),
+) -> ExternResult<Vec<u8>> {
+    let zome_call_response: ZomeCallResponse = call_remote_streamed(
+        agent_pubkey,
+        zome_info()?.name,
+        "echo_bytes".to_string().into(),
+        None,
+        bytes,
+    )?;
+    match zome_call_response {
+        ZomeCallResponse::Ok(v) => Ok(v.decode().map_err(|e| wasm_error!(e))?),
+        // This should be handled in real code.
+        _ => unreachable!(),
+    }
+}
+
 // returns the agent info reported by the given pub key
 // in theory the output is the same as the input
 // it's just that the output comes _from the opinion of the remote agent_